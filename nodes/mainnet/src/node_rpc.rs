@@ -31,8 +31,10 @@
 #![warn(missing_docs)]
 
 use jsonrpsee::RpcModule;
+use pallet_lmp_rpc::PolkadexLmpRpc;
 use pallet_ocex_rpc::PolkadexOcexRpc;
 use pallet_rewards_rpc::PolkadexRewardsRpc;
+use thea_rpc::TheaRpc;
 
 use grandpa::{
 	FinalityProofProvider, GrandpaJustificationStream, SharedAuthoritySet, SharedVoterState,
@@ -123,9 +125,17 @@ where
 	C::Api: rpc_assets::PolkadexAssetHandlerRuntimeApi<Block, AccountId, Hash>,
 	C::Api: pallet_rewards_rpc::PolkadexRewardsRuntimeApi<Block, AccountId, Hash>,
 	C::Api: pallet_ocex_rpc::PolkadexOcexRuntimeApi<Block, AccountId, Hash>,
+	C::Api: pallet_lmp_rpc::PolkadexLmpRuntimeApi<Block, AccountId, Balance>,
+	C::Api: thea_rpc::TheaRuntimeApi<
+		Block,
+		AccountId,
+		thea::ecdsa::AuthorityId,
+		thea::ecdsa::AuthoritySignature,
+	>,
 	C::Api: pallet_asset_conversion::AssetConversionApi<Block, Balance, u128, AssetId>,
 	C: BlockchainEvents<Block>,
 {
+	use pallet_lmp_rpc::PolkadexLmpRpcApiServer;
 	use pallet_ocex_rpc::PolkadexOcexRpcApiServer;
 	use pallet_rewards_rpc::PolkadexRewardsRpcApiServer;
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
@@ -134,6 +144,7 @@ where
 	use sc_rpc::dev::{Dev, DevApiServer};
 	use sc_sync_state_rpc::{SyncState, SyncStateApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
+	use thea_rpc::TheaRpcApiServer;
 	// use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
 
 	let mut io = RpcModule::new(());
@@ -166,7 +177,7 @@ where
 	)?;
 	io.merge(
 		Grandpa::new(
-			subscription_executor,
+			subscription_executor.clone(),
 			shared_authority_set.clone(),
 			shared_voter_state,
 			justification_stream,
@@ -183,6 +194,7 @@ where
 	// io.merge(StateMigration::new(client.clone(), backend, deny_unsafe).into_rpc())?;
 	io.merge(PolkadexAssetHandlerRpc::new(client.clone()).into_rpc())?;
 	io.merge(PolkadexRewardsRpc::new(client.clone()).into_rpc())?;
+	io.merge(TheaRpc::new(client.clone(), subscription_executor).into_rpc())?;
 	io.merge(
 		PolkadexOcexRpc::new(
 			client.clone(),
@@ -192,6 +204,15 @@ where
 		)
 		.into_rpc(),
 	)?;
+	io.merge(
+		PolkadexLmpRpc::new(
+			client.clone(),
+			backend
+				.offchain_storage()
+				.ok_or("Backend doesn't provide an offchain storage")?,
+		)
+		.into_rpc(),
+	)?;
 	io.merge(Dev::new(client.clone(), deny_unsafe).into_rpc())?;
 	let statement_store =
 		sc_rpc::statement::StatementStore::new(statement_store, deny_unsafe).into_rpc();
@@ -0,0 +1,95 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+//! Benchmarking setup for eth-light-client
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as EthLightClient;
+use crate::{fixtures, ssz, types::MAX_RECEIPT_PROOF_SIZE};
+use frame_benchmarking::v1::{account, benchmarks};
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+use frame_system::RawOrigin;
+use sp_std::vec;
+use thea_primitives::ETHEREUM_NETWORK;
+
+/// Size in bytes of the receipt trie nodes of the deposit log proof fixture
+const FIXTURE_RECEIPT_PROOF_SIZE: u32 = 1_046;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn bootstrap<T: Config>() {
+	let (config, header, committee) = fixtures::bootstrap();
+	let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+	Pallet::<T>::force_checkpoint(origin, config, header, committee).unwrap();
+}
+
+benchmarks! {
+	force_checkpoint {
+		let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+		let (config, header, current_sync_committee) = fixtures::bootstrap();
+		let call = Call::<T>::force_checkpoint { config, header, current_sync_committee };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T>(Event::CheckpointForced(header.slot, ssz::header_root(&header)).into());
+	}
+
+	submit_update {
+		bootstrap::<T>();
+		// Worst case carries the next sync committee
+		let update = fixtures::update_with_next_sync_committee();
+		let relayer: T::AccountId = account("relayer", 0, 0);
+		let finalized_header = update.finalized_header;
+	}: _(RawOrigin::Signed(relayer), update)
+	verify {
+		assert_eq!(<LatestFinalizedHeader<T>>::get(), Some(finalized_header));
+	}
+
+	set_bridge_contract {
+		let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+		let (contract, _, _) = fixtures::deposit_log_proof();
+		let call = Call::<T>::set_bridge_contract { network: ETHEREUM_NETWORK, contract: Some(contract) };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(<BridgeContracts<T>>::get(ETHEREUM_NETWORK), Some(contract));
+	}
+
+	submit_deposit_proof {
+		let x in FIXTURE_RECEIPT_PROOF_SIZE .. MAX_RECEIPT_PROOF_SIZE;
+		bootstrap::<T>();
+		let relayer: T::AccountId = account("relayer", 0, 0);
+		Pallet::<T>::submit_update(
+			RawOrigin::Signed(relayer.clone()).into(),
+			fixtures::update_with_next_sync_committee(),
+		).unwrap();
+		let (contract, mut proof, message) = fixtures::deposit_log_proof();
+		// Nodes past the receipt are not part of the path but are decoded with the call
+		let padding = x - proof.receipt_proof_size();
+		proof.receipt_proof.push(vec![0u8; padding as usize]);
+		<BridgeContracts<T>>::insert(ETHEREUM_NETWORK, contract);
+	}: _(RawOrigin::Signed(relayer), ETHEREUM_NETWORK, proof)
+	verify {
+		assert_last_event::<T>(Event::DepositProven(ETHEREUM_NETWORK, message.nonce).into());
+	}
+}
+
+#[cfg(test)]
+use frame_benchmarking::v1::impl_benchmark_test_suite;
+
+#[cfg(test)]
+impl_benchmark_test_suite!(EthLightClient, crate::mock::new_test_ext(), crate::mock::Test);
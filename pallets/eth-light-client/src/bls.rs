@@ -0,0 +1,72 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+//! BLS signatures of the beacon chain (public keys in G1, signatures in G2).
+
+use crate::types::{BlsPublicKey, BlsSignature};
+use ark_bls12_381::{g2::Config as G2Config, Bls12_381, G1Affine, G1Projective, G2Affine};
+use ark_ec::{
+	hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve},
+	pairing::Pairing,
+	short_weierstrass::Projective,
+	AffineRepr, CurveGroup,
+};
+use ark_ff::{field_hashers::DefaultFieldHasher, Zero};
+use ark_serialize::CanonicalDeserialize;
+use sha2::Sha256;
+
+/// Ciphersuite of proof of possession signatures used by the beacon chain
+pub const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Verifies the aggregate signature of the public keys over the same message, as
+/// `FastAggregateVerify` of the BLS signature standard
+pub fn fast_aggregate_verify(
+	pubkeys: &[BlsPublicKey],
+	message: &[u8],
+	signature: &BlsSignature,
+) -> bool {
+	if pubkeys.is_empty() {
+		return false;
+	}
+	let mut aggregate = G1Projective::zero();
+	for pubkey in pubkeys {
+		match G1Affine::deserialize_compressed(&pubkey[..]) {
+			Ok(point) if !point.is_zero() => aggregate += point,
+			_ => return false,
+		}
+	}
+	let signature = match G2Affine::deserialize_compressed(&signature[..]) {
+		Ok(signature) => signature,
+		Err(_) => return false,
+	};
+	let hasher = match MapToCurveBasedHasher::<
+		Projective<G2Config>,
+		DefaultFieldHasher<Sha256, 128>,
+		WBMap<G2Config>,
+	>::new(DST)
+	{
+		Ok(hasher) => hasher,
+		Err(_) => return false,
+	};
+	let message = match hasher.hash(message) {
+		Ok(point) => point,
+		Err(_) => return false,
+	};
+	Bls12_381::multi_pairing(
+		[aggregate.into_affine(), -G1Affine::generator()],
+		[message, signature],
+	)
+	.is_zero()
+}
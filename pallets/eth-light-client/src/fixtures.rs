@@ -0,0 +1,200 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+//! Light client fixtures shared by the tests and benchmarks.
+//!
+//! The fixtures use the JSON format of the beacon API. They are generated deterministically
+//! rather than recorded from mainnet: the sync committees consist of test keys so that the
+//! signed updates, the beacon states and the receipts trie are self-consistent and
+//! reproducible, while the chain parameters and generalized indices are the ones of
+//! mainnet Deneb.
+
+use crate::types::{
+	BeaconBlockHeader, BridgeContract, ChainConfig, LightClientUpdate, LogProof,
+	NextSyncCommitteeUpdate, SyncAggregate, SyncCommittee,
+};
+use serde::Deserialize;
+use sp_core::{H160, H256};
+use sp_std::{string::String, vec::Vec};
+use thea_primitives::types::Message;
+
+#[derive(Deserialize)]
+struct Header {
+	slot: String,
+	proposer_index: String,
+	parent_root: String,
+	state_root: String,
+	body_root: String,
+}
+
+#[derive(Deserialize)]
+struct Committee {
+	pubkeys: Vec<String>,
+	aggregate_pubkey: String,
+}
+
+#[derive(Deserialize)]
+struct Bootstrap {
+	genesis_validators_root: String,
+	fork_version: String,
+	header: Header,
+	current_sync_committee: Committee,
+}
+
+#[derive(Deserialize)]
+struct Aggregate {
+	sync_committee_bits: String,
+	sync_committee_signature: String,
+}
+
+#[derive(Deserialize)]
+struct Update {
+	attested_header: Header,
+	next_sync_committee: Option<Committee>,
+	next_sync_committee_branch: Option<Vec<String>>,
+	finalized_header: Header,
+	finality_branch: Vec<String>,
+	sync_aggregate: Aggregate,
+	signature_slot: String,
+}
+
+#[derive(Deserialize)]
+struct Contract {
+	address: String,
+	event_signature: String,
+}
+
+#[derive(Deserialize)]
+struct DepositLogProof {
+	bridge_contract: Contract,
+	finalized_block_root: String,
+	header: Header,
+	ancestry_branch: Vec<String>,
+	receipts_root: String,
+	receipts_root_branch: Vec<String>,
+	transaction_index: String,
+	receipt_proof: Vec<String>,
+	log_index: String,
+	message: Message,
+}
+
+fn bytes(value: &str) -> Vec<u8> {
+	hex::decode(value.trim_start_matches("0x")).expect("fixture holds valid hex")
+}
+
+fn array<const N: usize>(value: &str) -> [u8; N] {
+	bytes(value).try_into().expect("fixture holds values of valid length")
+}
+
+fn h256(value: &str) -> H256 {
+	H256(array(value))
+}
+
+fn branch(values: &[String]) -> Vec<H256> {
+	values.iter().map(|value| h256(value)).collect()
+}
+
+fn number<N: core::str::FromStr>(value: &str) -> N {
+	value.parse().ok().expect("fixture holds valid numbers")
+}
+
+impl From<Header> for BeaconBlockHeader {
+	fn from(header: Header) -> Self {
+		BeaconBlockHeader {
+			slot: number(&header.slot),
+			proposer_index: number(&header.proposer_index),
+			parent_root: h256(&header.parent_root),
+			state_root: h256(&header.state_root),
+			body_root: h256(&header.body_root),
+		}
+	}
+}
+
+impl From<Committee> for SyncCommittee {
+	fn from(committee: Committee) -> Self {
+		SyncCommittee {
+			pubkeys: committee.pubkeys.iter().map(|pubkey| array(pubkey)).collect(),
+			aggregate_pubkey: array(&committee.aggregate_pubkey),
+		}
+	}
+}
+
+fn update(json: &str) -> LightClientUpdate {
+	let update: Update = serde_json::from_str(json).expect("fixture is valid JSON");
+	let next_sync_committee = update.next_sync_committee.map(|committee| NextSyncCommitteeUpdate {
+		next_sync_committee: committee.into(),
+		next_sync_committee_branch: branch(&update.next_sync_committee_branch.unwrap_or_default()),
+	});
+	LightClientUpdate {
+		attested_header: update.attested_header.into(),
+		next_sync_committee,
+		finalized_header: update.finalized_header.into(),
+		finality_branch: branch(&update.finality_branch),
+		sync_aggregate: SyncAggregate {
+			sync_committee_bits: bytes(&update.sync_aggregate.sync_committee_bits),
+			sync_committee_signature: array(&update.sync_aggregate.sync_committee_signature),
+		},
+		signature_slot: number(&update.signature_slot),
+	}
+}
+
+/// Chain config, trusted header and its sync committee of period 1000
+pub fn bootstrap() -> (ChainConfig, BeaconBlockHeader, SyncCommittee) {
+	let bootstrap: Bootstrap = serde_json::from_str(include_str!("../fixtures/bootstrap.json"))
+		.expect("fixture is valid JSON");
+	let config = ChainConfig {
+		genesis_validators_root: h256(&bootstrap.genesis_validators_root),
+		fork_version: array(&bootstrap.fork_version),
+		finalized_root_gindex: 105,
+		next_sync_committee_gindex: 55,
+		block_roots_gindex: 37,
+		receipts_root_gindex: 803,
+	};
+	(config, bootstrap.header.into(), bootstrap.current_sync_committee.into())
+}
+
+/// Update of period 1000 finalizing the block of the deposit and carrying the sync
+/// committee of period 1001, signed by 412 of 512 members
+pub fn update_with_next_sync_committee() -> LightClientUpdate {
+	update(include_str!("../fixtures/update_with_next_sync_committee.json"))
+}
+
+/// Update finalizing the first epoch of period 1001, signed by the whole next sync committee
+pub fn update_next_period() -> LightClientUpdate {
+	update(include_str!("../fixtures/update_next_period.json"))
+}
+
+/// Bridge contract, proof of its `TheaMessage` log in the receipt of the second transaction
+/// of the deposit block and the message carried by the log
+pub fn deposit_log_proof() -> (BridgeContract, LogProof, Message) {
+	let proof: DepositLogProof =
+		serde_json::from_str(include_str!("../fixtures/deposit_log_proof.json"))
+			.expect("fixture is valid JSON");
+	let bridge_contract = BridgeContract {
+		address: H160(array(&proof.bridge_contract.address)),
+		event_signature: h256(&proof.bridge_contract.event_signature),
+	};
+	let log_proof = LogProof {
+		finalized_block_root: h256(&proof.finalized_block_root),
+		header: proof.header.into(),
+		ancestry_branch: branch(&proof.ancestry_branch),
+		receipts_root: h256(&proof.receipts_root),
+		receipts_root_branch: branch(&proof.receipts_root_branch),
+		transaction_index: number(&proof.transaction_index),
+		receipt_proof: proof.receipt_proof.iter().map(|node| bytes(node)).collect(),
+		log_index: number(&proof.log_index),
+	};
+	(bridge_contract, log_proof, proof.message)
+}
@@ -0,0 +1,496 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Ethereum Light Client Pallet
+//!
+//! Beacon chain light client following the finalized headers of Ethereum with the signatures of
+//! the sync committee, used to verify Thea deposits without trusting relayers.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! Governance bootstraps the light client with a trusted finalized header and its sync
+//! committee. Anyone can then submit light client updates signed by at least two thirds of the
+//! sync committee, which finalize newer headers and rotate the sync committee every period.
+//!
+//! Deposits to networks with a registered bridge contract are proven by the receipt of the
+//! transaction which emitted the `TheaMessage(bytes)` log of the bridge contract, in a block
+//! that is finalized or an ancestor of a finalized block. The message carried by the log is
+//! handed over to Thea, which executes it without waiting for the fork period.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//! - `force_checkpoint` - Bootstraps the light client from a trusted header.
+//! - `submit_update` - Imports a light client update signed by the sync committee.
+//! - `set_bridge_contract` - Sets the bridge contract of a network.
+//! - `submit_deposit_proof` - Proves a message emitted by the bridge contract.
+//!
+//! ### Storage Items
+//! - `ChainConfiguration` - Parameters of the tracked beacon chain.
+//! - `CurrentSyncCommittee` - Sync committee of the period of the latest finalized header.
+//! - `NextSyncCommittee` - Sync committee of the following period, if known.
+//! - `LatestFinalizedHeader` - Latest finalized header.
+//! - `FinalizedHeaders` - Recent finalized headers by block root.
+//! - `BridgeContracts` - Bridge contracts by network.
+//!
+//! # Events
+//! - `CheckpointForced` - Light client bootstrapped from a trusted header.
+//! - `HeaderFinalized` - New finalized header imported.
+//! - `SyncCommitteeUpdated` - Sync committee of the next period imported.
+//! - `BridgeContractUpdated` - Bridge contract of a network updated.
+//! - `DepositProven` - Message of the bridge contract proven.
+pub use pallet::*;
+
+pub mod bls;
+pub mod receipt;
+pub mod ssz;
+pub mod types;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub mod fixtures;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{
+		bls, receipt, ssz,
+		types::{
+			sync_committee_period, BeaconBlockHeader, BlsPublicKey, BridgeContract, ChainConfig,
+			LightClientUpdate, LogProof, SyncCommittee, DOMAIN_SYNC_COMMITTEE,
+			MAX_RECEIPT_PROOF_SIZE, SLOTS_PER_HISTORICAL_ROOT, SYNC_COMMITTEE_SIZE,
+		},
+	};
+	use frame_support::{pallet_prelude::*, traits::Contains};
+	use frame_system::pallet_prelude::*;
+	use sp_core::H256;
+	use sp_std::vec::Vec;
+	use thea_primitives::{
+		abi::{self, ParamType, Token},
+		types::Message,
+		Network, TheaIncomingProofHandler,
+	};
+
+	pub trait EthLightClientWeightInfo {
+		fn force_checkpoint() -> Weight;
+		fn submit_update() -> Weight;
+		fn set_bridge_contract() -> Weight;
+		fn submit_deposit_proof(x: u32) -> Weight;
+	}
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Origin that can bootstrap the light client and set bridge contracts
+		type GovernanceOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Handler of the proven messages, i.e. Thea
+		type MessageHandler: TheaIncomingProofHandler<Self::AccountId>;
+		/// Number of recent finalized headers that log proofs can refer to
+		#[pallet::constant]
+		type MaxFinalizedHeaders: Get<u32>;
+		/// Type representing the weight of this pallet
+		type WeightInfo: EthLightClientWeightInfo;
+	}
+
+	/// Parameters of the tracked beacon chain
+	#[pallet::storage]
+	#[pallet::getter(fn chain_config)]
+	pub(super) type ChainConfiguration<T: Config> = StorageValue<_, ChainConfig, OptionQuery>;
+
+	/// Sync committee of the period of the latest finalized header
+	#[pallet::storage]
+	pub(super) type CurrentSyncCommittee<T: Config> = StorageValue<_, SyncCommittee, OptionQuery>;
+
+	/// Sync committee of the period following the latest finalized header
+	#[pallet::storage]
+	pub(super) type NextSyncCommittee<T: Config> = StorageValue<_, SyncCommittee, OptionQuery>;
+
+	/// Latest finalized header
+	#[pallet::storage]
+	#[pallet::getter(fn latest_finalized_header)]
+	pub(super) type LatestFinalizedHeader<T: Config> =
+		StorageValue<_, BeaconBlockHeader, OptionQuery>;
+
+	/// Recent finalized headers by block root
+	#[pallet::storage]
+	#[pallet::getter(fn finalized_header)]
+	pub(super) type FinalizedHeaders<T: Config> =
+		StorageMap<_, Identity, H256, BeaconBlockHeader, OptionQuery>;
+
+	/// Ring buffer of the roots of recent finalized headers, used to prune `FinalizedHeaders`
+	#[pallet::storage]
+	pub(super) type FinalizedHeaderRoots<T: Config> =
+		StorageMap<_, Identity, u32, H256, OptionQuery>;
+
+	/// Position of the next root in `FinalizedHeaderRoots`
+	#[pallet::storage]
+	pub(super) type FinalizedHeaderIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Bridge contracts by network, deposits of these networks require proofs
+	#[pallet::storage]
+	#[pallet::getter(fn bridge_contract)]
+	pub(super) type BridgeContracts<T: Config> =
+		StorageMap<_, Identity, Network, BridgeContract, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Light client bootstrapped from a trusted header (slot, block root)
+		CheckpointForced(u64, H256),
+		/// Finalized header imported (slot, block root)
+		HeaderFinalized(u64, H256),
+		/// Sync committee of the period imported
+		SyncCommitteeUpdated(u64),
+		/// Bridge contract of the network updated
+		BridgeContractUpdated(Network, Option<BridgeContract>),
+		/// Message of the bridge contract proven (network, nonce)
+		DepositProven(Network, u64),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Light client is not bootstrapped
+		NotInitialized,
+		/// Sync committee does not have `SYNC_COMMITTEE_SIZE` members
+		InvalidSyncCommitteeSize,
+		/// Participation bits do not match the sync committee
+		InvalidSyncAggregate,
+		/// Less than two thirds of the sync committee signed the update
+		InsufficientParticipation,
+		/// Slots of the headers and the signature are not increasing
+		InvalidUpdateSlots,
+		/// Update is not signed by the current or the next sync committee
+		InvalidSignaturePeriod,
+		/// Sync committee of the next period is not known yet
+		NextSyncCommitteeUnknown,
+		/// Update neither finalizes a newer header nor the next sync committee
+		StaleUpdate,
+		/// Finalized header is not in the attested state
+		InvalidFinalityProof,
+		/// Next sync committee is not in the attested state of the current period
+		InvalidNextSyncCommittee,
+		/// Sync committee signature is invalid
+		InvalidSignature,
+		/// Network has no bridge contract
+		UnknownBridgeContract,
+		/// Block root is not a recent finalized header
+		HeaderNotFinalized,
+		/// Header is not an ancestor of the finalized header
+		InvalidAncestryProof,
+		/// Receipts root is not in the block body
+		InvalidReceiptsRootProof,
+		/// Receipt is not in the receipts trie
+		InvalidReceiptProof,
+		/// Receipt has no log at the index
+		LogNotFound,
+		/// Log is not emitted by the bridge contract
+		InvalidLog,
+		/// Log does not carry a message of the network
+		InvalidMessage,
+		/// Receipt trie nodes exceed `MAX_RECEIPT_PROOF_SIZE` bytes
+		ReceiptProofTooLarge,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Bootstraps the light client from a trusted finalized header and its sync committee.
+		/// The chain config has to be updated the same way when the fork version changes.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::force_checkpoint())]
+		pub fn force_checkpoint(
+			origin: OriginFor<T>,
+			config: ChainConfig,
+			header: BeaconBlockHeader,
+			current_sync_committee: SyncCommittee,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				current_sync_committee.pubkeys.len() == SYNC_COMMITTEE_SIZE,
+				Error::<T>::InvalidSyncCommitteeSize
+			);
+			<ChainConfiguration<T>>::put(config);
+			<CurrentSyncCommittee<T>>::put(current_sync_committee);
+			<NextSyncCommittee<T>>::kill();
+			let root = Self::insert_finalized_header(header);
+			Self::deposit_event(Event::<T>::CheckpointForced(header.slot, root));
+			Ok(())
+		}
+
+		/// Imports a light client update signed by the sync committee
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::submit_update())]
+		pub fn submit_update(origin: OriginFor<T>, update: LightClientUpdate) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::process_update(update)
+		}
+
+		/// Sets the bridge contract of the network, deposits of networks with a bridge contract
+		/// are only accepted with proofs
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_bridge_contract())]
+		pub fn set_bridge_contract(
+			origin: OriginFor<T>,
+			network: Network,
+			contract: Option<BridgeContract>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			<BridgeContracts<T>>::set(network, contract);
+			Self::deposit_event(Event::<T>::BridgeContractUpdated(network, contract));
+			Ok(())
+		}
+
+		/// Proves the message emitted by the bridge contract of the network and hands it over
+		/// to Thea, the submitter is rewarded as its relayer. Weighted by the size of the receipt
+		/// trie nodes, which are hashed and decoded.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::submit_deposit_proof(proof.receipt_proof_size()))]
+		pub fn submit_deposit_proof(
+			origin: OriginFor<T>,
+			network: Network,
+			proof: LogProof,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			ensure!(
+				proof.receipt_proof_size() <= MAX_RECEIPT_PROOF_SIZE,
+				Error::<T>::ReceiptProofTooLarge
+			);
+			let message = Self::verify_log_proof(network, &proof)?;
+			let nonce = message.nonce;
+			T::MessageHandler::on_proven_message(relayer, message)?;
+			Self::deposit_event(Event::<T>::DepositProven(network, nonce));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn process_update(update: LightClientUpdate) -> DispatchResult {
+			let config = <ChainConfiguration<T>>::get().ok_or(Error::<T>::NotInitialized)?;
+			let latest = <LatestFinalizedHeader<T>>::get().ok_or(Error::<T>::NotInitialized)?;
+			let current = <CurrentSyncCommittee<T>>::get().ok_or(Error::<T>::NotInitialized)?;
+			let next = <NextSyncCommittee<T>>::get();
+			ensure!(
+				update.signature_slot > update.attested_header.slot
+					&& update.attested_header.slot >= update.finalized_header.slot,
+				Error::<T>::InvalidUpdateSlots
+			);
+			let store_period = sync_committee_period(latest.slot);
+			let signature_period = sync_committee_period(update.signature_slot);
+			let committee = if signature_period == store_period {
+				current
+			} else if signature_period == store_period.saturating_add(1) {
+				next.clone().ok_or(Error::<T>::NextSyncCommitteeUnknown)?
+			} else {
+				return Err(Error::<T>::InvalidSignaturePeriod.into());
+			};
+			let imports_next_sync_committee =
+				next.is_none() && update.next_sync_committee.is_some();
+			ensure!(
+				update.finalized_header.slot > latest.slot || imports_next_sync_committee,
+				Error::<T>::StaleUpdate
+			);
+
+			let bits = &update.sync_aggregate.sync_committee_bits;
+			ensure!(bits.len() * 8 == SYNC_COMMITTEE_SIZE, Error::<T>::InvalidSyncAggregate);
+			let participants: Vec<BlsPublicKey> = committee
+				.pubkeys
+				.iter()
+				.enumerate()
+				.filter(|(index, _)| (bits[index / 8] >> (index % 8)) & 1 == 1)
+				.map(|(_, pubkey)| *pubkey)
+				.collect();
+			ensure!(
+				participants.len() * 3 >= SYNC_COMMITTEE_SIZE * 2,
+				Error::<T>::InsufficientParticipation
+			);
+
+			ensure!(
+				ssz::is_valid_merkle_branch(
+					ssz::header_root(&update.finalized_header),
+					&update.finality_branch,
+					config.finalized_root_gindex,
+					update.attested_header.state_root,
+				),
+				Error::<T>::InvalidFinalityProof
+			);
+			if let Some(next_update) = &update.next_sync_committee {
+				let committee = &next_update.next_sync_committee;
+				ensure!(
+					committee.pubkeys.len() == SYNC_COMMITTEE_SIZE,
+					Error::<T>::InvalidSyncCommitteeSize
+				);
+				ensure!(
+					sync_committee_period(update.attested_header.slot) == store_period
+						&& next.as_ref().map_or(true, |next| next == committee)
+						&& ssz::is_valid_merkle_branch(
+							ssz::sync_committee_root(committee),
+							&next_update.next_sync_committee_branch,
+							config.next_sync_committee_gindex,
+							update.attested_header.state_root,
+						),
+					Error::<T>::InvalidNextSyncCommittee
+				);
+			}
+
+			let domain = ssz::compute_domain(
+				DOMAIN_SYNC_COMMITTEE,
+				config.fork_version,
+				config.genesis_validators_root,
+			);
+			let signing_root =
+				ssz::compute_signing_root(ssz::header_root(&update.attested_header), domain);
+			ensure!(
+				bls::fast_aggregate_verify(
+					&participants,
+					signing_root.as_bytes(),
+					&update.sync_aggregate.sync_committee_signature,
+				),
+				Error::<T>::InvalidSignature
+			);
+
+			if imports_next_sync_committee {
+				if let Some(next_update) = update.next_sync_committee {
+					<NextSyncCommittee<T>>::put(next_update.next_sync_committee);
+					Self::deposit_event(Event::<T>::SyncCommitteeUpdated(
+						store_period.saturating_add(1),
+					));
+				}
+			}
+			if update.finalized_header.slot > latest.slot {
+				// Finalizing the first header of the next period rotates the sync committees
+				if sync_committee_period(update.finalized_header.slot) > store_period {
+					let next = <NextSyncCommittee<T>>::take()
+						.ok_or(Error::<T>::NextSyncCommitteeUnknown)?;
+					<CurrentSyncCommittee<T>>::put(next);
+				}
+				let root = Self::insert_finalized_header(update.finalized_header);
+				Self::deposit_event(Event::<T>::HeaderFinalized(
+					update.finalized_header.slot,
+					root,
+				));
+			}
+			Ok(())
+		}
+
+		/// Stores the finalized header, pruning the oldest one if `MaxFinalizedHeaders` is
+		/// reached
+		fn insert_finalized_header(header: BeaconBlockHeader) -> H256 {
+			let root = ssz::header_root(&header);
+			let index = <FinalizedHeaderIndex<T>>::get();
+			if let Some(pruned) = <FinalizedHeaderRoots<T>>::get(index) {
+				<FinalizedHeaders<T>>::remove(pruned);
+			}
+			<FinalizedHeaderRoots<T>>::insert(index, root);
+			<FinalizedHeaders<T>>::insert(root, header);
+			<FinalizedHeaderIndex<T>>::put(
+				index.saturating_add(1) % T::MaxFinalizedHeaders::get().max(1),
+			);
+			<LatestFinalizedHeader<T>>::put(header);
+			root
+		}
+
+		/// Verifies the log proof and returns the message emitted by the bridge contract of the
+		/// network
+		pub fn verify_log_proof(network: Network, proof: &LogProof) -> Result<Message, Error<T>> {
+			let config = <ChainConfiguration<T>>::get().ok_or(Error::<T>::NotInitialized)?;
+			let contract =
+				<BridgeContracts<T>>::get(network).ok_or(Error::<T>::UnknownBridgeContract)?;
+			let finalized = <FinalizedHeaders<T>>::get(proof.finalized_block_root)
+				.ok_or(Error::<T>::HeaderNotFinalized)?;
+
+			// Blocks other than the finalized one are proven by the `block_roots` of its state
+			let header_root = ssz::header_root(&proof.header);
+			if header_root != proof.finalized_block_root {
+				ensure!(
+					proof.header.slot < finalized.slot
+						&& finalized.slot - proof.header.slot <= SLOTS_PER_HISTORICAL_ROOT,
+					Error::<T>::InvalidAncestryProof
+				);
+				let gindex = config
+					.block_roots_gindex
+					.saturating_mul(SLOTS_PER_HISTORICAL_ROOT)
+					.saturating_add(proof.header.slot % SLOTS_PER_HISTORICAL_ROOT);
+				ensure!(
+					ssz::is_valid_merkle_branch(
+						header_root,
+						&proof.ancestry_branch,
+						gindex,
+						finalized.state_root,
+					),
+					Error::<T>::InvalidAncestryProof
+				);
+			}
+			ensure!(
+				ssz::is_valid_merkle_branch(
+					proof.receipts_root,
+					&proof.receipts_root_branch,
+					config.receipts_root_gindex,
+					proof.header.body_root,
+				),
+				Error::<T>::InvalidReceiptsRootProof
+			);
+
+			let receipt = receipt::verify_proof(
+				proof.receipts_root,
+				&receipt::encode_index(proof.transaction_index),
+				&proof.receipt_proof,
+			)
+			.ok_or(Error::<T>::InvalidReceiptProof)?;
+			let log =
+				receipt::decode_log(&receipt, proof.log_index).ok_or(Error::<T>::LogNotFound)?;
+			ensure!(
+				log.address == contract.address
+					&& log.topics.first() == Some(&contract.event_signature),
+				Error::<T>::InvalidLog
+			);
+
+			// The event carries the ABI encoded message as its only field
+			let message = match abi::decode(&[ParamType::Bytes], &log.data).as_deref() {
+				Ok([Token::Bytes(message)]) => abi::decode_message(message).ok(),
+				_ => None,
+			}
+			.filter(|message| message.network == network)
+			.ok_or(Error::<T>::InvalidMessage)?;
+			Ok(message)
+		}
+	}
+
+	impl<T: Config> Contains<Network> for Pallet<T> {
+		/// Networks whose deposits must be proven to the light client
+		fn contains(network: &Network) -> bool {
+			<BridgeContracts<T>>::contains_key(network)
+		}
+	}
+}
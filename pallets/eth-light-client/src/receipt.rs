@@ -0,0 +1,198 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+//! Receipt inclusion proofs against the receipts root of an execution payload.
+
+use crate::types::Log;
+use sp_core::{H160, H256};
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+/// RLP item
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Rlp<'a> {
+	String(&'a [u8]),
+	List(&'a [u8]),
+}
+
+/// Decodes the first RLP item of the data, returning it with its raw encoding
+fn decode_item(data: &[u8]) -> Option<(Rlp<'_>, &[u8], &[u8])> {
+	let prefix = *data.first()?;
+	let (offset, len, is_list) = match prefix {
+		0x00..=0x7f => (0, 1, false),
+		0x80..=0xb7 => (1, (prefix - 0x80) as usize, false),
+		0xb8..=0xbf => {
+			let len_of_len = (prefix - 0xb7) as usize;
+			(1 + len_of_len, decode_length(data.get(1..1 + len_of_len)?)?, false)
+		},
+		0xc0..=0xf7 => (1, (prefix - 0xc0) as usize, true),
+		0xf8..=0xff => {
+			let len_of_len = (prefix - 0xf7) as usize;
+			(1 + len_of_len, decode_length(data.get(1..1 + len_of_len)?)?, true)
+		},
+	};
+	let end = offset.checked_add(len)?;
+	let payload = data.get(offset..end)?;
+	let item = if is_list { Rlp::List(payload) } else { Rlp::String(payload) };
+	Some((item, &data[..end], &data[end..]))
+}
+
+fn decode_length(bytes: &[u8]) -> Option<usize> {
+	if bytes.len() > 8 || bytes.first() == Some(&0) {
+		return None;
+	}
+	let len = bytes.iter().fold(0u64, |len, byte| (len << 8) | *byte as u64);
+	usize::try_from(len).ok()
+}
+
+/// Decodes the items of an RLP list with their raw encodings
+fn decode_list(data: &[u8]) -> Option<Vec<(Rlp<'_>, &[u8])>> {
+	let (item, _, rest) = decode_item(data)?;
+	let mut payload = match (item, rest.is_empty()) {
+		(Rlp::List(payload), true) => payload,
+		_ => return None,
+	};
+	let mut items = Vec::new();
+	while !payload.is_empty() {
+		let (item, raw, rest) = decode_item(payload)?;
+		items.push((item, raw));
+		payload = rest;
+	}
+	Some(items)
+}
+
+fn string<'a>(item: &(Rlp<'a>, &'a [u8])) -> Option<&'a [u8]> {
+	match item.0 {
+		Rlp::String(bytes) => Some(bytes),
+		Rlp::List(_) => None,
+	}
+}
+
+/// Returns the RLP encoding of the integer, the key of a receipt in the receipts trie
+pub fn encode_index(index: u64) -> Vec<u8> {
+	match index {
+		0 => sp_std::vec![0x80],
+		1..=0x7f => sp_std::vec![index as u8],
+		_ => {
+			let bytes = index.to_be_bytes();
+			let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(7);
+			let mut encoded = sp_std::vec![0x80 + (8 - start) as u8];
+			encoded.extend_from_slice(&bytes[start..]);
+			encoded
+		},
+	}
+}
+
+/// Decodes the hex prefix encoded path of a leaf or extension node
+fn decode_path(encoded: &[u8]) -> Option<(Vec<u8>, bool)> {
+	let flags = *encoded.first()? >> 4;
+	let is_leaf = flags & 2 == 2;
+	let mut nibbles = Vec::new();
+	if flags & 1 == 1 {
+		nibbles.push(encoded[0] & 0x0f);
+	}
+	for byte in &encoded[1..] {
+		nibbles.push(byte >> 4);
+		nibbles.push(byte & 0x0f);
+	}
+	Some((nibbles, is_leaf))
+}
+
+/// Reference of a child node, embedded if its encoding is shorter than 32 bytes
+enum NodeRef<'a> {
+	Hash(H256),
+	Inline(&'a [u8]),
+}
+
+fn node_ref<'a>(item: &(Rlp, &'a [u8])) -> Option<NodeRef<'a>> {
+	match item.0 {
+		Rlp::String(hash) if hash.len() == 32 => Some(NodeRef::Hash(H256::from_slice(hash))),
+		Rlp::List(_) if item.1.len() < 32 => Some(NodeRef::Inline(item.1)),
+		_ => None,
+	}
+}
+
+/// Verifies the Merkle Patricia proof of the key and returns its value
+pub fn verify_proof(root: H256, key: &[u8], proof: &[Vec<u8>]) -> Option<Vec<u8>> {
+	let mut nibbles: Vec<u8> = key.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]).collect();
+	let mut proof = proof.iter();
+	let mut expected_hash = Some(root);
+	let mut inline: &[u8] = &[];
+	loop {
+		let node: &[u8] = match expected_hash.take() {
+			Some(hash) => {
+				let node = proof.next()?;
+				if H256(keccak_256(node)) != hash {
+					return None;
+				}
+				node
+			},
+			None => inline,
+		};
+		let items = decode_list(node)?;
+		let child = match items.len() {
+			17 => {
+				let Some((nibble, rest)) = nibbles.split_first() else {
+					return string(&items[16]).filter(|value| !value.is_empty()).map(Vec::from);
+				};
+				let child = &items[*nibble as usize];
+				nibbles = rest.to_vec();
+				child
+			},
+			2 => {
+				let (path, is_leaf) = decode_path(string(&items[0])?)?;
+				if is_leaf {
+					return (path == nibbles).then(|| string(&items[1]).map(Vec::from)).flatten();
+				}
+				if !nibbles.starts_with(&path) {
+					return None;
+				}
+				nibbles.drain(..path.len());
+				&items[1]
+			},
+			_ => return None,
+		};
+		match node_ref(child)? {
+			NodeRef::Hash(hash) => expected_hash = Some(hash),
+			NodeRef::Inline(node) => inline = node,
+		}
+	}
+}
+
+/// Decodes the log at the index of the consensus encoded receipt
+pub fn decode_log(receipt: &[u8], log_index: u32) -> Option<Log> {
+	// Typed receipts are prefixed with their transaction type
+	let receipt = match receipt.first()? {
+		0x00..=0x7f => &receipt[1..],
+		_ => receipt,
+	};
+	let fields = decode_list(receipt)?;
+	let logs = match fields.get(3)? {
+		(Rlp::List(_), raw) => decode_list(raw)?,
+		_ => return None,
+	};
+	let (_, log) = logs.get(log_index as usize)?;
+	match decode_list(log)?.as_slice() {
+		[address, (Rlp::List(_), topics), data] => {
+			let address = string(address).filter(|address| address.len() == 20)?;
+			let topics = decode_list(topics)?
+				.iter()
+				.map(|topic| string(topic).filter(|topic| topic.len() == 32).map(H256::from_slice))
+				.collect::<Option<Vec<_>>>()?;
+			Some(Log { address: H160::from_slice(address), topics, data: string(data)?.to_vec() })
+		},
+		_ => None,
+	}
+}
@@ -0,0 +1,108 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+//! SSZ hash tree roots and Merkle branches of the beacon chain containers.
+
+use crate::types::{BeaconBlockHeader, BlsPublicKey, SyncCommittee};
+use sp_core::H256;
+use sp_io::hashing::sha2_256;
+use sp_std::vec::Vec;
+
+/// Returns `sha256(left ++ right)`
+pub fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> H256 {
+	let mut data = [0u8; 64];
+	data[..32].copy_from_slice(left);
+	data[32..].copy_from_slice(right);
+	H256(sha2_256(&data))
+}
+
+/// Merkleizes the chunks, padding them with zero chunks to the next power of two
+pub fn merkleize(mut chunks: Vec<H256>) -> H256 {
+	let width = chunks.len().next_power_of_two();
+	chunks.resize(width, H256::zero());
+	while chunks.len() > 1 {
+		chunks = chunks.chunks(2).map(|pair| hash_pair(&pair[0].0, &pair[1].0)).collect();
+	}
+	chunks[0]
+}
+
+fn uint64_root(value: u64) -> H256 {
+	let mut chunk = H256::zero();
+	chunk.0[..8].copy_from_slice(&value.to_le_bytes());
+	chunk
+}
+
+pub fn header_root(header: &BeaconBlockHeader) -> H256 {
+	merkleize(sp_std::vec![
+		uint64_root(header.slot),
+		uint64_root(header.proposer_index),
+		header.parent_root,
+		header.state_root,
+		header.body_root,
+	])
+}
+
+pub fn pubkey_root(pubkey: &BlsPublicKey) -> H256 {
+	let mut left = [0u8; 32];
+	let mut right = [0u8; 32];
+	left.copy_from_slice(&pubkey[..32]);
+	right[..16].copy_from_slice(&pubkey[32..]);
+	hash_pair(&left, &right)
+}
+
+pub fn sync_committee_root(committee: &SyncCommittee) -> H256 {
+	let pubkeys = merkleize(committee.pubkeys.iter().map(pubkey_root).collect());
+	hash_pair(&pubkeys.0, &pubkey_root(&committee.aggregate_pubkey).0)
+}
+
+/// Returns the domain of the fork, `compute_domain` of the consensus specs
+pub fn compute_domain(
+	domain_type: [u8; 4],
+	fork_version: [u8; 4],
+	genesis_validators_root: H256,
+) -> H256 {
+	let mut version = H256::zero();
+	version.0[..4].copy_from_slice(&fork_version);
+	let fork_data_root = hash_pair(&version.0, &genesis_validators_root.0);
+	let mut domain = H256::zero();
+	domain.0[..4].copy_from_slice(&domain_type);
+	domain.0[4..].copy_from_slice(&fork_data_root.0[..28]);
+	domain
+}
+
+/// Returns the root of `SigningData`, the message signed by validators
+pub fn compute_signing_root(object_root: H256, domain: H256) -> H256 {
+	hash_pair(&object_root.0, &domain.0)
+}
+
+/// Verifies the branch of the leaf at the generalized index
+pub fn is_valid_merkle_branch(leaf: H256, branch: &[H256], gindex: u64, root: H256) -> bool {
+	if gindex == 0 {
+		return false;
+	}
+	let depth = 63 - gindex.leading_zeros() as usize;
+	if branch.len() != depth {
+		return false;
+	}
+	let mut value = leaf;
+	for (i, sibling) in branch.iter().enumerate() {
+		value = if (gindex >> i) & 1 == 1 {
+			hash_pair(&sibling.0, &value.0)
+		} else {
+			hash_pair(&value.0, &sibling.0)
+		};
+	}
+	value == root
+}
@@ -0,0 +1,296 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+use crate::{
+	fixtures, mock::*, ssz, types::MAX_RECEIPT_PROOF_SIZE, BridgeContracts, CurrentSyncCommittee,
+	Error, Event, FinalizedHeaders, LatestFinalizedHeader, NextSyncCommittee,
+};
+use frame_support::{assert_noop, assert_ok, traits::Contains};
+use sp_core::{H160, H256};
+use sp_runtime::DispatchError;
+use thea_primitives::ETHEREUM_NETWORK;
+
+fn bootstrap() {
+	let (config, header, committee) = fixtures::bootstrap();
+	assert_ok!(EthLightClient::force_checkpoint(RuntimeOrigin::root(), config, header, committee));
+}
+
+fn set_bridge_contract() {
+	let (contract, _, _) = fixtures::deposit_log_proof();
+	assert_ok!(EthLightClient::set_bridge_contract(
+		RuntimeOrigin::root(),
+		ETHEREUM_NETWORK,
+		Some(contract)
+	));
+}
+
+#[test]
+fn test_force_checkpoint_bootstraps_light_client() {
+	new_test_ext().execute_with(|| {
+		let (config, header, mut committee) = fixtures::bootstrap();
+		assert_noop!(
+			EthLightClient::force_checkpoint(
+				RuntimeOrigin::signed(1),
+				config.clone(),
+				header,
+				committee.clone()
+			),
+			DispatchError::BadOrigin
+		);
+		let pubkey = committee.pubkeys.pop().unwrap();
+		assert_noop!(
+			EthLightClient::force_checkpoint(
+				RuntimeOrigin::root(),
+				config.clone(),
+				header,
+				committee.clone()
+			),
+			Error::<Test>::InvalidSyncCommitteeSize
+		);
+		committee.pubkeys.push(pubkey);
+		assert_ok!(EthLightClient::force_checkpoint(
+			RuntimeOrigin::root(),
+			config.clone(),
+			header,
+			committee.clone()
+		));
+		let root = ssz::header_root(&header);
+		assert_eq!(EthLightClient::chain_config(), Some(config));
+		assert_eq!(<LatestFinalizedHeader<Test>>::get(), Some(header));
+		assert_eq!(EthLightClient::finalized_header(root), Some(header));
+		assert_eq!(<CurrentSyncCommittee<Test>>::get(), Some(committee));
+		System::assert_last_event(Event::CheckpointForced(header.slot, root).into());
+	})
+}
+
+#[test]
+fn test_submit_update_finalizes_header_and_next_sync_committee() {
+	new_test_ext().execute_with(|| {
+		let update = fixtures::update_with_next_sync_committee();
+		assert_noop!(
+			EthLightClient::submit_update(RuntimeOrigin::signed(1), update.clone()),
+			Error::<Test>::NotInitialized
+		);
+		bootstrap();
+		assert_ok!(EthLightClient::submit_update(RuntimeOrigin::signed(1), update.clone()));
+		let root = ssz::header_root(&update.finalized_header);
+		assert_eq!(<LatestFinalizedHeader<Test>>::get(), Some(update.finalized_header));
+		assert_eq!(EthLightClient::finalized_header(root), Some(update.finalized_header));
+		assert_eq!(
+			<NextSyncCommittee<Test>>::get(),
+			update.next_sync_committee.clone().map(|next| next.next_sync_committee)
+		);
+		System::assert_has_event(Event::SyncCommitteeUpdated(1001).into());
+		System::assert_last_event(
+			Event::HeaderFinalized(update.finalized_header.slot, root).into(),
+		);
+		// Neither a newer header nor a new sync committee
+		assert_noop!(
+			EthLightClient::submit_update(RuntimeOrigin::signed(1), update),
+			Error::<Test>::StaleUpdate
+		);
+	})
+}
+
+#[test]
+fn test_update_of_next_period_rotates_sync_committee() {
+	new_test_ext().execute_with(|| {
+		bootstrap();
+		let update = fixtures::update_next_period();
+		assert_noop!(
+			EthLightClient::submit_update(RuntimeOrigin::signed(1), update.clone()),
+			Error::<Test>::NextSyncCommitteeUnknown
+		);
+		let previous_update = fixtures::update_with_next_sync_committee();
+		assert_ok!(EthLightClient::submit_update(
+			RuntimeOrigin::signed(1),
+			previous_update.clone()
+		));
+		assert_ok!(EthLightClient::submit_update(RuntimeOrigin::signed(1), update.clone()));
+		assert_eq!(<LatestFinalizedHeader<Test>>::get(), Some(update.finalized_header));
+		assert_eq!(
+			<CurrentSyncCommittee<Test>>::get(),
+			previous_update.next_sync_committee.map(|next| next.next_sync_committee)
+		);
+		assert_eq!(<NextSyncCommittee<Test>>::get(), None);
+		// Only the two latest finalized headers are kept
+		let (_, header, _) = fixtures::bootstrap();
+		assert_eq!(EthLightClient::finalized_header(ssz::header_root(&header)), None);
+		assert_eq!(<FinalizedHeaders<Test>>::iter().count(), 2);
+	})
+}
+
+#[test]
+fn test_submit_update_rejects_invalid_updates() {
+	new_test_ext().execute_with(|| {
+		bootstrap();
+		let update = fixtures::update_with_next_sync_committee();
+
+		// Signer removed from the participants
+		let mut invalid = update.clone();
+		invalid.sync_aggregate.sync_committee_bits[63] &= 0x7f;
+		assert_noop!(
+			EthLightClient::submit_update(RuntimeOrigin::signed(1), invalid),
+			Error::<Test>::InvalidSignature
+		);
+
+		let mut invalid = update.clone();
+		invalid.sync_aggregate.sync_committee_bits[20..].fill(0);
+		assert_noop!(
+			EthLightClient::submit_update(RuntimeOrigin::signed(1), invalid),
+			Error::<Test>::InsufficientParticipation
+		);
+
+		let mut invalid = update.clone();
+		invalid.finalized_header.proposer_index += 1;
+		assert_noop!(
+			EthLightClient::submit_update(RuntimeOrigin::signed(1), invalid),
+			Error::<Test>::InvalidFinalityProof
+		);
+
+		let mut invalid = update.clone();
+		if let Some(next) = invalid.next_sync_committee.as_mut() {
+			next.next_sync_committee.pubkeys.swap(0, 1);
+		}
+		assert_noop!(
+			EthLightClient::submit_update(RuntimeOrigin::signed(1), invalid),
+			Error::<Test>::InvalidNextSyncCommittee
+		);
+
+		let mut invalid = update.clone();
+		invalid.attested_header.proposer_index += 1;
+		assert_noop!(
+			EthLightClient::submit_update(RuntimeOrigin::signed(1), invalid),
+			Error::<Test>::InvalidSignature
+		);
+
+		let mut invalid = update;
+		invalid.signature_slot = invalid.attested_header.slot;
+		assert_noop!(
+			EthLightClient::submit_update(RuntimeOrigin::signed(1), invalid),
+			Error::<Test>::InvalidUpdateSlots
+		);
+	})
+}
+
+#[test]
+fn test_submit_deposit_proof_hands_over_message() {
+	new_test_ext().execute_with(|| {
+		let (contract, proof, message) = fixtures::deposit_log_proof();
+		bootstrap();
+		assert!(!EthLightClient::contains(&ETHEREUM_NETWORK));
+		assert_noop!(
+			EthLightClient::submit_deposit_proof(
+				RuntimeOrigin::signed(1),
+				ETHEREUM_NETWORK,
+				proof.clone()
+			),
+			Error::<Test>::UnknownBridgeContract
+		);
+		set_bridge_contract();
+		assert_eq!(<BridgeContracts<Test>>::get(ETHEREUM_NETWORK), Some(contract));
+		assert!(EthLightClient::contains(&ETHEREUM_NETWORK));
+		// Deposit block is not finalized yet
+		assert_noop!(
+			EthLightClient::submit_deposit_proof(
+				RuntimeOrigin::signed(1),
+				ETHEREUM_NETWORK,
+				proof.clone()
+			),
+			Error::<Test>::HeaderNotFinalized
+		);
+		assert_ok!(EthLightClient::submit_update(
+			RuntimeOrigin::signed(1),
+			fixtures::update_with_next_sync_committee()
+		));
+		assert_ok!(EthLightClient::submit_deposit_proof(
+			RuntimeOrigin::signed(2),
+			ETHEREUM_NETWORK,
+			proof
+		));
+		assert_eq!(message.network, ETHEREUM_NETWORK);
+		PROVEN_MESSAGES.with(|messages| assert_eq!(*messages.borrow(), vec![(2, message.clone())]));
+		System::assert_last_event(Event::DepositProven(ETHEREUM_NETWORK, message.nonce).into());
+	})
+}
+
+#[test]
+fn test_submit_deposit_proof_rejects_invalid_proofs() {
+	new_test_ext().execute_with(|| {
+		bootstrap();
+		set_bridge_contract();
+		assert_ok!(EthLightClient::submit_update(
+			RuntimeOrigin::signed(1),
+			fixtures::update_with_next_sync_committee()
+		));
+		let (contract, proof, _) = fixtures::deposit_log_proof();
+		let assert_invalid = |proof, error: Error<Test>| {
+			assert_noop!(
+				EthLightClient::submit_deposit_proof(
+					RuntimeOrigin::signed(1),
+					ETHEREUM_NETWORK,
+					proof
+				),
+				error
+			);
+		};
+
+		let mut invalid = proof.clone();
+		invalid.ancestry_branch[3] = H256::zero();
+		assert_invalid(invalid, Error::<Test>::InvalidAncestryProof);
+
+		let mut invalid = proof.clone();
+		invalid.receipts_root = H256::zero();
+		assert_invalid(invalid, Error::<Test>::InvalidReceiptsRootProof);
+
+		let mut invalid = proof.clone();
+		invalid.transaction_index = 2;
+		assert_invalid(invalid, Error::<Test>::InvalidReceiptProof);
+
+		let mut invalid = proof.clone();
+		invalid.receipt_proof[2][200] ^= 1;
+		assert_invalid(invalid, Error::<Test>::InvalidReceiptProof);
+
+		// Log of another contract in the same receipt
+		let mut invalid = proof.clone();
+		invalid.log_index = 0;
+		assert_invalid(invalid, Error::<Test>::InvalidLog);
+
+		let mut invalid = proof.clone();
+		invalid.log_index = 2;
+		assert_invalid(invalid, Error::<Test>::LogNotFound);
+
+		let mut invalid = proof.clone();
+		invalid.receipt_proof.push(vec![0u8; MAX_RECEIPT_PROOF_SIZE as usize]);
+		assert_invalid(invalid, Error::<Test>::ReceiptProofTooLarge);
+
+		// Message of another network emitted by the bridge contract
+		assert_ok!(EthLightClient::set_bridge_contract(RuntimeOrigin::root(), 3, Some(contract)));
+		assert_noop!(
+			EthLightClient::submit_deposit_proof(RuntimeOrigin::signed(1), 3, proof.clone()),
+			Error::<Test>::InvalidMessage
+		);
+
+		let mut other_contract = contract;
+		other_contract.address = H160::repeat_byte(1);
+		assert_ok!(EthLightClient::set_bridge_contract(
+			RuntimeOrigin::root(),
+			ETHEREUM_NETWORK,
+			Some(other_contract)
+		));
+		assert_invalid(proof, Error::<Test>::InvalidLog);
+		PROVEN_MESSAGES.with(|messages| assert!(messages.borrow().is_empty()));
+	})
+}
@@ -0,0 +1,153 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+//! Types of the beacon chain light client.
+
+use frame_support::pallet_prelude::*;
+use sp_core::{H160, H256};
+use sp_std::vec::Vec;
+
+/// Number of validators in a sync committee
+pub const SYNC_COMMITTEE_SIZE: usize = 512;
+/// Slots per epoch
+pub const SLOTS_PER_EPOCH: u64 = 32;
+/// Epochs per sync committee period
+pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: u64 = 256;
+/// Length of the `block_roots` vector of the beacon state
+pub const SLOTS_PER_HISTORICAL_ROOT: u64 = 8192;
+/// Domain type of sync committee signatures
+pub const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [7, 0, 0, 0];
+/// Maximum total size in bytes of the receipt trie nodes of a log proof
+pub const MAX_RECEIPT_PROOF_SIZE: u32 = 64 * 1024;
+
+/// Compressed BLS12-381 G1 public key
+pub type BlsPublicKey = [u8; 48];
+/// Compressed BLS12-381 G2 signature
+pub type BlsSignature = [u8; 96];
+
+/// Returns the sync committee period of the slot
+pub fn sync_committee_period(slot: u64) -> u64 {
+	slot / SLOTS_PER_EPOCH / EPOCHS_PER_SYNC_COMMITTEE_PERIOD
+}
+
+#[derive(Clone, Copy, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct BeaconBlockHeader {
+	pub slot: u64,
+	pub proposer_index: u64,
+	pub parent_root: H256,
+	pub state_root: H256,
+	pub body_root: H256,
+}
+
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct SyncCommittee {
+	pub pubkeys: Vec<BlsPublicKey>,
+	pub aggregate_pubkey: BlsPublicKey,
+}
+
+/// Parameters of the tracked beacon chain. The generalized indices depend on the fork, the
+/// ones of Deneb are `105`, `55`, `37` and `803`.
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct ChainConfig {
+	pub genesis_validators_root: H256,
+	/// Fork version signed by the sync committee
+	pub fork_version: [u8; 4],
+	/// Generalized index of the finalized checkpoint root in the beacon state
+	pub finalized_root_gindex: u64,
+	/// Generalized index of the next sync committee in the beacon state
+	pub next_sync_committee_gindex: u64,
+	/// Generalized index of the `block_roots` vector in the beacon state
+	pub block_roots_gindex: u64,
+	/// Generalized index of the execution payload receipts root in the beacon block body
+	pub receipts_root_gindex: u64,
+}
+
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct SyncAggregate {
+	/// Participation bitfield of the sync committee, little endian bit order
+	pub sync_committee_bits: Vec<u8>,
+	pub sync_committee_signature: BlsSignature,
+}
+
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct NextSyncCommitteeUpdate {
+	pub next_sync_committee: SyncCommittee,
+	/// Branch of the next sync committee in the attested state
+	pub next_sync_committee_branch: Vec<H256>,
+}
+
+/// Light client update as served by `/eth/v1/beacon/light_client/updates`
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct LightClientUpdate {
+	/// Header signed by the sync committee
+	pub attested_header: BeaconBlockHeader,
+	pub next_sync_committee: Option<NextSyncCommitteeUpdate>,
+	/// Header finalized in the attested state
+	pub finalized_header: BeaconBlockHeader,
+	/// Branch of the finalized header root in the attested state
+	pub finality_branch: Vec<H256>,
+	pub sync_aggregate: SyncAggregate,
+	/// Slot at which the sync aggregate was included
+	pub signature_slot: u64,
+}
+
+/// Proof of a log emitted in a block that is, or is an ancestor of, a finalized header
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct LogProof {
+	/// Root of the finalized header stored by the light client
+	pub finalized_block_root: H256,
+	/// Header of the block containing the log
+	pub header: BeaconBlockHeader,
+	/// Branch of the header root in the `block_roots` of the finalized state, empty if
+	/// `header` is the finalized header
+	pub ancestry_branch: Vec<H256>,
+	/// Receipts root of the execution payload
+	pub receipts_root: H256,
+	/// Branch of the receipts root in the block body
+	pub receipts_root_branch: Vec<H256>,
+	/// Index of the transaction in the execution block
+	pub transaction_index: u64,
+	/// Nodes of the receipts trie from the root to the receipt
+	pub receipt_proof: Vec<Vec<u8>>,
+	/// Index of the log in the receipt
+	pub log_index: u32,
+}
+
+impl LogProof {
+	/// Total size in bytes of the receipt trie nodes, which are hashed and decoded to verify
+	/// the receipt
+	pub fn receipt_proof_size(&self) -> u32 {
+		return self
+			.receipt_proof
+			.iter()
+			.fold(0u32, |size, node| size.saturating_add(node.len() as u32));
+	}
+}
+
+/// Bridge contract emitting Thea messages as `event TheaMessage(bytes message)`
+#[derive(Clone, Copy, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct BridgeContract {
+	pub address: H160,
+	/// Topic of the event, i.e. `keccak256("TheaMessage(bytes)")`
+	pub event_signature: H256,
+}
+
+/// Log of an execution layer receipt
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Log {
+	pub address: H160,
+	pub topics: Vec<H256>,
+	pub data: Vec<u8>,
+}
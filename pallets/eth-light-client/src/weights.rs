@@ -0,0 +1,110 @@
+//! Weights for `eth_light_client`
+//!
+//! Placeholder weights, not produced by the benchmark CLI: regenerate with the command below.
+
+// Command to regenerate:
+// ./polkadex-node
+// benchmark
+// pallet
+// --pallet
+// eth-light-client
+// --steps
+// 100
+// --repeat
+// 150
+// --extrinsic
+// *
+// --output
+// weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `eth_light_client`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::EthLightClientWeightInfo for WeightInfo<T> {
+	/// Storage: `EthLightClient::FinalizedHeaderIndex` (r:1 w:1)
+	/// Proof: `EthLightClient::FinalizedHeaderIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::FinalizedHeaderRoots` (r:1 w:1)
+	/// Proof: `EthLightClient::FinalizedHeaderRoots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::ChainConfiguration` (r:0 w:1)
+	/// Proof: `EthLightClient::ChainConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::CurrentSyncCommittee` (r:0 w:1)
+	/// Proof: `EthLightClient::CurrentSyncCommittee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::NextSyncCommittee` (r:0 w:1)
+	/// Proof: `EthLightClient::NextSyncCommittee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::FinalizedHeaders` (r:0 w:1)
+	/// Proof: `EthLightClient::FinalizedHeaders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::LatestFinalizedHeader` (r:0 w:1)
+	/// Proof: `EthLightClient::LatestFinalizedHeader` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn force_checkpoint() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(274_630_000, 0)
+			.saturating_add(Weight::from_parts(0, 3471))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `EthLightClient::ChainConfiguration` (r:1 w:0)
+	/// Proof: `EthLightClient::ChainConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::LatestFinalizedHeader` (r:1 w:1)
+	/// Proof: `EthLightClient::LatestFinalizedHeader` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::CurrentSyncCommittee` (r:1 w:0)
+	/// Proof: `EthLightClient::CurrentSyncCommittee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::NextSyncCommittee` (r:1 w:1)
+	/// Proof: `EthLightClient::NextSyncCommittee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::FinalizedHeaderIndex` (r:1 w:1)
+	/// Proof: `EthLightClient::FinalizedHeaderIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::FinalizedHeaderRoots` (r:1 w:1)
+	/// Proof: `EthLightClient::FinalizedHeaderRoots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::FinalizedHeaders` (r:0 w:1)
+	/// Proof: `EthLightClient::FinalizedHeaders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn submit_update() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(151_397_260_000, 0)
+			.saturating_add(Weight::from_parts(0, 28543))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `EthLightClient::BridgeContracts` (r:0 w:1)
+	/// Proof: `EthLightClient::BridgeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_bridge_contract() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(9_930_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EthLightClient::ChainConfiguration` (r:1 w:0)
+	/// Proof: `EthLightClient::ChainConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::BridgeContracts` (r:1 w:0)
+	/// Proof: `EthLightClient::BridgeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthLightClient::FinalizedHeaders` (r:1 w:0)
+	/// Proof: `EthLightClient::FinalizedHeaders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::IncomingNonce` (r:1 w:0)
+	/// Proof: `Thea::IncomingNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::IncomingMessagesQueue` (r:1 w:1)
+	/// Proof: `Thea::IncomingMessagesQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Thea::Relayers` (r:1 w:1)
+	/// Proof: `Thea::Relayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::ActiveRelayers` (r:1 w:1)
+	/// Proof: `Thea::ActiveRelayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1046, 65536]`.
+	fn submit_deposit_proof(x: u32, ) -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(1_213_940_000, 0)
+			.saturating_add(Weight::from_parts(0, 3861))
+			.saturating_add(Weight::from_parts(20_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+}
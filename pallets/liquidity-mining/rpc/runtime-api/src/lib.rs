@@ -0,0 +1,42 @@
+// This file is part of Polkadex.
+//
+// Copyright (c) 2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use orderbook_primitives::types::TradingPair;
+use pallet_lmp::types::{MarketMakerConfig, PoolFlags};
+use parity_scale_codec::Codec;
+use rust_decimal::Decimal;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait PolkadexLmpRuntimeApi<AccountId, Balance> where AccountId: Codec, Balance: Codec {
+		// Returns all pools with their market maker, config, pause flags and high-water mark
+		fn list_pools() -> Vec<(TradingPair, AccountId, MarketMakerConfig<AccountId>, PoolFlags, Option<Decimal>)>;
+		// Returns the shares of the pool held by the LP
+		fn get_lp_shares(market: TradingPair, market_maker: AccountId, lp: AccountId) -> Balance;
+		// Returns the value of the pool in quote asset and its NAV per share
+		fn get_pool_nav(market: TradingPair, market_maker: AccountId) -> Result<(Decimal, Decimal), sp_runtime::DispatchError>;
+		// Returns the NAV per share of the pool at the end of given epoch and the shares minted as performance fee
+		fn get_performance_fee(epoch: u16, market: TradingPair, market_maker: AccountId) -> Option<(Decimal, Balance)>;
+		// Returns the pending withdrawal requests of the pool queued in given epoch
+		fn get_pending_withdrawals(epoch: u16, market: TradingPair, market_maker: AccountId) -> Vec<(AccountId, Balance, Balance)>;
+		// Returns the rewards of the LP in given epoch and if they are already claimed
+		fn get_claimable_rewards(market: TradingPair, market_maker: AccountId, epoch: u16, lp: AccountId) -> (Balance, bool);
+	}
+}
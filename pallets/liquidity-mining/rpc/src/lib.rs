@@ -0,0 +1,294 @@
+// This file is part of Polkadex.
+//
+// Copyright (c) 2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! This crate provides RPC methods for the crowd-sourced liquidity mining pallet - pools, LP
+//! positions and their valuation.
+
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	tracing::log,
+	types::error::{CallError, ErrorObject},
+};
+use orderbook_primitives::types::TradingPair;
+pub use pallet_lmp_runtime_api::PolkadexLmpRuntimeApi;
+use parity_scale_codec::Codec;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::offchain::{storage::OffchainDb, OffchainDbExt, OffchainStorage};
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+const RUNTIME_ERROR: i32 = 1;
+
+/// A crowd-sourced pool as returned by `lmpPool_list`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PoolInfo<AccountId> {
+	pub market: String,
+	pub market_maker: AccountId,
+	pub pool_id: AccountId,
+	pub name: String,
+	pub share_id: String,
+	pub commission: String,
+	pub exit_fee: String,
+	pub performance_fee: String,
+	pub high_water_mark: Option<String>,
+	pub public_funds_allowed: bool,
+	pub force_closed: bool,
+	pub deposits_paused: bool,
+	pub withdrawals_paused: bool,
+}
+
+#[rpc(client, server)]
+pub trait PolkadexLmpRpcApi<BlockHash, AccountId, Balance> {
+	#[method(name = "lmpPool_list")]
+	fn list_pools(&self, at: Option<BlockHash>) -> RpcResult<Vec<PoolInfo<AccountId>>>;
+
+	#[method(name = "lmpPool_lpShares")]
+	fn get_lp_shares(
+		&self,
+		market: String,
+		market_maker: AccountId,
+		lp: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<String>;
+
+	/// Returns (pool value in quote asset, NAV per share)
+	#[method(name = "lmpPool_nav")]
+	fn get_pool_nav(
+		&self,
+		market: String,
+		market_maker: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<(String, String)>;
+
+	/// Returns (NAV per share, shares minted as performance fee) of the pool at the end of given
+	/// epoch
+	#[method(name = "lmpPool_performanceFee")]
+	fn get_performance_fee(
+		&self,
+		epoch: u16,
+		market: String,
+		market_maker: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(String, String)>>;
+
+	/// Returns (LP, shares burned, total shares) of every pending withdrawal request
+	#[method(name = "lmpPool_pendingWithdrawals")]
+	fn get_pending_withdrawals(
+		&self,
+		epoch: u16,
+		market: String,
+		market_maker: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, String, String)>>;
+
+	/// Returns (rewards, is_claimed) of the LP in given epoch
+	#[method(name = "lmpPool_claimableRewards")]
+	fn get_claimable_rewards(
+		&self,
+		market: String,
+		market_maker: AccountId,
+		epoch: u16,
+		lp: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<(String, bool)>;
+}
+
+/// A structure that represents the Polkadex liquidity mining pallet RPC, which allows querying
+/// crowd-sourced pools and the positions of their LPs.
+///
+/// # Type Parameters
+///
+/// * `Client`: The client API used to interact with the Substrate runtime.
+/// * `Block`: The block type of the Substrate.
+pub struct PolkadexLmpRpc<Client, Block, T: OffchainStorage + 'static> {
+	/// An `Arc` reference to the client API for accessing runtime functionality.
+	client: Arc<Client>,
+
+	/// Offchain storage, pool balances are read from the OCEX offchain state
+	offchain_db: OffchainDb<T>,
+
+	/// A marker for the `Block` type parameter, used to ensure the struct
+	/// is covariant with respect to the block type.
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<Client, Block, T: OffchainStorage> PolkadexLmpRpc<Client, Block, T> {
+	pub fn new(client: Arc<Client>, storage: T) -> Self {
+		Self { client, offchain_db: OffchainDb::new(storage), _marker: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<Client, Block, AccountId, Balance, T>
+	PolkadexLmpRpcApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+	for PolkadexLmpRpc<Client, Block, T>
+where
+	Block: BlockT,
+	Client: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	Client::Api: PolkadexLmpRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + Clone + Serialize + for<'de> Deserialize<'de> + Send + Sync + 'static,
+	Balance: Codec + ToString,
+	T: OffchainStorage + 'static,
+{
+	fn list_pools(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<PoolInfo<AccountId>>> {
+		let api = self.client.runtime_api();
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let pools = api.list_pools(at).map_err(runtime_error_into_rpc_err)?;
+		Ok(pools
+			.into_iter()
+			.map(|(market, market_maker, config, flags, high_water_mark)| PoolInfo {
+				market: market.to_string(),
+				market_maker,
+				pool_id: config.pool_id,
+				name: String::from_utf8_lossy(&config.name).trim_end_matches('\0').to_string(),
+				share_id: config.share_id.to_string(),
+				commission: config.commission.to_string(),
+				exit_fee: config.exit_fee.to_string(),
+				performance_fee: config.performance_fee.to_string(),
+				high_water_mark: high_water_mark.map(|nav_per_share| nav_per_share.to_string()),
+				public_funds_allowed: config.public_funds_allowed,
+				force_closed: config.force_closed,
+				deposits_paused: flags.deposits_paused(),
+				withdrawals_paused: flags.withdrawals_paused(),
+			})
+			.collect())
+	}
+
+	fn get_lp_shares(
+		&self,
+		market: String,
+		market_maker: AccountId,
+		lp: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<String> {
+		let api = self.client.runtime_api();
+		let market = TradingPair::try_from(market).map_err(runtime_error_into_rpc_err)?;
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let shares = api
+			.get_lp_shares(at, market, market_maker, lp)
+			.map_err(runtime_error_into_rpc_err)?;
+		Ok(shares.to_string())
+	}
+
+	fn get_pool_nav(
+		&self,
+		market: String,
+		market_maker: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(String, String)> {
+		let mut api = self.client.runtime_api();
+		api.register_extension(OffchainDbExt::new(self.offchain_db.clone()));
+		let market = TradingPair::try_from(market).map_err(runtime_error_into_rpc_err)?;
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let (pool_value, nav_per_share) = api
+			.get_pool_nav(at, market, market_maker)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(runtime_error_into_rpc_err)?;
+		Ok((pool_value.to_string(), nav_per_share.to_string()))
+	}
+
+	fn get_performance_fee(
+		&self,
+		epoch: u16,
+		market: String,
+		market_maker: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<(String, String)>> {
+		let api = self.client.runtime_api();
+		let market = TradingPair::try_from(market).map_err(runtime_error_into_rpc_err)?;
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let performance_fee = api
+			.get_performance_fee(at, epoch, market, market_maker)
+			.map_err(runtime_error_into_rpc_err)?;
+		Ok(performance_fee
+			.map(|(nav_per_share, shares)| (nav_per_share.to_string(), shares.to_string())))
+	}
+
+	fn get_pending_withdrawals(
+		&self,
+		epoch: u16,
+		market: String,
+		market_maker: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, String, String)>> {
+		let api = self.client.runtime_api();
+		let market = TradingPair::try_from(market).map_err(runtime_error_into_rpc_err)?;
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let requests = api
+			.get_pending_withdrawals(at, epoch, market, market_maker)
+			.map_err(runtime_error_into_rpc_err)?;
+		Ok(requests
+			.into_iter()
+			.map(|(lp, burned, total)| (lp, burned.to_string(), total.to_string()))
+			.collect())
+	}
+
+	fn get_claimable_rewards(
+		&self,
+		market: String,
+		market_maker: AccountId,
+		epoch: u16,
+		lp: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(String, bool)> {
+		let api = self.client.runtime_api();
+		let market = TradingPair::try_from(market).map_err(runtime_error_into_rpc_err)?;
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let (rewards, is_claimed) = api
+			.get_claimable_rewards(at, market, market_maker, epoch, lp)
+			.map_err(runtime_error_into_rpc_err)?;
+		Ok((rewards.to_string(), is_claimed))
+	}
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+	log::error!(target:"liquidity-mining","runtime rpc error: {:?} ",err);
+	CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, "Runtime error", Some(format!("{err:?}"))))
+		.into()
+}
@@ -0,0 +1,85 @@
+// This file is part of Polkadex.
+//
+// Copyright (c) 2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for pallet-lmp
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::{
+	pallet::{PendingEarlyExits, PoolFlagsOf, Pools},
+	types::MarketMakerConfig,
+	Pallet as LiquidityMining,
+};
+use frame_benchmarking::v1::{account, benchmarks};
+use frame_support::traits::fungibles::{Create, Mutate};
+use frame_system::RawOrigin;
+use orderbook_primitives::types::TradingPair;
+use polkadex_primitives::{AssetId, UNIT_BALANCE};
+use rust_decimal::Decimal;
+use sp_runtime::SaturatedConversion;
+
+fn create_pool<T: Config>() -> (TradingPair, T::AccountId, T::AccountId) {
+	let market = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+	let market_maker: T::AccountId = account("market_maker", 0, 0);
+	let pool_id: T::AccountId = account("pool", 0, 0);
+	let config = MarketMakerConfig {
+		pool_id: pool_id.clone(),
+		commission: Decimal::new(1, 1),
+		exit_fee: Decimal::new(1, 2),
+		performance_fee: Decimal::new(1, 1),
+		public_funds_allowed: true,
+		name: [1; 10],
+		share_id: 1,
+		force_closed: false,
+	};
+	<Pools<T>>::insert(market, &market_maker, config);
+	(market, market_maker, pool_id)
+}
+
+benchmarks! {
+	set_pool_paused {
+		let (market, market_maker, pool_id) = create_pool::<T>();
+	}: _(RawOrigin::Signed(market_maker), market, true)
+	verify {
+		assert!(<PoolFlagsOf<T>>::get(&pool_id).paused_by_market_maker);
+	}
+
+	force_set_pool_paused {
+		let (market, market_maker, pool_id) = create_pool::<T>();
+	}: _(RawOrigin::Root, market, market_maker, true)
+	verify {
+		assert!(<PoolFlagsOf<T>>::get(&pool_id).paused_by_governance);
+	}
+
+	remove_liquidity_instantly {
+		let (market, market_maker, pool_id) = create_pool::<T>();
+		let lp: T::AccountId = account("lp", 0, 0);
+		T::OtherAssets::create(1, lp.clone(), true, 1u128.saturated_into())?;
+		T::OtherAssets::mint_into(1, &lp, (100 * UNIT_BALANCE).saturated_into())?;
+		let shares = (50 * UNIT_BALANCE).saturated_into();
+	}: _(RawOrigin::Signed(lp.clone()), market, market_maker, shares)
+	verify {
+		assert!(<PendingEarlyExits<T>>::contains_key(&pool_id, &lp));
+	}
+}
+
+#[cfg(test)]
+use frame_benchmarking::impl_benchmark_test_suite;
+
+#[cfg(test)]
+impl_benchmark_test_suite!(LiquidityMining, crate::mock::new_test_ext(), crate::mock::Test);
@@ -17,8 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::pallet::{
-	AddLiquidityRecords, Config, Error, Event, LMPEpoch, Pallet, Pools, SnapshotFlag,
-	WithdrawingEpoch,
+	AddLiquidityRecords, Config, Error, Event, LMPEpoch, LPShares, Pallet, Pools, SnapshotCursor,
+	SnapshotFlag, WithdrawingEpoch,
 };
 use frame_support::{
 	dispatch::DispatchResult,
@@ -32,19 +32,20 @@ use sp_runtime::{traits::BlockNumberProvider, SaturatedConversion};
 impl<T: Config> LiquidityMiningCrowdSourcePallet<T::AccountId> for Pallet<T> {
 	fn new_epoch(new_epoch: u16) {
 		<LMPEpoch<T>>::put(new_epoch);
-		// Set the flag for triggering offchain worker
+		// Set the flag for triggering offchain worker, pools are scored from the first one
 		<SnapshotFlag<T>>::put(frame_system::Pallet::<T>::current_block_number());
+		<SnapshotCursor<T>>::kill();
 	}
 
 	fn add_liquidity_success(
 		market: TradingPair,
-		market_maker: &T::AccountId,
+		pool: &T::AccountId,
 		lp: &T::AccountId,
 		shared_issued: Decimal,
 		price: Decimal,
 		total_inventory_in_quote: Decimal,
 	) -> DispatchResult {
-		let pool_config = <Pools<T>>::get(market, market_maker).ok_or(Error::<T>::UnknownPool)?;
+		let (_, pool_config) = Self::pool_config(market, pool)?;
 		let new_shared_issued = shared_issued
 			.saturating_mul(Decimal::from(UNIT_BALANCE))
 			.to_u128()
@@ -62,6 +63,9 @@ impl<T: Config> LiquidityMiningCrowdSourcePallet<T::AccountId> for Pallet<T> {
 			.ok_or(Error::<T>::ConversionError)?
 			.saturated_into();
 		T::OtherAssets::mint_into(pool_config.share_id, lp, new_shared_issued.saturated_into())?;
+		<LPShares<T>>::mutate(pool_config.share_id, lp, |shares| {
+			*shares = shares.saturating_add(new_shared_issued.saturated_into())
+		});
 		// Note the block in which they deposited and
 		// use it to pro-rate the rewards for initial epoch
 
@@ -74,7 +78,7 @@ impl<T: Config> LiquidityMiningCrowdSourcePallet<T::AccountId> for Pallet<T> {
 
 		Self::deposit_event(Event::<T>::LiquidityAdded {
 			market,
-			pool: market_maker.clone(),
+			pool: pool.clone(),
 			lp: lp.clone(),
 			shares: new_shared_issued.saturated_into(),
 			share_id: polkadex_primitives::AssetId::Asset(pool_config.share_id),
@@ -112,64 +116,70 @@ impl<T: Config> LiquidityMiningCrowdSourcePallet<T::AccountId> for Pallet<T> {
 		base_required: Decimal,
 		quote_required: Decimal,
 	) -> DispatchResult {
-		let shares_burned = total_shares.saturating_mul(burn_frac);
-		let burn_frac = burn_frac
-			.saturating_mul(Decimal::from(UNIT_BALANCE))
-			.to_u128()
-			.ok_or(Error::<T>::ConversionError)?
-			.saturated_into();
-
-		let shares_burned = shares_burned
+		let shares_burned = total_shares
+			.saturating_mul(burn_frac)
 			.saturating_mul(Decimal::from(UNIT_BALANCE))
 			.to_u128()
 			.ok_or(Error::<T>::ConversionError)?
 			.saturated_into();
+		Self::mint_back_shares(
+			market,
+			pool,
+			lp,
+			shares_burned,
+			burn_frac,
+			base_free,
+			quote_free,
+			base_required,
+			quote_required,
+		)
+	}
 
-		// Mint back the shares here.
-		let pool_config = <Pools<T>>::get(market, pool).ok_or(Error::<T>::UnknownPool)?;
-		T::OtherAssets::mint_into(pool_config.share_id, lp, shares_burned)?;
+	fn remove_liquidity_instantly_success(
+		market: TradingPair,
+		pool: &T::AccountId,
+		lp: &T::AccountId,
+		base_free: Decimal,
+		quote_free: Decimal,
+		request_id: u64,
+	) -> DispatchResult {
+		Self::take_pending_early_exit(pool, lp, request_id)?;
+		Self::remove_liquidity_success(market, pool, lp, base_free, quote_free)
+	}
 
-		let base_free = base_free
-			.saturating_mul(Decimal::from(UNIT_BALANCE))
-			.to_u128()
-			.ok_or(Error::<T>::ConversionError)?
-			.saturated_into();
-		let quote_free = quote_free
-			.saturating_mul(Decimal::from(UNIT_BALANCE))
-			.to_u128()
-			.ok_or(Error::<T>::ConversionError)?
-			.saturated_into();
-		let base_required = base_required
-			.saturating_mul(Decimal::from(UNIT_BALANCE))
-			.to_u128()
-			.ok_or(Error::<T>::ConversionError)?
-			.saturated_into();
-		let quote_required = quote_required
-			.saturating_mul(Decimal::from(UNIT_BALANCE))
-			.to_u128()
-			.ok_or(Error::<T>::ConversionError)?
-			.saturated_into();
-		Self::deposit_event(Event::<T>::LiquidityRemovalFailed {
+	fn remove_liquidity_instantly_failed(
+		market: TradingPair,
+		pool: &T::AccountId,
+		lp: &T::AccountId,
+		burn_frac: Decimal,
+		base_free: Decimal,
+		quote_free: Decimal,
+		base_required: Decimal,
+		quote_required: Decimal,
+		request_id: u64,
+	) -> DispatchResult {
+		// Instant exits burned more shares than they withdraw, mint back all of them
+		let shares_burned = Self::take_pending_early_exit(pool, lp, request_id)?;
+		Self::mint_back_shares(
 			market,
-			pool: pool.clone(),
-			lp: lp.clone(),
+			pool,
+			lp,
+			shares_burned,
 			burn_frac,
 			base_free,
 			quote_free,
 			base_required,
 			quote_required,
-		});
-		Ok(())
+		)
 	}
 
 	fn pool_force_close_success(
 		market: TradingPair,
-		market_maker: &T::AccountId,
+		pool: &T::AccountId,
 		base_freed: Decimal,
 		quote_freed: Decimal,
 	) -> DispatchResult {
-		let mut pool_config =
-			<Pools<T>>::get(market, market_maker).ok_or(Error::<T>::UnknownPool)?;
+		let (market_maker, mut pool_config) = Self::pool_config(market, pool)?;
 		pool_config.force_closed = true;
 		<Pools<T>>::insert(market, market_maker, pool_config);
 		let base_freed = base_freed
@@ -185,13 +195,35 @@ impl<T: Config> LiquidityMiningCrowdSourcePallet<T::AccountId> for Pallet<T> {
 		//FIXME: What are we doing with base_freed and quote_freed?
 		Self::deposit_event(Event::<T>::PoolForceClosed {
 			market,
-			pool: market_maker.clone(),
+			pool: pool.clone(),
 			base_freed,
 			quote_freed,
 		});
 		Ok(())
 	}
 
+	fn pool_valued(
+		market: TradingPair,
+		pool: &T::AccountId,
+		epoch: u16,
+		total_inventory_in_quote: Decimal,
+		total_shares: Decimal,
+	) -> DispatchResult {
+		let (market_maker, pool_config) = Self::pool_config(market, pool)?;
+		let nav_per_share = match total_inventory_in_quote.checked_div(total_shares) {
+			Some(nav_per_share) if !nav_per_share.is_zero() => nav_per_share,
+			_ => return Ok(()),
+		};
+		Self::charge_performance_fee(
+			market,
+			&market_maker,
+			&pool_config,
+			epoch,
+			nav_per_share,
+			total_shares,
+		)
+	}
+
 	fn stop_accepting_lmp_withdrawals(epoch: u16) {
 		<WithdrawingEpoch<T>>::put(epoch)
 	}
@@ -20,10 +20,15 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(unused_crate_dependencies)]
 
+use frame_support::weights::Weight;
 use sp_std::vec::Vec;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod callback;
+pub mod migration;
 pub mod types;
+pub mod weights;
 
 #[cfg(test)]
 mod mock;
@@ -31,10 +36,16 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub trait LiquidityMiningWeightInfo {
+	fn set_pool_paused() -> Weight;
+	fn force_set_pool_paused() -> Weight;
+	fn remove_liquidity_instantly() -> Weight;
+}
+
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
 	use super::*;
-	use crate::types::MarketMakerConfig;
+	use crate::types::{MarketMakerConfig, PoolFlags};
 	use core::ops::{Div, DivAssign, MulAssign};
 	use frame_support::{
 		pallet_prelude::*,
@@ -56,7 +67,7 @@ pub mod pallet {
 	use sp_io::hashing::blake2_128;
 	use sp_runtime::{
 		traits::{CheckedDiv, UniqueSaturatedInto},
-		Saturating,
+		PerThing, Permill, Saturating,
 	};
 	use sp_std::collections::btree_map::BTreeMap;
 
@@ -72,6 +83,7 @@ pub mod pallet {
 		MMClaimFlag,
 	);
 
+	type NAVPerShare = Decimal;
 	type LMPScoreSheet<T> = BTreeMap<
 		(TradingPair, <T as frame_system::Config>::AccountId, u16),
 		(BTreeMap<<T as frame_system::Config>::AccountId, (BalanceOf<T>, bool)>, BalanceOf<T>),
@@ -98,9 +110,24 @@ pub mod pallet {
 				AssetId = u128,
 			> + Inspect<<Self as frame_system::Config>::AccountId>
 			+ Create<<Self as frame_system::Config>::AccountId>;
+
+		/// Maximum number of pools scored in one snapshot submission
+		#[pallet::constant]
+		type MaxPoolsPerSnapshot: Get<u32>;
+
+		/// Portion of the shares left in the pool by an LP exiting before the end of the epoch
+		#[pallet::constant]
+		type EarlyExitPenalty: Get<Permill>;
+
+		/// Type representing the weight of this pallet
+		type WeightInfo: LiquidityMiningWeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// LP Shares
@@ -187,6 +214,51 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type SnapshotFlag<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
 
+	/// Raw key of the last pool scored in the ongoing snapshot, pools are scored in storage order
+	#[pallet::storage]
+	pub(super) type SnapshotCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// Pause flags by pool
+	#[pallet::storage]
+	#[pallet::getter(fn pool_flags)]
+	pub(super) type PoolFlagsOf<T: Config> =
+		StorageMap<_, Identity, T::AccountId, PoolFlags, ValueQuery>;
+
+	/// NAV per share of the pool at the end of the epoch and the shares minted as performance fee
+	#[pallet::storage]
+	#[pallet::getter(fn pool_nav)]
+	pub(super) type PoolNAV<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u16, // epoch
+		Identity,
+		T::AccountId, // pool_id
+		(NAVPerShare, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// Highest NAV per share of the pool on which performance fee is already charged
+	#[pallet::storage]
+	#[pallet::getter(fn high_water_mark)]
+	pub(super) type HighWaterMark<T: Config> =
+		StorageMap<_, Identity, T::AccountId, NAVPerShare, OptionQuery>;
+
+	/// Instant exits sent to the engine and not yet settled, as (request id, shares burned)
+	#[pallet::storage]
+	pub(super) type PendingEarlyExits<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::AccountId, // pool_id
+		Identity,
+		T::AccountId, // LP
+		(u64, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// Request id of the next instant exit
+	#[pallet::storage]
+	pub(super) type NextEarlyExitId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
 	/// Issueing withdrawals for epoch
 	#[pallet::storage]
 	pub(super) type WithdrawingEpoch<T: Config> = StorageValue<_, u16, ValueQuery>;
@@ -224,6 +296,29 @@ pub mod pallet {
 			base_freed: BalanceOf<T>,
 			quote_freed: BalanceOf<T>,
 		},
+		PoolFlagsUpdated {
+			market: TradingPair,
+			pool: T::AccountId,
+			flags: PoolFlags,
+		},
+		SnapshotCompleted {
+			epoch: u16,
+		},
+		EarlyExitRequested {
+			market: TradingPair,
+			pool: T::AccountId,
+			lp: T::AccountId,
+			shares: BalanceOf<T>,
+			penalty: BalanceOf<T>,
+		},
+		PerformanceFeeCharged {
+			market: TradingPair,
+			pool: T::AccountId,
+			epoch: u16,
+			nav_per_share: NAVPerShare,
+			high_water_mark: NAVPerShare,
+			shares: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -236,6 +331,8 @@ pub mod pallet {
 		InvalidCommission,
 		/// Exit fee should be between 0-1
 		InvalidExitFee,
+		/// Performance fee should be between 0-1
+		InvalidPerformanceFee,
 		/// Pool already exists
 		PoolExists,
 		/// There is not enough quote for given base amount
@@ -262,6 +359,14 @@ pub mod pallet {
 		SnapshotInProgress,
 		/// Price Oracle not available, try again later
 		PriceNotAvailable,
+		/// Pool is paused
+		PoolPaused,
+		/// Snapshot results don't match the next pools to be scored
+		InvalidSnapshotBatch,
+		/// A previous instant exit of this LP is not settled yet
+		EarlyExitInProgress,
+		/// No pending instant exit matches the settled request
+		UnknownEarlyExit,
 	}
 
 	#[pallet::hooks]
@@ -334,6 +439,7 @@ pub mod pallet {
 			market: TradingPair,
 			#[pallet::compact] commission: u128,
 			#[pallet::compact] exit_fee: u128,
+			#[pallet::compact] performance_fee: u128,
 			public_funds_allowed: bool,
 			trading_account: T::AccountId,
 		) -> DispatchResult {
@@ -342,13 +448,16 @@ pub mod pallet {
 			ensure!(!<Pools<T>>::contains_key(market, &market_maker), Error::<T>::PoolExists);
 			// Check market is active
 			ensure!(T::OCEX::is_registered_market(&market), Error::<T>::UnknownMarket);
-			// Check if commission and exit fee are between 0-1
+			// Check if commission, exit fee and performance fee are between 0-1
 			let mut commission =
 				Decimal::from_u128(commission).ok_or(Error::<T>::ConversionError)?;
 			let mut exit_fee = Decimal::from_u128(exit_fee).ok_or(Error::<T>::ConversionError)?;
+			let mut performance_fee =
+				Decimal::from_u128(performance_fee).ok_or(Error::<T>::ConversionError)?;
 			// Convert to Polkadex UNIT
 			commission.div_assign(Decimal::from(UNIT_BALANCE));
 			exit_fee.div_assign(Decimal::from(UNIT_BALANCE));
+			performance_fee.div_assign(Decimal::from(UNIT_BALANCE));
 			ensure!(
 				Decimal::zero() <= commission && commission <= Decimal::one(),
 				Error::<T>::InvalidCommission
@@ -357,6 +466,10 @@ pub mod pallet {
 				Decimal::zero() <= exit_fee && exit_fee <= Decimal::one(),
 				Error::<T>::InvalidExitFee
 			);
+			ensure!(
+				Decimal::zero() <= performance_fee && performance_fee <= Decimal::one(),
+				Error::<T>::InvalidPerformanceFee
+			);
 			// Create the a pool address with origin and market combo if it doesn't exist
 			let (pool, share_id) = Self::create_pool_account(&market_maker, market);
 			T::OtherAssets::create(share_id, pool.clone(), true, One::one())?;
@@ -401,6 +514,7 @@ pub mod pallet {
 				pool_id: pool,
 				commission,
 				exit_fee,
+				performance_fee,
 				public_funds_allowed,
 				name,
 				share_id,
@@ -422,8 +536,15 @@ pub mod pallet {
 		) -> DispatchResult {
 			let lp = ensure_signed(origin)?;
 			let config = <Pools<T>>::get(market, &market_maker).ok_or(Error::<T>::UnknownPool)?;
-			ensure!(<SnapshotFlag<T>>::get().is_none(), Error::<T>::SnapshotInProgress); // TODO: @zktony Replace with pool level flags
+			ensure!(
+				!Self::is_pool_snapshotting(market, &market_maker),
+				Error::<T>::SnapshotInProgress
+			);
 			ensure!(!config.force_closed, Error::<T>::PoolForceClosed);
+			ensure!(
+				!<PoolFlagsOf<T>>::get(&config.pool_id).deposits_paused(),
+				Error::<T>::PoolPaused
+			);
 			if !config.public_funds_allowed && !config.force_closed {
 				ensure!(lp == market_maker, Error::<T>::PublicDepositsNotAllowed);
 			}
@@ -471,8 +592,15 @@ pub mod pallet {
 		) -> DispatchResult {
 			let lp = ensure_signed(origin)?;
 
-			let config = <Pools<T>>::get(market, market_maker).ok_or(Error::<T>::UnknownPool)?;
-			ensure!(<SnapshotFlag<T>>::get().is_none(), Error::<T>::SnapshotInProgress); // TODO: @zktony Replace with pool level flags
+			let config = <Pools<T>>::get(market, &market_maker).ok_or(Error::<T>::UnknownPool)?;
+			ensure!(
+				!Self::is_pool_snapshotting(market, &market_maker),
+				Error::<T>::SnapshotInProgress
+			);
+			ensure!(
+				!<PoolFlagsOf<T>>::get(&config.pool_id).withdrawals_paused(),
+				Error::<T>::PoolPaused
+			);
 
 			let total = T::OtherAssets::total_issuance(config.share_id);
 			ensure!(!total.is_zero(), Error::<T>::TotalShareIssuanceIsZero);
@@ -483,6 +611,9 @@ pub mod pallet {
 				Precision::Exact,
 				Fortitude::Polite,
 			)?;
+			<LPShares<T>>::mutate(config.share_id, &lp, |lp_shares| {
+				*lp_shares = lp_shares.saturating_sub(burned_amt)
+			});
 			// Queue it for execution at the end of the epoch
 			let epoch = <WithdrawingEpoch<T>>::get();
 			<WithdrawalRequests<T>>::mutate(epoch, config.pool_id, |pending| {
@@ -501,8 +632,8 @@ pub mod pallet {
 			market_maker: T::AccountId,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			ensure!(<Pools<T>>::contains_key(market, &market_maker), Error::<T>::UnknownPool);
-			T::OCEX::force_close_pool(market, market_maker);
+			let config = <Pools<T>>::get(market, &market_maker).ok_or(Error::<T>::UnknownPool)?;
+			T::OCEX::force_close_pool(market, config.pool_id);
 			Ok(())
 		}
 
@@ -623,19 +754,46 @@ pub mod pallet {
 			results: LMPScoreSheet<T>,
 		) -> DispatchResult {
 			ensure_none(origin)?;
-
-			for ((market, market_maker, epoch), (scores_map, total_score)) in results {
-				let pool_config =
-					<Pools<T>>::get(market, &market_maker).ok_or(Error::<T>::UnknownPool)?;
+			ensure!(
+				results.len() <= T::MaxPoolsPerSnapshot::get() as usize,
+				Error::<T>::InvalidSnapshotBatch
+			);
+			let mut results = results;
+			let epoch = <LMPEpoch<T>>::get().saturating_sub(1);
+			// Results must cover the next pools in storage order
+			let mut pools = Self::pools_to_snapshot();
+			let mut last_key = None;
+			while !results.is_empty() {
+				let (market, market_maker, pool_config) =
+					pools.next().ok_or(Error::<T>::InvalidSnapshotBatch)?;
+				let (scores_map, total_score) = results
+					.remove(&(market, market_maker.clone(), epoch))
+					.ok_or(Error::<T>::InvalidSnapshotBatch)?;
 				<LiquidityProviders<T>>::insert(
 					epoch,
 					&pool_config.pool_id,
 					(scores_map, total_score, false),
 				);
-				<Pools<T>>::insert(market, &market_maker, pool_config);
+				// The performance fee is charged once the engine reports the pool value
+				if let Some(price) = T::OCEX::average_price(market) {
+					T::OCEX::value_pool(
+						market,
+						pool_config.pool_id.clone(),
+						epoch,
+						price,
+						Self::total_shares(&pool_config),
+					);
+				}
+				last_key = Some(<Pools<T>>::hashed_key_for(market, market_maker));
 			}
 
-			<SnapshotFlag<T>>::take();
+			if pools.next().is_none() {
+				<SnapshotFlag<T>>::take();
+				<SnapshotCursor<T>>::kill();
+				Self::deposit_event(Event::<T>::SnapshotCompleted { epoch });
+			} else if let Some(last_key) = last_key {
+				<SnapshotCursor<T>>::put(last_key);
+			}
 			Ok(())
 		}
 
@@ -654,6 +812,10 @@ pub mod pallet {
 			let num_requests: usize = num_requests as usize;
 			let pool_config =
 				<Pools<T>>::get(market, &market_maker).ok_or(Error::<T>::UnknownPool)?;
+			ensure!(
+				!<PoolFlagsOf<T>>::get(&pool_config.pool_id).withdrawals_paused(),
+				Error::<T>::PoolPaused
+			);
 			let mut requests = <WithdrawalRequests<T>>::get(epoch, &pool_config.pool_id);
 			for request in requests.iter().take(num_requests) {
 				T::OCEX::remove_liquidity(
@@ -721,6 +883,7 @@ pub mod pallet {
 				Precision::Exact,
 				Fortitude::Force,
 			)?;
+			<LPShares<T>>::remove(pool_config.share_id, &lp);
 			T::OtherAssets::transfer(
 				market.base.asset_id().ok_or(Error::<T>::ConversionError)?,
 				&pool_config.pool_id,
@@ -738,6 +901,109 @@ pub mod pallet {
 			// TODO: Emit events (Ask @frontend team about this)
 			Ok(())
 		}
+
+		/// Pause or resume deposits to the market maker's pool
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_paused())]
+		pub fn set_pool_paused(
+			origin: OriginFor<T>,
+			market: TradingPair,
+			paused: bool,
+		) -> DispatchResult {
+			let market_maker = ensure_signed(origin)?;
+			let pool_config =
+				<Pools<T>>::get(market, &market_maker).ok_or(Error::<T>::UnknownPool)?;
+			Self::update_pool_flags(market, pool_config.pool_id, |flags| {
+				flags.paused_by_market_maker = paused
+			});
+			Ok(())
+		}
+
+		/// Pause or resume deposits and withdrawals of a pool
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_set_pool_paused())]
+		pub fn force_set_pool_paused(
+			origin: OriginFor<T>,
+			market: TradingPair,
+			market_maker: T::AccountId,
+			paused: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let pool_config =
+				<Pools<T>>::get(market, &market_maker).ok_or(Error::<T>::UnknownPool)?;
+			Self::update_pool_flags(market, pool_config.pool_id, |flags| {
+				flags.paused_by_governance = paused
+			});
+			Ok(())
+		}
+
+		/// Withdraws the LP's share of the pool immediately instead of at the end of the epoch.
+		/// A penalty portion of the shares is burned without being withdrawn, which accrues to
+		/// the remaining LPs.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity_instantly())]
+		#[transactional]
+		pub fn remove_liquidity_instantly(
+			origin: OriginFor<T>,
+			market: TradingPair,
+			market_maker: T::AccountId,
+			#[pallet::compact] shares: BalanceOf<T>,
+		) -> DispatchResult {
+			let lp = ensure_signed(origin)?;
+
+			let config = <Pools<T>>::get(market, &market_maker).ok_or(Error::<T>::UnknownPool)?;
+			ensure!(
+				!Self::is_pool_snapshotting(market, &market_maker),
+				Error::<T>::SnapshotInProgress
+			);
+			ensure!(!config.force_closed, Error::<T>::PoolForceClosed);
+			ensure!(
+				!<PoolFlagsOf<T>>::get(&config.pool_id).withdrawals_paused(),
+				Error::<T>::PoolPaused
+			);
+			ensure!(
+				!<PendingEarlyExits<T>>::contains_key(&config.pool_id, &lp),
+				Error::<T>::EarlyExitInProgress
+			);
+
+			let total = T::OtherAssets::total_issuance(config.share_id);
+			ensure!(!total.is_zero(), Error::<T>::TotalShareIssuanceIsZero);
+			let burned_amt = T::OtherAssets::burn_from(
+				config.share_id,
+				&lp,
+				shares,
+				Precision::Exact,
+				Fortitude::Polite,
+			)?;
+			<LPShares<T>>::mutate(config.share_id, &lp, |lp_shares| {
+				*lp_shares = lp_shares.saturating_sub(burned_amt)
+			});
+			let penalty = T::EarlyExitPenalty::get().mul_floor(burned_amt);
+			let withdrawn = burned_amt.saturating_sub(penalty);
+			// The engine reports the result back with the request id
+			let request_id = <NextEarlyExitId<T>>::mutate(|next_id| {
+				let request_id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				request_id
+			});
+			<PendingEarlyExits<T>>::insert(&config.pool_id, &lp, (request_id, burned_amt));
+			T::OCEX::remove_liquidity_instantly(
+				market,
+				config.pool_id.clone(),
+				lp.clone(),
+				withdrawn,
+				total,
+				request_id,
+			);
+			Self::deposit_event(Event::<T>::EarlyExitRequested {
+				market,
+				pool: config.pool_id,
+				lp,
+				shares: burned_amt,
+				penalty,
+			});
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -750,8 +1016,10 @@ pub mod pallet {
 			// TODO: Only compute the result every five blocks
 
 			let mut results: LMPScoreSheet<T> = BTreeMap::new();
-			// Loop over all pools and lps and calculate score of all LPs
-			for (market, mm, config) in <Pools<T>>::iter() {
+			// Loop over the next batch of pools and calculate score of all their LPs
+			for (market, mm, config) in
+				Self::pools_to_snapshot().take(T::MaxPoolsPerSnapshot::get() as usize)
+			{
 				let mut scores_map = BTreeMap::new();
 				let mut pool_total_score: BalanceOf<T> = Zero::zero();
 				for (lp, mut total_shares) in <LPShares<T>>::iter_prefix(config.share_id) {
@@ -789,6 +1057,221 @@ pub mod pallet {
 			}
 		}
 
+		/// Returns the pools not yet scored in the ongoing snapshot, in storage order
+		fn pools_to_snapshot(
+		) -> impl Iterator<Item = (TradingPair, T::AccountId, MarketMakerConfig<T::AccountId>)> {
+			match <SnapshotCursor<T>>::get() {
+				Some(cursor) => <Pools<T>>::iter_from(cursor),
+				None => <Pools<T>>::iter(),
+			}
+		}
+
+		/// Returns true if the pool is yet to be scored in the ongoing snapshot
+		pub fn is_pool_snapshotting(market: TradingPair, market_maker: &T::AccountId) -> bool {
+			if <SnapshotFlag<T>>::get().is_none() {
+				return false;
+			}
+			match <SnapshotCursor<T>>::get() {
+				Some(cursor) => <Pools<T>>::hashed_key_for(market, market_maker) > cursor,
+				None => true,
+			}
+		}
+
+		fn update_pool_flags(
+			market: TradingPair,
+			pool: T::AccountId,
+			update: impl FnOnce(&mut PoolFlags),
+		) {
+			let flags = <PoolFlagsOf<T>>::mutate(&pool, |flags| {
+				update(flags);
+				*flags
+			});
+			Self::deposit_event(Event::<T>::PoolFlagsUpdated { market, pool, flags });
+		}
+
+		/// Values the pool's offchain balances with the OCEX price oracle, returns the pool value in
+		/// quote asset and the NAV per share
+		fn pool_value(
+			market: TradingPair,
+			config: &MarketMakerConfig<T::AccountId>,
+		) -> Result<(Decimal, NAVPerShare), DispatchError> {
+			let price = T::OCEX::average_price(market).ok_or(Error::<T>::PriceNotAvailable)?;
+			let (base, quote) = T::OCEX::get_pool_balances(config.pool_id.clone(), market);
+			let pool_value = base.saturating_mul(price).saturating_add(quote);
+			let total_shares = Self::total_shares(config);
+			let nav_per_share = pool_value.checked_div(total_shares).unwrap_or_default();
+			Ok((pool_value, nav_per_share))
+		}
+
+		fn total_shares(config: &MarketMakerConfig<T::AccountId>) -> Decimal {
+			Decimal::from(T::OtherAssets::total_issuance(config.share_id).saturated_into::<u128>())
+				.div(Decimal::from(UNIT_BALANCE))
+		}
+
+		/// Records the NAV per share of the pool at the end of the epoch and charges the
+		/// performance fee on its growth above the high-water mark by minting new shares to the
+		/// market maker. The first recorded NAV only sets the high-water mark.
+		pub(crate) fn charge_performance_fee(
+			market: TradingPair,
+			market_maker: &T::AccountId,
+			config: &MarketMakerConfig<T::AccountId>,
+			epoch: u16,
+			nav_per_share: NAVPerShare,
+			total_shares: Decimal,
+		) -> DispatchResult {
+			let high_water_mark = match <HighWaterMark<T>>::get(&config.pool_id) {
+				None => {
+					<HighWaterMark<T>>::insert(&config.pool_id, nav_per_share);
+					<PoolNAV<T>>::insert(epoch, &config.pool_id, (nav_per_share, Zero::zero()));
+					return Ok(());
+				},
+				Some(high_water_mark) => high_water_mark,
+			};
+			if nav_per_share <= high_water_mark || config.performance_fee.is_zero() {
+				<PoolNAV<T>>::insert(epoch, &config.pool_id, (nav_per_share, Zero::zero()));
+				return Ok(());
+			}
+			let pool_value = nav_per_share.saturating_mul(total_shares);
+			let fee = nav_per_share
+				.saturating_sub(high_water_mark)
+				.saturating_mul(total_shares)
+				.saturating_mul(config.performance_fee);
+			// Mint enough shares so that they are worth the fee after dilution
+			let fee_shares = fee
+				.saturating_mul(total_shares)
+				.checked_div(pool_value.saturating_sub(fee))
+				.ok_or(Error::<T>::ConversionError)?;
+			let new_high_water_mark = pool_value
+				.checked_div(total_shares.saturating_add(fee_shares))
+				.ok_or(Error::<T>::ConversionError)?;
+			let fee_shares: BalanceOf<T> = fee_shares
+				.saturating_mul(Decimal::from(UNIT_BALANCE))
+				.to_u128()
+				.ok_or(Error::<T>::ConversionError)?
+				.saturated_into();
+			if fee_shares.is_zero() {
+				<PoolNAV<T>>::insert(epoch, &config.pool_id, (nav_per_share, fee_shares));
+				return Ok(());
+			}
+			T::OtherAssets::mint_into(config.share_id, market_maker, fee_shares)?;
+			<LPShares<T>>::mutate(config.share_id, market_maker, |shares| {
+				*shares = shares.saturating_add(fee_shares)
+			});
+			<HighWaterMark<T>>::insert(&config.pool_id, new_high_water_mark);
+			<PoolNAV<T>>::insert(epoch, &config.pool_id, (nav_per_share, fee_shares));
+			Self::deposit_event(Event::<T>::PerformanceFeeCharged {
+				market,
+				pool: config.pool_id.clone(),
+				epoch,
+				nav_per_share,
+				high_water_mark: new_high_water_mark,
+				shares: fee_shares,
+			});
+			Ok(())
+		}
+
+		/// Returns the market maker and config of the pool with the given pool account
+		pub fn pool_config(
+			market: TradingPair,
+			pool: &T::AccountId,
+		) -> Result<(T::AccountId, MarketMakerConfig<T::AccountId>), DispatchError> {
+			<Pools<T>>::iter_prefix(market)
+				.find(|(_, config)| &config.pool_id == pool)
+				.ok_or_else(|| Error::<T>::UnknownPool.into())
+		}
+
+		/// Returns all pools with their market maker, config, pause flags and high-water mark -
+		/// called by RPC
+		pub fn list_pools() -> Vec<(
+			TradingPair,
+			T::AccountId,
+			MarketMakerConfig<T::AccountId>,
+			PoolFlags,
+			Option<NAVPerShare>,
+		)> {
+			<Pools<T>>::iter()
+				.map(|(market, market_maker, config)| {
+					let flags = <PoolFlagsOf<T>>::get(&config.pool_id);
+					let high_water_mark = <HighWaterMark<T>>::get(&config.pool_id);
+					(market, market_maker, config, flags, high_water_mark)
+				})
+				.collect()
+		}
+
+		/// Returns the share token balance of the LP in the pool - called by RPC
+		pub fn get_lp_shares(
+			market: TradingPair,
+			market_maker: T::AccountId,
+			lp: T::AccountId,
+		) -> BalanceOf<T> {
+			match <Pools<T>>::get(market, &market_maker) {
+				Some(config) => T::OtherAssets::balance(config.share_id, &lp),
+				None => Zero::zero(),
+			}
+		}
+
+		/// Returns the value of the pool in quote asset and its NAV per share, the pool's
+		/// offchain balances are valued with the OCEX price oracle - called by RPC
+		pub fn get_pool_nav(
+			market: TradingPair,
+			market_maker: T::AccountId,
+		) -> Result<(Decimal, Decimal), DispatchError> {
+			let config = <Pools<T>>::get(market, &market_maker).ok_or(Error::<T>::UnknownPool)?;
+			Self::pool_value(market, &config)
+		}
+
+		/// Returns the NAV per share of the pool at the end of given epoch and the shares minted
+		/// to the market maker as performance fee - called by RPC
+		pub fn get_performance_fee(
+			epoch: u16,
+			market: TradingPair,
+			market_maker: T::AccountId,
+		) -> Option<(Decimal, BalanceOf<T>)> {
+			let config = <Pools<T>>::get(market, &market_maker)?;
+			<PoolNAV<T>>::get(epoch, config.pool_id)
+		}
+
+		/// Returns the pending withdrawal requests of the pool queued in given epoch as
+		/// (LP, shares burned, total shares) - called by RPC
+		pub fn get_pending_withdrawals(
+			epoch: u16,
+			market: TradingPair,
+			market_maker: T::AccountId,
+		) -> Vec<(T::AccountId, BalanceOf<T>, BalanceOf<T>)> {
+			match <Pools<T>>::get(market, &market_maker) {
+				Some(config) => <WithdrawalRequests<T>>::get(epoch, config.pool_id),
+				None => Vec::new(),
+			}
+		}
+
+		/// Returns the rewards of the LP in given epoch and if they are already claimed - called
+		/// by RPC
+		pub fn get_claimable_rewards(
+			market: TradingPair,
+			market_maker: T::AccountId,
+			epoch: u16,
+			lp: T::AccountId,
+		) -> (BalanceOf<T>, bool) {
+			let config = match <Pools<T>>::get(market, &market_maker) {
+				Some(config) => config,
+				None => return (Zero::zero(), false),
+			};
+			let (scores_map, total_score, _) = <LiquidityProviders<T>>::get(epoch, &config.pool_id);
+			let (score, is_claimed) = match scores_map.get(&lp) {
+				Some(score) => *score,
+				None => return (Zero::zero(), false),
+			};
+			let total_rewards = match <Rewards<T>>::get(epoch, &config.pool_id) {
+				Some(total_rewards) => total_rewards,
+				None => T::OCEX::get_pool_rewards(config.pool_id, epoch, market).0,
+			};
+			let rewards = score
+				.saturating_mul(total_rewards)
+				.checked_div(&total_score)
+				.unwrap_or_default();
+			(rewards, is_claimed)
+		}
+
 		pub fn create_pool_account(
 			maker: &T::AccountId,
 			market: TradingPair,
@@ -802,6 +1285,76 @@ pub mod pallet {
 			(pool_id.into_sub_account_truncating(hash), shares_id)
 		}
 
+		/// Removes the pending instant exit of the LP, returning the shares it burned
+		pub(crate) fn take_pending_early_exit(
+			pool: &T::AccountId,
+			lp: &T::AccountId,
+			request_id: u64,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			<PendingEarlyExits<T>>::try_mutate_exists(pool, lp, |pending| match pending.take() {
+				Some((id, shares_burned)) if id == request_id => Ok(shares_burned),
+				_ => Err(Error::<T>::UnknownEarlyExit.into()),
+			})
+		}
+
+		/// Mints back the shares burned by a liquidity removal the engine could not settle
+		#[allow(clippy::too_many_arguments)]
+		pub(crate) fn mint_back_shares(
+			market: TradingPair,
+			pool: &T::AccountId,
+			lp: &T::AccountId,
+			shares_burned: BalanceOf<T>,
+			burn_frac: Decimal,
+			base_free: Decimal,
+			quote_free: Decimal,
+			base_required: Decimal,
+			quote_required: Decimal,
+		) -> DispatchResult {
+			let burn_frac = burn_frac
+				.saturating_mul(Decimal::from(UNIT_BALANCE))
+				.to_u128()
+				.ok_or(Error::<T>::ConversionError)?
+				.saturated_into();
+
+			let (_, pool_config) = Self::pool_config(market, pool)?;
+			T::OtherAssets::mint_into(pool_config.share_id, lp, shares_burned)?;
+			<LPShares<T>>::mutate(pool_config.share_id, lp, |shares| {
+				*shares = shares.saturating_add(shares_burned)
+			});
+
+			let base_free = base_free
+				.saturating_mul(Decimal::from(UNIT_BALANCE))
+				.to_u128()
+				.ok_or(Error::<T>::ConversionError)?
+				.saturated_into();
+			let quote_free = quote_free
+				.saturating_mul(Decimal::from(UNIT_BALANCE))
+				.to_u128()
+				.ok_or(Error::<T>::ConversionError)?
+				.saturated_into();
+			let base_required = base_required
+				.saturating_mul(Decimal::from(UNIT_BALANCE))
+				.to_u128()
+				.ok_or(Error::<T>::ConversionError)?
+				.saturated_into();
+			let quote_required = quote_required
+				.saturating_mul(Decimal::from(UNIT_BALANCE))
+				.to_u128()
+				.ok_or(Error::<T>::ConversionError)?
+				.saturated_into();
+			Self::deposit_event(Event::<T>::LiquidityRemovalFailed {
+				market,
+				pool: pool.clone(),
+				lp: lp.clone(),
+				burn_frac,
+				base_free,
+				quote_free,
+				base_required,
+				quote_required,
+			});
+			Ok(())
+		}
+
 		pub fn transfer_asset(
 			payer: &T::AccountId,
 			payee: &T::AccountId,
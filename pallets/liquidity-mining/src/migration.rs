@@ -0,0 +1,97 @@
+// This file is part of Polkadex.
+//
+// Copyright (c) 2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the liquidity mining pallet.
+
+use crate::{pallet::Pools, types::MarketMakerConfig, Config, Pallet};
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+use rust_decimal::Decimal;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+pub mod v1 {
+	use super::*;
+
+	/// `MarketMakerConfig` before the performance fee
+	#[derive(Decode)]
+	struct OldMarketMakerConfig<AccountId> {
+		pool_id: AccountId,
+		commission: Decimal,
+		exit_fee: Decimal,
+		public_funds_allowed: bool,
+		name: [u8; 10],
+		share_id: u128,
+		force_closed: bool,
+	}
+
+	impl<AccountId> From<OldMarketMakerConfig<AccountId>> for MarketMakerConfig<AccountId> {
+		fn from(old: OldMarketMakerConfig<AccountId>) -> Self {
+			MarketMakerConfig {
+				pool_id: old.pool_id,
+				commission: old.commission,
+				exit_fee: old.exit_fee,
+				performance_fee: Decimal::ZERO,
+				public_funds_allowed: old.public_funds_allowed,
+				name: old.name,
+				share_id: old.share_id,
+				force_closed: old.force_closed,
+			}
+		}
+	}
+
+	/// Adds a zero performance fee to the config of every registered pool
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() >= 1 {
+				log::info!(target: "lmp", "Skipping migration to v1, already applied");
+				return T::DbWeight::get().reads(1);
+			}
+			let mut translated = 0u64;
+			<Pools<T>>::translate::<OldMarketMakerConfig<T::AccountId>, _>(|_, _, old| {
+				translated += 1;
+				Some(old.into())
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: "lmp", "Migrated {:?} pool configs to v1", translated);
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let pools = <Pools<T>>::iter_keys().count() as u32;
+			Ok(pools.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let pools: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "Unable to decode the pre upgrade state")?;
+			ensure!(StorageVersion::get::<Pallet<T>>() == 1, "Storage version not updated");
+			ensure!(<Pools<T>>::iter().count() as u32 == pools, "Pool configs lost in migration");
+			ensure!(
+				<Pools<T>>::iter_values().all(|config| config.performance_fee.is_zero()),
+				"Pool configs migrated with a performance fee"
+			);
+			Ok(())
+		}
+	}
+}
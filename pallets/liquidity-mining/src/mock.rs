@@ -21,7 +21,7 @@
 use frame_support::{
 	pallet_prelude::Weight,
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU128, ConstU64, OnTimestampSet},
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, OnTimestampSet},
 	PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSigned};
@@ -33,7 +33,7 @@ use sp_std::cell::RefCell;
 use pallet_ocex_lmp as ocex;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Permill,
 };
 // Reexport crate as its pallet name for construct_runtime.
 
@@ -48,6 +48,7 @@ frame_support::construct_runtime!(
 		Timestamp: pallet_timestamp,
 		LiqudityMining: crate::pallet,
 		OCEX: ocex,
+		AssetConversion: pallet_asset_conversion,
 	}
 );
 
@@ -122,6 +123,9 @@ parameter_types! {
 	pub const LMPRewardsPalletId: PalletId = PalletId(*b"OCEX_TMP");
 	pub const MsPerDay: u64 = 86_400_000;
 	pub const OBWithdrawalLimit: u32 = 50;
+	pub const LMPRewardStreamsClaimPeriod: u32 = 100;
+	pub const MaxPoolsPerSnapshot: u32 = 1;
+	pub const EarlyExitPenalty: Permill = Permill::from_percent(10);
 }
 
 impl crate::pallet::Config for Test {
@@ -130,6 +134,9 @@ impl crate::pallet::Config for Test {
 	type PalletId = LMPRewardsPalletId;
 	type NativeCurrency = Balances;
 	type OtherAssets = Assets;
+	type MaxPoolsPerSnapshot = MaxPoolsPerSnapshot;
+	type EarlyExitPenalty = EarlyExitPenalty;
+	type WeightInfo = crate::weights::WeightInfo<Test>;
 }
 
 impl ocex::Config for Test {
@@ -137,16 +144,54 @@ impl ocex::Config for Test {
 	type PalletId = OcexPalletId;
 	type TreasuryPalletId = TresuryPalletId;
 	type LMPRewardsPalletId = LMPRewardsPalletId;
+	type LMPRewardStreamsClaimPeriod = LMPRewardStreamsClaimPeriod;
 	type NativeCurrency = Balances;
 	type OtherAssets = Assets;
 	type EnclaveOrigin = EnsureRoot<sp_runtime::AccountId32>;
 	type AuthorityId = ocex::sr25519::AuthorityId;
 	type GovernanceOrigin = EnsureRoot<sp_runtime::AccountId32>;
+	type LMPRewardsVesting = ();
 	type CrowdSourceLiqudityMining = LiqudityMining;
+	type Swap = AssetConversion;
 	type OBWithdrawalLimit = OBWithdrawalLimit;
 	type WeightInfo = ocex::weights::WeightInfo<Test>;
 }
 
+frame_support::ord_parameter_types! {
+	pub const AssetConversionOrigin: sp_runtime::AccountId32 = sp_runtime::AccountId32::new([9; 32]);
+}
+
+parameter_types! {
+	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
+	pub AllowMultiAssetPools: bool = true;
+	pub const PoolSetupFee: u128 = 1000000000000; // should be more or equal to the existential deposit
+	pub const MintMinLiquidity: u128 = 100;  // 100 is good enough when the main currency has 10-12 decimals.
+	pub const LiquidityWithdrawalFee: sp_runtime::Permill = sp_runtime::Permill::from_percent(0);  // should be non-zero if AllowMultiAssetPools is true, otherwise can be zero.
+}
+
+impl pallet_asset_conversion::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Balance = u128;
+	type AssetBalance = u128;
+	type HigherPrecisionBalance = u128;
+	type AssetId = u128;
+	type MultiAssetId = polkadex_primitives::AssetId;
+	type MultiAssetIdConverter = polkadex_primitives::AssetIdConverter;
+	type PoolAssetId = u128;
+	type Assets = Assets;
+	type PoolAssets = Assets;
+	type LPFee = ConstU32<3>; // means 0.3%
+	type PoolSetupFee = PoolSetupFee;
+	type PoolSetupFeeReceiver = AssetConversionOrigin;
+	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
+	type MintMinLiquidity = MintMinLiquidity;
+	type MaxSwapPathLength = ConstU32<4>;
+	type PalletId = AssetConversionPalletId;
+	type AllowMultiAssetPools = AllowMultiAssetPools;
+	type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Test>;
+}
+
 parameter_types! {
 	pub const AssetDeposit: u128 = 100;
 	pub const ApprovalDeposit: u128 = 1;
@@ -31,12 +31,22 @@ use std::{collections::BTreeMap, ops::DivAssign};
 
 use crate::pallet::Pools;
 use frame_support::traits::fungibles::Inspect;
+use orderbook_primitives::ingress::{EgressMessages, IngressMessages};
 use orderbook_primitives::lmp::LMPMarketConfigWrapper;
+use orderbook_primitives::ocex::TradingPairConfig;
 use orderbook_primitives::traits::LiquidityMiningCrowdSourcePallet;
 use pallet_ocex_lmp::pallet::PriceOracle;
-use parity_scale_codec::Compact;
+use parity_scale_codec::{Compact, Decode};
 use rust_decimal::{prelude::FromPrimitive, Decimal};
-use sp_runtime::{traits::One, ArithmeticError::Underflow};
+use sp_core::offchain::{
+	testing::{TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_runtime::{
+	testing::TestXt,
+	traits::{Dispatchable, One},
+	ArithmeticError::Underflow,
+};
 
 #[test]
 fn test_register_pool_happy_path() {
@@ -59,6 +69,7 @@ fn test_register_pool_happy_path() {
 			trading_pair,
 			commission,
 			exit_fee,
+			0,
 			public_fund_allowed,
 			trading_account.clone()
 		));
@@ -71,6 +82,7 @@ fn test_register_pool_happy_path() {
 				trading_pair,
 				commission,
 				exit_fee,
+				0,
 				public_fund_allowed,
 				trading_account
 			),
@@ -99,6 +111,7 @@ fn test_register_pool_error_unknown_pool() {
 				trading_pair,
 				commission,
 				exit_fee,
+				0,
 				public_fund_allowed,
 				trading_account.clone()
 			),
@@ -132,6 +145,7 @@ fn test_register_pool_error_register_pool_fails() {
 				trading_pair,
 				commission,
 				exit_fee,
+				0,
 				public_fund_allowed,
 				trading_account.clone()
 			),
@@ -334,9 +348,10 @@ fn test_force_close_pool_happy_path_and_error() {
 		let market_maker = AccountId32::new([2; 32]);
 		let base_freed = Decimal::from(2);
 		let quote_freed = Decimal::from(3);
+		let (pool, _share_id) = LiqudityMining::create_pool_account(&market_maker, trading_pair);
 		assert_ok!(LiqudityMining::pool_force_close_success(
 			trading_pair,
-			&market_maker,
+			&pool,
 			base_freed,
 			quote_freed
 		));
@@ -363,11 +378,12 @@ fn test_add_liquidity_success_happy_path() {
 		let price: Decimal = Decimal::from(5);
 		let total_inventory_in_quote: Decimal = Decimal::from(40);
 		register_test_pool(true);
+		let (pool, _share_id) = LiqudityMining::create_pool_account(&market_maker, trading_pair);
 		// Start new epoch
 		LiqudityMining::new_epoch(1);
 		assert_ok!(LiqudityMining::add_liquidity_success(
 			trading_pair,
-			&market_maker,
+			&pool,
 			&lp,
 			share_issued,
 			price,
@@ -384,7 +400,7 @@ fn test_submit_scores_of_lps_happy_path() {
 		let total_score = 100 * UNIT_BALANCE;
 		let mut results: BTreeMap<
 			(TradingPair, AccountId32, u16),
-			(BTreeMap<AccountId32, (u128, bool)>, u128),
+			(BTreeMap<AccountId32, (u128, bool)>, u128, Option<Decimal>),
 		> = BTreeMap::new();
 		results.insert(
 			(
@@ -392,9 +408,11 @@ fn test_submit_scores_of_lps_happy_path() {
 				market_maker.clone(),
 				1,
 			),
-			(score_map, total_score),
+			(score_map, total_score, None),
 		);
 		register_test_pool(true);
+		// Snapshot the pools for epoch 1
+		LiqudityMining::new_epoch(2);
 		assert_ok!(LiqudityMining::submit_scores_of_lps(RuntimeOrigin::none(), results));
 	})
 }
@@ -416,7 +434,7 @@ fn test_claim_rewards_by_lp_happy_path_and_error() {
 		let total_score = 100 * UNIT_BALANCE;
 		let mut results: BTreeMap<
 			(TradingPair, AccountId32, u16),
-			(BTreeMap<AccountId32, (u128, bool)>, u128),
+			(BTreeMap<AccountId32, (u128, bool)>, u128, Option<Decimal>),
 		> = BTreeMap::new();
 		results.insert(
 			(
@@ -424,8 +442,10 @@ fn test_claim_rewards_by_lp_happy_path_and_error() {
 				market_maker.clone(),
 				0,
 			),
-			(score_map, total_score),
+			(score_map, total_score, None),
 		);
+		// Snapshot the pools for epoch 0
+		LiqudityMining::new_epoch(1);
 		assert_ok!(LiqudityMining::submit_scores_of_lps(RuntimeOrigin::none(), results));
 		assert_ok!(LiqudityMining::claim_rewards_by_lp(
 			RuntimeOrigin::signed(trader.clone()),
@@ -462,7 +482,7 @@ fn test_claim_rewards_by_mm_happy_path_and_error() {
 		let total_score = 100 * UNIT_BALANCE;
 		let mut results: BTreeMap<
 			(TradingPair, AccountId32, u16),
-			(BTreeMap<AccountId32, (u128, bool)>, u128),
+			(BTreeMap<AccountId32, (u128, bool)>, u128, Option<Decimal>),
 		> = BTreeMap::new();
 		results.insert(
 			(
@@ -470,7 +490,7 @@ fn test_claim_rewards_by_mm_happy_path_and_error() {
 				market_maker.clone(),
 				1,
 			),
-			(score_map, total_score),
+			(score_map, total_score, None),
 		);
 		assert_ok!(LiqudityMining::submit_scores_of_lps(RuntimeOrigin::none(), results));
 		assert_ok!(LiqudityMining::claim_rewards_by_mm(
@@ -517,6 +537,496 @@ fn test_initiate_withdrawal() {
 	})
 }
 
+#[test]
+fn test_snapshot_pools_incrementally() {
+	new_test_ext().execute_with(|| {
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		register_test_pool(true);
+		let other_market_maker = AccountId32::new([4; 32]);
+		mint_base_quote_asset_for_user(other_market_maker.clone());
+		assert_ok!(LiqudityMining::register_pool(
+			RuntimeOrigin::signed(other_market_maker.clone()),
+			[2; 10],
+			trading_pair,
+			UNIT_BALANCE,
+			UNIT_BALANCE,
+			0,
+			true,
+			AccountId32::new([5; 32])
+		));
+		LiqudityMining::new_epoch(1);
+		let pools: Vec<AccountId32> = <Pools<Test>>::iter().map(|(_, mm, _)| mm).collect();
+		assert!(pools.iter().all(|mm| LiqudityMining::is_pool_snapshotting(trading_pair, mm)));
+		let sheet = |market_maker: &AccountId32| {
+			BTreeMap::from([((trading_pair, market_maker.clone(), 0), (BTreeMap::new(), 0))])
+		};
+		// Pools must be scored in storage order, one per submission
+		assert_noop!(
+			LiqudityMining::submit_scores_of_lps(RuntimeOrigin::none(), sheet(&pools[1])),
+			crate::pallet::Error::<Test>::InvalidSnapshotBatch
+		);
+		assert_ok!(LiqudityMining::submit_scores_of_lps(RuntimeOrigin::none(), sheet(&pools[0])));
+		assert!(!LiqudityMining::is_pool_snapshotting(trading_pair, &pools[0]));
+		assert!(LiqudityMining::is_pool_snapshotting(trading_pair, &pools[1]));
+		assert_noop!(
+			LiqudityMining::add_liquidity(
+				RuntimeOrigin::signed(pools[1].clone()),
+				trading_pair,
+				pools[1].clone(),
+				UNIT_BALANCE,
+				UNIT_BALANCE * 10
+			),
+			crate::pallet::Error::<Test>::SnapshotInProgress
+		);
+		assert_ok!(LiqudityMining::submit_scores_of_lps(RuntimeOrigin::none(), sheet(&pools[1])));
+		assert!(!LiqudityMining::is_pool_snapshotting(trading_pair, &pools[1]));
+	})
+}
+
+#[test]
+fn test_pause_pool() {
+	new_test_ext().execute_with(|| {
+		add_liquidity();
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let market_maker = AccountId32::new([2; 32]);
+		let lp = AccountId32::new([3; 32]);
+		let (pool, _share_id) = LiqudityMining::create_pool_account(&market_maker, trading_pair);
+		assert_ok!(LiqudityMining::set_pool_paused(
+			RuntimeOrigin::signed(market_maker.clone()),
+			trading_pair,
+			true
+		));
+		assert!(LiqudityMining::pool_flags(&pool).paused_by_market_maker);
+		assert_noop!(
+			LiqudityMining::add_liquidity(
+				RuntimeOrigin::signed(lp.clone()),
+				trading_pair,
+				market_maker.clone(),
+				UNIT_BALANCE,
+				UNIT_BALANCE * 10
+			),
+			crate::pallet::Error::<Test>::PoolPaused
+		);
+		// Withdrawals are only paused by governance
+		assert_ok!(LiqudityMining::force_set_pool_paused(
+			RuntimeOrigin::root(),
+			trading_pair,
+			market_maker.clone(),
+			true
+		));
+		assert_noop!(
+			LiqudityMining::remove_liquidity(
+				RuntimeOrigin::signed(lp.clone()),
+				trading_pair,
+				market_maker.clone(),
+				UNIT_BALANCE
+			),
+			crate::pallet::Error::<Test>::PoolPaused
+		);
+		assert_ok!(LiqudityMining::force_set_pool_paused(
+			RuntimeOrigin::root(),
+			trading_pair,
+			market_maker.clone(),
+			false
+		));
+		assert_ok!(LiqudityMining::remove_liquidity(
+			RuntimeOrigin::signed(lp),
+			trading_pair,
+			market_maker,
+			UNIT_BALANCE
+		));
+	})
+}
+
+#[test]
+fn test_remove_liquidity_instantly() {
+	new_test_ext().execute_with(|| {
+		add_liquidity();
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let market_maker = AccountId32::new([2; 32]);
+		let lp = AccountId32::new([3; 32]);
+		let (pool, share_id) = LiqudityMining::create_pool_account(&market_maker, trading_pair);
+		assert_ok!(LiqudityMining::remove_liquidity_instantly(
+			RuntimeOrigin::signed(lp.clone()),
+			trading_pair,
+			market_maker.clone(),
+			UNIT_BALANCE * 5
+		));
+		assert_eq!(Assets::balance(share_id, &lp), UNIT_BALANCE);
+		assert_eq!(
+			LiqudityMining::get_lp_shares(trading_pair, market_maker.clone(), lp.clone()),
+			UNIT_BALANCE
+		);
+		// 10% of the burned shares are not withdrawn and stay with the remaining LPs
+		let burn_frac = Decimal::new(75, 2);
+		assert!(OCEX::ingress_messages(1).contains(&IngressMessages::RemoveLiquidityInstantly(
+			TradingPairConfig::default(AssetId::Polkadex, AssetId::Asset(1)),
+			pool.clone(),
+			lp.clone(),
+			burn_frac,
+			Decimal::from(6 * UNIT_BALANCE),
+			0,
+		)));
+		System::assert_last_event(
+			crate::pallet::Event::<Test>::EarlyExitRequested {
+				market: trading_pair,
+				pool: pool.clone(),
+				lp: lp.clone(),
+				shares: UNIT_BALANCE * 5,
+				penalty: UNIT_BALANCE / 2,
+			}
+			.into(),
+		);
+		assert_noop!(
+			LiqudityMining::remove_liquidity_instantly(
+				RuntimeOrigin::signed(lp.clone()),
+				trading_pair,
+				market_maker.clone(),
+				UNIT_BALANCE
+			),
+			crate::pallet::Error::<Test>::EarlyExitInProgress
+		);
+
+		// Settling a regular withdrawal of the LP leaves the instant exit pending
+		assert_ok!(LiqudityMining::remove_liquidity_success(
+			trading_pair,
+			&pool,
+			&lp,
+			Decimal::ZERO,
+			Decimal::ZERO
+		));
+		assert_noop!(
+			LiqudityMining::remove_liquidity_instantly_failed(
+				trading_pair,
+				&pool,
+				&lp,
+				burn_frac,
+				Decimal::ZERO,
+				Decimal::ZERO,
+				Decimal::from(5),
+				Decimal::from(25),
+				1
+			),
+			crate::pallet::Error::<Test>::UnknownEarlyExit
+		);
+
+		// The pool lacks free balance, all the burned shares are minted back
+		assert_ok!(LiqudityMining::remove_liquidity_instantly_failed(
+			trading_pair,
+			&pool,
+			&lp,
+			burn_frac,
+			Decimal::ZERO,
+			Decimal::ZERO,
+			Decimal::from(5),
+			Decimal::from(25),
+			0
+		));
+		assert_eq!(Assets::balance(share_id, &lp), 6 * UNIT_BALANCE);
+		assert_eq!(
+			LiqudityMining::get_lp_shares(trading_pair, market_maker.clone(), lp.clone()),
+			6 * UNIT_BALANCE
+		);
+		assert_ok!(LiqudityMining::remove_liquidity_instantly(
+			RuntimeOrigin::signed(lp),
+			trading_pair,
+			market_maker,
+			UNIT_BALANCE
+		));
+	})
+}
+
+#[test]
+fn test_pool_queries() {
+	new_test_ext().execute_with(|| {
+		add_liquidity();
+		let market_maker = AccountId32::new([2; 32]);
+		let lp = AccountId32::new([3; 32]);
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let (pool, share_id) = LiqudityMining::create_pool_account(&market_maker, trading_pair);
+
+		let pools = LiqudityMining::list_pools();
+		assert_eq!(pools.len(), 1);
+		let (market, mm, config, flags, high_water_mark) = pools[0].clone();
+		assert_eq!((market, mm), (trading_pair, market_maker.clone()));
+		assert_eq!((config.pool_id, config.share_id), (pool.clone(), share_id));
+		assert!(config.public_funds_allowed);
+		assert!(!flags.deposits_paused());
+		assert_eq!(high_water_mark, None);
+
+		assert_eq!(
+			LiqudityMining::get_lp_shares(trading_pair, market_maker.clone(), lp.clone()),
+			6 * UNIT_BALANCE
+		);
+		assert_eq!(
+			LiqudityMining::get_lp_shares(trading_pair, AccountId32::new([9; 32]), lp.clone()),
+			0
+		);
+		assert_eq!(
+			LiqudityMining::get_pool_nav(trading_pair, AccountId32::new([9; 32])),
+			Err(crate::pallet::Error::<Test>::UnknownPool.into())
+		);
+
+		assert_ok!(LiqudityMining::remove_liquidity(
+			RuntimeOrigin::signed(lp.clone()),
+			trading_pair,
+			market_maker.clone(),
+			UNIT_BALANCE * 2
+		));
+		assert_eq!(
+			LiqudityMining::get_pending_withdrawals(0, trading_pair, market_maker.clone()),
+			vec![(lp.clone(), 2 * UNIT_BALANCE, 6 * UNIT_BALANCE)]
+		);
+		assert_eq!(
+			LiqudityMining::get_lp_shares(trading_pair, market_maker.clone(), lp.clone()),
+			4 * UNIT_BALANCE
+		);
+		// No scores submitted for the LP yet
+		assert_eq!(
+			LiqudityMining::get_claimable_rewards(trading_pair, market_maker, 0, lp),
+			(0, false)
+		);
+	})
+}
+
+#[test]
+fn test_liquidity_mining_flow_through_an_epoch() {
+	let mut ext = new_test_ext();
+	let (pool_ext, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(TransactionPoolExt::new(pool_ext));
+	// Pool balances are read from the OCEX offchain state during the snapshot
+	let (offchain, _offchain_state) = TestOffchainExt::with_offchain_db(ext.offchain_db());
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.execute_with(|| {
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let market_maker = AccountId32::new([2; 32]);
+		let lp = AccountId32::new([3; 32]);
+		let (pool, share_id) = LiqudityMining::create_pool_account(&market_maker, trading_pair);
+		// Runs the offchain snapshot and includes the scores it submits
+		let snapshot = || {
+			LiqudityMining::take_snapshot();
+			let tx = pool_state.write().transactions.pop().unwrap();
+			let tx = TestXt::<RuntimeCall, ()>::decode(&mut &*tx).unwrap();
+			assert_ok!(tx.call.dispatch(RuntimeOrigin::none()));
+		};
+		register_test_pool(true);
+		add_lmp_config();
+		snapshot();
+
+		// LP deposits during epoch 2 and the engine confirms it
+		let mut price_map = BTreeMap::new();
+		price_map.insert((AssetId::Polkadex, AssetId::Asset(1)), (Decimal::from(5), Decimal::ONE));
+		<PriceOracle<Test>>::set(price_map);
+		mint_base_quote_asset_for_user(lp.clone());
+		assert_ok!(LiqudityMining::add_liquidity(
+			RuntimeOrigin::signed(lp.clone()),
+			trading_pair,
+			market_maker.clone(),
+			UNIT_BALANCE * 6,
+			UNIT_BALANCE * 40
+		));
+		assert_ok!(OCEX::process_egress_msg(&vec![EgressMessages::AddLiquidityResult(
+			TradingPairConfig::default(AssetId::Polkadex, AssetId::Asset(1)),
+			pool.clone(),
+			lp.clone(),
+			Decimal::from(6),
+			Decimal::from(5),
+			Decimal::from(30),
+		)]));
+		assert_eq!(Assets::balance(share_id, &lp), 6 * UNIT_BALANCE);
+
+		// Epoch 3 starts and the LPs of epoch 2 are scored
+		System::set_block_number(101);
+		OCEX::start_new_epoch(101);
+		assert_eq!(LiqudityMining::active_lmp_epoch(), 3);
+		snapshot();
+		let (scores, total_score, _) = LiqudityMining::liquidity_providers(2, &pool);
+		assert_eq!(scores.get(&lp), Some(&(600 * UNIT_BALANCE, false)));
+		assert_eq!(total_score, 600 * UNIT_BALANCE);
+
+		// Engine finalizes the pending epochs, the pool earns rewards in epoch 2
+		assert_ok!(OCEX::update_lmp_scores(&BTreeMap::new()));
+		assert_ok!(OCEX::update_lmp_scores(&BTreeMap::new()));
+		let mut trader_metrics: TraderMetricsMap<AccountId32> = BTreeMap::new();
+		trader_metrics.insert(pool.clone(), (Decimal::from(100), Decimal::from(100)));
+		let mut trading_pair_metrics_map: TradingPairMetricsMap<AccountId32> = BTreeMap::new();
+		trading_pair_metrics_map
+			.insert(trading_pair, (trader_metrics, (Decimal::from(1000), Decimal::from(1000))));
+		assert_ok!(OCEX::update_lmp_scores(&trading_pair_metrics_map));
+		let reward_account =
+			<crate::mock::Test as pallet_ocex_lmp::Config>::LMPRewardsPalletId::get()
+				.into_account_truncating();
+		Balances::mint_into(&reward_account, 300 * UNIT_BALANCE).unwrap();
+
+		assert_eq!(
+			LiqudityMining::get_claimable_rewards(
+				trading_pair,
+				market_maker.clone(),
+				2,
+				lp.clone()
+			),
+			(200 * UNIT_BALANCE, false)
+		);
+		let lp_balance = Balances::free_balance(&lp);
+		assert_ok!(LiqudityMining::claim_rewards_by_lp(
+			RuntimeOrigin::signed(lp.clone()),
+			trading_pair,
+			market_maker.clone(),
+			2
+		));
+		assert_eq!(Balances::free_balance(&lp), lp_balance + 200 * UNIT_BALANCE);
+		assert_eq!(
+			LiqudityMining::get_claimable_rewards(
+				trading_pair,
+				market_maker.clone(),
+				2,
+				lp.clone()
+			),
+			(200 * UNIT_BALANCE, true)
+		);
+		assert_noop!(
+			LiqudityMining::claim_rewards_by_lp(
+				RuntimeOrigin::signed(lp),
+				trading_pair,
+				market_maker,
+				2
+			),
+			crate::pallet::Error::<Test>::AlreadyClaimed
+		);
+	})
+}
+
+#[test]
+fn test_performance_fee_above_high_water_mark() {
+	new_test_ext().execute_with(|| {
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let market_maker = AccountId32::new([2; 32]);
+		let lp = AccountId32::new([3; 32]);
+		register_test_trading_pair();
+		mint_base_quote_asset_for_user(market_maker.clone());
+		assert_noop!(
+			LiqudityMining::register_pool(
+				RuntimeOrigin::signed(market_maker.clone()),
+				[1; 10],
+				trading_pair,
+				UNIT_BALANCE,
+				UNIT_BALANCE,
+				UNIT_BALANCE * 2,
+				true,
+				AccountId32::new([1; 32])
+			),
+			crate::pallet::Error::<Test>::InvalidPerformanceFee
+		);
+		assert_ok!(LiqudityMining::register_pool(
+			RuntimeOrigin::signed(market_maker.clone()),
+			[1; 10],
+			trading_pair,
+			UNIT_BALANCE,
+			UNIT_BALANCE,
+			UNIT_BALANCE / 2,
+			true,
+			AccountId32::new([1; 32])
+		));
+		let (pool, share_id) = LiqudityMining::create_pool_account(&market_maker, trading_pair);
+		assert_ok!(LiqudityMining::add_liquidity_success(
+			trading_pair,
+			&pool,
+			&lp,
+			Decimal::from(6),
+			Decimal::from(5),
+			Decimal::from(40)
+		));
+		let sheet = |epoch: u16| {
+			BTreeMap::from([((trading_pair, market_maker.clone(), epoch), (BTreeMap::new(), 0))])
+		};
+		// The engine reports the pool value in quote for the 6 shares issued
+		let pool_valued = |epoch: u16, nav_per_share: u32| {
+			LiqudityMining::pool_valued(
+				trading_pair,
+				&pool,
+				epoch,
+				Decimal::from(nav_per_share * 6),
+				Decimal::from(6),
+			)
+		};
+
+		// The first NAV only sets the high-water mark
+		LiqudityMining::new_epoch(1);
+		assert_ok!(LiqudityMining::submit_scores_of_lps(RuntimeOrigin::none(), sheet(0)));
+		assert_ok!(pool_valued(0, 10));
+		assert_eq!(LiqudityMining::high_water_mark(&pool), Some(Decimal::from(10)));
+		assert_eq!(LiqudityMining::pool_nav(0, &pool), Some((Decimal::from(10), 0)));
+
+		// NAV grows from 10 to 14, half of the growth on 6 shares is paid with one new share
+		LiqudityMining::new_epoch(2);
+		assert_ok!(LiqudityMining::submit_scores_of_lps(RuntimeOrigin::none(), sheet(1)));
+		assert_ok!(pool_valued(1, 14));
+		assert_eq!(Assets::balance(share_id, &market_maker), UNIT_BALANCE);
+		assert_eq!(
+			LiqudityMining::get_lp_shares(trading_pair, market_maker.clone(), market_maker.clone()),
+			UNIT_BALANCE
+		);
+		assert_eq!(LiqudityMining::high_water_mark(&pool), Some(Decimal::from(12)));
+		assert_eq!(
+			LiqudityMining::get_performance_fee(1, trading_pair, market_maker.clone()),
+			Some((Decimal::from(14), UNIT_BALANCE))
+		);
+		System::assert_last_event(
+			crate::pallet::Event::<Test>::PerformanceFeeCharged {
+				market: trading_pair,
+				pool: pool.clone(),
+				epoch: 1,
+				nav_per_share: Decimal::from(14),
+				high_water_mark: Decimal::from(12),
+				shares: UNIT_BALANCE,
+			}
+			.into(),
+		);
+
+		// No fee is charged below the high-water mark
+		LiqudityMining::new_epoch(3);
+		assert_ok!(LiqudityMining::submit_scores_of_lps(RuntimeOrigin::none(), sheet(2)));
+		assert_ok!(pool_valued(2, 11));
+		assert_eq!(Assets::balance(share_id, &market_maker), UNIT_BALANCE);
+		assert_eq!(LiqudityMining::high_water_mark(&pool), Some(Decimal::from(12)));
+		assert_eq!(LiqudityMining::pool_nav(2, &pool), Some((Decimal::from(11), 0)));
+	})
+}
+
+#[test]
+fn test_migration_to_v1() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+	use parity_scale_codec::Encode;
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<LiqudityMining>();
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let market_maker = AccountId32::new([2; 32]);
+		let pool = AccountId32::new([3; 32]);
+		let commission = Decimal::from_f64(0.1).unwrap();
+		let exit_fee = Decimal::from_f64(0.01).unwrap();
+		frame_support::storage::unhashed::put_raw(
+			&<Pools<Test>>::hashed_key_for(trading_pair, &market_maker),
+			&(pool.clone(), commission, exit_fee, true, [1u8; 10], 10u128, false).encode(),
+		);
+		crate::migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<LiqudityMining>(), 1);
+		assert_eq!(
+			<Pools<Test>>::get(trading_pair, &market_maker),
+			Some(crate::types::MarketMakerConfig {
+				pool_id: pool,
+				commission,
+				exit_fee,
+				performance_fee: Decimal::ZERO,
+				public_funds_allowed: true,
+				name: [1; 10],
+				share_id: 10,
+				force_closed: false,
+			})
+		);
+	})
+}
+
 pub fn update_lmp_score() {
 	let total_score = Decimal::from(1000);
 	let total_fee_paid = Decimal::from(1000);
@@ -551,6 +1061,7 @@ pub fn add_lmp_config() {
 		min_maker_volume: polkadex_primitives::UNIT_BALANCE,
 		max_spread: polkadex_primitives::UNIT_BALANCE,
 		min_depth: polkadex_primitives::UNIT_BALANCE,
+		reward_streams: vec![],
 	};
 	assert_ok!(OCEX::set_lmp_epoch_config(
 		RuntimeOrigin::root(),
@@ -591,9 +1102,10 @@ fn add_liquidity() {
 	let share_issued = Decimal::from(6);
 	let price = Decimal::from(5);
 	let total_inventory_in_quote = Decimal::from(40);
+	let (pool, _share_id) = LiqudityMining::create_pool_account(&market_maker, trading_pair);
 	assert_ok!(LiqudityMining::add_liquidity_success(
 		trading_pair,
-		&market_maker,
+		&pool,
 		&user_who_wants_to_add_liq,
 		share_issued,
 		price,
@@ -632,6 +1144,7 @@ fn register_test_pool(public_fund_allowed: bool) {
 		trading_pair,
 		commission,
 		exit_fee,
+		0,
 		public_fund_allowed,
 		trading_account.clone()
 	));
@@ -20,15 +20,38 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use rust_decimal::Decimal;
 use scale_info::TypeInfo;
 
-#[derive(Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq, Eq)]
 pub struct MarketMakerConfig<AccountId> {
 	pub pool_id: AccountId,
 	pub commission: Decimal,
 	pub exit_fee: Decimal,
+	/// Fee on the growth of NAV per share above the high-water mark, paid in new shares
+	pub performance_fee: Decimal,
 	pub public_funds_allowed: bool,
 	pub name: [u8; 10],
 	pub share_id: u128,
 	pub force_closed: bool,
 }
 
+/// Pause flags of a pool
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct PoolFlags {
+	/// Deposits paused by the market maker
+	pub paused_by_market_maker: bool,
+	/// Deposits and withdrawals paused by governance
+	pub paused_by_governance: bool,
+}
+
+impl PoolFlags {
+	/// Returns true if new deposits are not accepted
+	pub fn deposits_paused(&self) -> bool {
+		self.paused_by_market_maker || self.paused_by_governance
+	}
+
+	/// Returns true if withdrawals are not accepted
+	pub fn withdrawals_paused(&self) -> bool {
+		self.paused_by_governance
+	}
+}
+
 pub type EpochNumber = u32;
@@ -0,0 +1,78 @@
+//! Weights for `pallet_lmp`
+//!
+//! PLACEHOLDER WEIGHTS, NOT MEASURED: regenerate with the benchmark CLI before release.
+
+// Executed Command:
+// ./polkadex-node
+// benchmark
+// pallet
+// --pallet
+// pallet-lmp
+// --steps
+// 100
+// --repeat
+// 200
+// --extrinsic
+// *
+// --output
+// pallet_lmp_weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_lmp`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::LiquidityMiningWeightInfo for WeightInfo<T> {
+	/// Storage: `CrowdSourceLMP::Pools` (r:1 w:0)
+	/// Proof: `CrowdSourceLMP::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CrowdSourceLMP::PoolFlagsOf` (r:1 w:1)
+	/// Proof: `CrowdSourceLMP::PoolFlagsOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_pool_paused() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3634))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CrowdSourceLMP::Pools` (r:1 w:0)
+	/// Proof: `CrowdSourceLMP::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CrowdSourceLMP::PoolFlagsOf` (r:1 w:1)
+	/// Proof: `CrowdSourceLMP::PoolFlagsOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn force_set_pool_paused() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3634))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CrowdSourceLMP::Pools` (r:1 w:0)
+	/// Proof: `CrowdSourceLMP::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CrowdSourceLMP::SnapshotFlag` (r:1 w:0)
+	/// Proof: `CrowdSourceLMP::SnapshotFlag` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CrowdSourceLMP::PoolFlagsOf` (r:1 w:0)
+	/// Proof: `CrowdSourceLMP::PoolFlagsOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CrowdSourceLMP::PendingEarlyExits` (r:1 w:1)
+	/// Proof: `CrowdSourceLMP::PendingEarlyExits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `CrowdSourceLMP::LPShares` (r:1 w:1)
+	/// Proof: `CrowdSourceLMP::LPShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CrowdSourceLMP::NextEarlyExitId` (r:1 w:1)
+	/// Proof: `CrowdSourceLMP::NextEarlyExitId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::IngressMessages` (r:1 w:1)
+	/// Proof: `OCEX::IngressMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_liquidity_instantly() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(80_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3687))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+}
@@ -18,7 +18,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use orderbook_primitives::{types::TradingPair, ObCheckpointRaw};
+use orderbook_primitives::{lmp::LMPScheduledEpoch, types::TradingPair, ObCheckpointRaw};
 use parity_scale_codec::Codec;
 use polkadex_primitives::AssetId;
 use rust_decimal::Decimal;
@@ -37,6 +37,8 @@ sp_api::decl_runtime_apis! {
 		fn top_lmp_accounts(epoch: u16, market: TradingPair, sorted_by_mm_score: bool, limit: u16) -> Vec<AccountId>;
 		// Returns the eligible rewards for given main, epoch and market
 		fn calculate_lmp_rewards(main: AccountId, epoch: u16, market: TradingPair) -> (Decimal, Decimal, bool);
+		// Returns the immediate and vested portions of the eligible rewards for given main, epoch and market
+		fn calculate_lmp_claimable_rewards(main: AccountId, epoch: u16, market: TradingPair) -> (Decimal, Decimal, bool);
 		// Returns the total fees paid by user in quote currency units
 		fn get_fees_paid_by_user_per_epoch(epoch: u32,market: TradingPair, main: AccountId) -> Decimal;
 		// Returns the total volume generated by user in quote currency units
@@ -45,5 +47,14 @@ sp_api::decl_runtime_apis! {
 		fn get_total_score(epoch: u16, market: TradingPair) -> (Decimal, Decimal);
 		// Returns the individual score
 		fn get_trader_metrics(epoch: u16, market: TradingPair, main: AccountId) -> (Decimal, Decimal, bool);
+		// Returns the configs and expected start blocks of the upcoming LMP epochs
+		fn get_lmp_schedule() -> Vec<LMPScheduledEpoch<AccountId>>;
+		// Returns the Q score per minute index of given main in that epoch and market
+		fn get_lmp_score_timeline(epoch: u16, market: TradingPair, main: AccountId) -> BTreeMap<u16, Decimal>;
+		// Returns the projected rewards of given main if the running epoch ended now
+		fn get_projected_lmp_rewards(main: AccountId, market: TradingPair) -> (Decimal, Decimal);
+		// Returns a page of the rewarded accounts of a finalized epoch with their score and fees paid,
+		// in descending order of scores
+		fn lmp_rewarded_leaderboard(epoch: u16, market: TradingPair, sorted_by_mm_score: bool, offset: u32, limit: u16) -> Vec<(AccountId, Decimal, Decimal)>;
 	}
 }
@@ -109,6 +109,16 @@ pub trait PolkadexOcexRpcApi<BlockHash, AccountId, Hash> {
 		at: Option<BlockHash>,
 	) -> RpcResult<Vec<u16>>;
 
+	/// Returns (epoch, immediate rewards, vested rewards) of every unclaimed epoch
+	#[method(name = "lmp_listClaimableEpochsV2")]
+	fn list_claimable_epochs_v2(
+		&self,
+		market: String,
+		main: AccountId,
+		until_epoch: u16,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(u16, String, String)>>;
+
 	#[method(name = "lmp_totalScore")]
 	fn get_total_score(
 		&self,
@@ -125,6 +135,35 @@ pub trait PolkadexOcexRpcApi<BlockHash, AccountId, Hash> {
 		epoch: u16,
 		at: Option<BlockHash>,
 	) -> RpcResult<(String, String, bool)>;
+
+	#[method(name = "lmp_scoreTimeline")]
+	fn get_score_timeline(
+		&self,
+		epoch: u16,
+		market: String,
+		main: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<(u16, String)>, u16)>;
+
+	#[method(name = "lmp_projectedRewards")]
+	fn get_projected_rewards(
+		&self,
+		market: String,
+		main: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<(String, String)>;
+
+	/// Lists the rewarded traders of a finalized epoch, the running epoch is not covered
+	#[method(name = "lmp_rewardedLeaderboard")]
+	fn get_rewarded_leaderboard(
+		&self,
+		epoch: u16,
+		market: String,
+		sorted_by_mm_score: bool,
+		offset: u32,
+		limit: u16,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, String, String)>>;
 }
 
 /// A structure that represents the Polkadex OCEX pallet RPC, which allows querying
@@ -381,6 +420,37 @@ where
 		Ok(claimable_epochs)
 	}
 
+	fn list_claimable_epochs_v2(
+		&self,
+		market: String,
+		main: AccountId,
+		until_epoch: u16,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u16, String, String)>> {
+		let mut api = self.client.runtime_api();
+		api.register_extension(OffchainDbExt::new(self.offchain_db.clone()));
+		let market = TradingPair::try_from(market).map_err(runtime_error_into_rpc_err)?;
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let mut claimable_epochs = Vec::new();
+
+		for epoch in 0..=until_epoch {
+			let (immediate, vested, is_claimed) = api
+				.calculate_lmp_claimable_rewards(at, main.clone(), epoch, market)
+				.map_err(runtime_error_into_rpc_err)?;
+			// If any one of the rewards are present and is_claimed is false,
+			// then its claimable
+			if (!immediate.is_zero() || !vested.is_zero()) && !is_claimed {
+				claimable_epochs.push((epoch, immediate.to_string(), vested.to_string()))
+			}
+		}
+
+		Ok(claimable_epochs)
+	}
+
 	fn get_total_score(
 		&self,
 		market: String,
@@ -420,6 +490,77 @@ where
 			.map_err(runtime_error_into_rpc_err)?;
 		Ok((mm_score.to_string(), trading_score.to_string(), is_claimed))
 	}
+
+	fn get_score_timeline(
+		&self,
+		epoch: u16,
+		market: String,
+		main: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(Vec<(u16, String)>, u16)> {
+		let mut api = self.client.runtime_api();
+		api.register_extension(OffchainDbExt::new(self.offchain_db.clone()));
+		let market = TradingPair::try_from(market).map_err(runtime_error_into_rpc_err)?;
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let timeline = api
+			.get_lmp_score_timeline(at, epoch, market, main)
+			.map_err(runtime_error_into_rpc_err)?;
+		let uptime = timeline.len() as u16;
+		let scores = timeline
+			.into_iter()
+			.map(|(index, score)| (index, score.to_string()))
+			.collect::<Vec<(u16, String)>>();
+		Ok((scores, uptime))
+	}
+
+	fn get_projected_rewards(
+		&self,
+		market: String,
+		main: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(String, String)> {
+		let mut api = self.client.runtime_api();
+		api.register_extension(OffchainDbExt::new(self.offchain_db.clone()));
+		let market = TradingPair::try_from(market).map_err(runtime_error_into_rpc_err)?;
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let (mm_rewards, trading_rewards) = api
+			.get_projected_lmp_rewards(at, main, market)
+			.map_err(runtime_error_into_rpc_err)?;
+		Ok((mm_rewards.to_string(), trading_rewards.to_string()))
+	}
+
+	fn get_rewarded_leaderboard(
+		&self,
+		epoch: u16,
+		market: String,
+		sorted_by_mm_score: bool,
+		offset: u32,
+		limit: u16,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, String, String)>> {
+		let api = self.client.runtime_api();
+		let market = TradingPair::try_from(market).map_err(runtime_error_into_rpc_err)?;
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let leaderboard = api
+			.lmp_rewarded_leaderboard(at, epoch, market, sorted_by_mm_score, offset, limit)
+			.map_err(runtime_error_into_rpc_err)?;
+		Ok(leaderboard
+			.into_iter()
+			.map(|(main, mm_score, fees_paid)| (main, mm_score.to_string(), fees_paid.to_string()))
+			.collect())
+	}
 }
 
 /// Converts a runtime trap into an RPC error.
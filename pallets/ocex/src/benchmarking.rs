@@ -27,6 +27,7 @@ use frame_benchmarking::{
 };
 use frame_support::traits::OnInitialize;
 use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+use frame_support::BoundedVec;
 use frame_system::RawOrigin;
 use orderbook_primitives::{TraderMetricsMap, TradingPairMetrics, TradingPairMetricsMap};
 use parity_scale_codec::{Compact, Decode};
@@ -388,6 +389,7 @@ benchmarks! {
 		min_maker_volume: UNIT_BALANCE,
 		max_spread: UNIT_BALANCE,
 		min_depth: UNIT_BALANCE,
+		reward_streams: vec![],
 		};
 		Ocex::<T>::set_lmp_epoch_config(
 		RawOrigin::Root.into(),
@@ -451,6 +453,7 @@ benchmarks! {
 		min_maker_volume: UNIT_BALANCE,
 		max_spread: UNIT_BALANCE,
 		min_depth: UNIT_BALANCE,
+		reward_streams: vec![],
 		};
 	}: _(RawOrigin::Root, total_liquidity_mining_rewards, total_trading_rewards, vec![lmp_config], max_accounts_rewarded, claim_safety_period)
 	verify {
@@ -462,19 +465,269 @@ benchmarks! {
 		let auction_duration: BlockNumberFor<T> = 100u32.into();
 		let burn_ration = 10u8;
 		let fee_distribution = FeeDistribution {
-			recipient_address: recipient_address.clone(),
+			recipients: vec![FeeRecipient { account: recipient_address.clone(), share: 100 }]
+				.try_into()
+				.unwrap(),
 			auction_duration,
 			burn_ration,
+			mode: FeeDistributionMode::Auction,
 		};
 	}: _(RawOrigin::Root, fee_distribution)
 // After executing the benchmark, verify the fee distribution configuration
 verify {
    let current_fee_distribution = <FeeDistributionConfig<T>>::get().unwrap();
-   assert_eq!(current_fee_distribution.recipient_address, recipient_address);
+   assert_eq!(current_fee_distribution.recipients[0].account, recipient_address);
    assert_eq!(current_fee_distribution.auction_duration, auction_duration);
    assert_eq!(current_fee_distribution.burn_ration, burn_ration);
 }
 
+	set_fee_recipients {
+		let recipient_address = T::AccountId::decode(&mut &[1; 32][..]).unwrap();
+		let fee_distribution = FeeDistribution {
+			recipients: vec![FeeRecipient { account: recipient_address, share: 100 }]
+				.try_into()
+				.unwrap(),
+			auction_duration: 100u32.into(),
+			burn_ration: 10u8,
+			mode: FeeDistributionMode::Auction,
+		};
+		<FeeDistributionConfig<T>>::put(fee_distribution);
+		let recipients: FeeRecipients<T::AccountId> = (0..MAX_FEE_RECIPIENTS as u8)
+			.map(|i| FeeRecipient {
+				account: T::AccountId::decode(&mut &[i; 32][..]).unwrap(),
+				share: if i == 0 { 100 - (MAX_FEE_RECIPIENTS as u8 - 1) } else { 1 },
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(RawOrigin::Root, recipients)
+	verify {
+		let current_fee_distribution = <FeeDistributionConfig<T>>::get().unwrap();
+		assert_eq!(current_fee_distribution.recipients.len(), MAX_FEE_RECIPIENTS as usize);
+	}
+
+	refund_lmp_reward_streams {
+		let base_asset = AssetId::Polkadex;
+		let quote_asset = AssetId::Asset(1);
+		let trading_pair = TradingPair { base: base_asset, quote: quote_asset };
+		let sponsor = T::AccountId::decode(&mut &[2; 32][..]).unwrap();
+		let _imbalance = T::NativeCurrency::deposit_creating(&sponsor, UNIT_BALANCE.saturated_into());
+		T::OtherAssets::create(quote_asset.asset_id().unwrap(), sponsor.clone(), true, One::one()).unwrap();
+		T::OtherAssets::mint_into(
+			quote_asset.asset_id().unwrap(),
+			&sponsor,
+			(1000 * UNIT_BALANCE).saturated_into(),
+		).unwrap();
+		let mut allowliested_tokens = AllowlistedToken::<T>::get();
+		allowliested_tokens.try_insert(base_asset).unwrap();
+		allowliested_tokens.try_insert(quote_asset).unwrap();
+		AllowlistedToken::<T>::put(allowliested_tokens);
+		<ExchangeState<T>>::put(true);
+		Ocex::<T>::register_trading_pair(
+		RawOrigin::Root.into(),
+		base_asset,
+		quote_asset,
+		(1_0000_0000_u128 * 1_000_000_u128).saturated_into(),
+		(1_000_000_000_000_000_u128 * 1_000_u128).saturated_into(),
+		1_000_000_u128.saturated_into(),
+		1_0000_0000_u128.saturated_into(),
+		).unwrap();
+		let lmp_config = LMPMarketConfigWrapper {
+		trading_pair,
+		market_weightage: UNIT_BALANCE,
+		min_fees_paid: UNIT_BALANCE,
+		min_maker_volume: UNIT_BALANCE,
+		max_spread: UNIT_BALANCE,
+		min_depth: UNIT_BALANCE,
+		reward_streams: vec![0],
+		};
+		Ocex::<T>::fund_lmp_reward_stream(
+			RawOrigin::Signed(sponsor.clone()).into(),
+			trading_pair,
+			quote_asset,
+			Compact::from(1000 * UNIT_BALANCE),
+		).unwrap();
+		Ocex::<T>::set_lmp_epoch_config(
+		RawOrigin::Root.into(),
+		Some(Compact::from(1000 * UNIT_BALANCE)),
+		Some(Compact::from(1000 * UNIT_BALANCE)),
+		vec![lmp_config],
+		Some(10),
+		Some(0)
+		).unwrap();
+		Ocex::<T>::start_new_epoch(1u32.saturated_into());
+		let epoch = 1;
+		<LMPClaimBlk<T>>::insert(epoch, BlockNumberFor::<T>::zero());
+		frame_system::Pallet::<T>::set_block_number(
+			T::LMPRewardStreamsClaimPeriod::get().saturated_into(),
+		);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), epoch, trading_pair)
+	verify {
+		assert!(<LMPRewardStreamsRefunded<T>>::get(epoch, trading_pair));
+		assert_eq!(
+			T::OtherAssets::balance(quote_asset.asset_id().unwrap(), &sponsor),
+			(1000 * UNIT_BALANCE).saturated_into()
+		);
+	}
+
+	fund_lmp_reward_stream {
+		let base_asset = AssetId::Polkadex;
+		let quote_asset = AssetId::Asset(1);
+		let trading_pair = TradingPair { base: base_asset, quote: quote_asset };
+		let sponsor = T::AccountId::decode(&mut &[2; 32][..]).unwrap();
+		let _imbalance = T::NativeCurrency::deposit_creating(&sponsor, UNIT_BALANCE.saturated_into());
+		T::OtherAssets::create(quote_asset.asset_id().unwrap(), sponsor.clone(), true, One::one()).unwrap();
+		T::OtherAssets::mint_into(
+			quote_asset.asset_id().unwrap(),
+			&sponsor,
+			(1000 * UNIT_BALANCE).saturated_into(),
+		).unwrap();
+		let mut allowliested_tokens = AllowlistedToken::<T>::get();
+		allowliested_tokens.try_insert(base_asset).unwrap();
+		allowliested_tokens.try_insert(quote_asset).unwrap();
+		AllowlistedToken::<T>::put(allowliested_tokens);
+		<ExchangeState<T>>::put(true);
+		Ocex::<T>::register_trading_pair(
+		RawOrigin::Root.into(),
+		base_asset,
+		quote_asset,
+		(1_0000_0000_u128 * 1_000_000_u128).saturated_into(),
+		(1_000_000_000_000_000_u128 * 1_000_u128).saturated_into(),
+		1_000_000_u128.saturated_into(),
+		1_0000_0000_u128.saturated_into(),
+		).unwrap();
+	}: _(RawOrigin::Signed(sponsor), trading_pair, quote_asset, Compact::from(1000 * UNIT_BALANCE))
+	verify {
+		assert!(<FundedLMPRewardStreams<T>>::get(0).is_some());
+	}
+
+	withdraw_lmp_reward_stream {
+		let base_asset = AssetId::Polkadex;
+		let quote_asset = AssetId::Asset(1);
+		let trading_pair = TradingPair { base: base_asset, quote: quote_asset };
+		let sponsor = T::AccountId::decode(&mut &[2; 32][..]).unwrap();
+		let _imbalance = T::NativeCurrency::deposit_creating(&sponsor, UNIT_BALANCE.saturated_into());
+		T::OtherAssets::create(quote_asset.asset_id().unwrap(), sponsor.clone(), true, One::one()).unwrap();
+		T::OtherAssets::mint_into(
+			quote_asset.asset_id().unwrap(),
+			&sponsor,
+			(1000 * UNIT_BALANCE).saturated_into(),
+		).unwrap();
+		let mut allowliested_tokens = AllowlistedToken::<T>::get();
+		allowliested_tokens.try_insert(base_asset).unwrap();
+		allowliested_tokens.try_insert(quote_asset).unwrap();
+		AllowlistedToken::<T>::put(allowliested_tokens);
+		<ExchangeState<T>>::put(true);
+		Ocex::<T>::register_trading_pair(
+		RawOrigin::Root.into(),
+		base_asset,
+		quote_asset,
+		(1_0000_0000_u128 * 1_000_000_u128).saturated_into(),
+		(1_000_000_000_000_000_u128 * 1_000_u128).saturated_into(),
+		1_000_000_u128.saturated_into(),
+		1_0000_0000_u128.saturated_into(),
+		).unwrap();
+		Ocex::<T>::fund_lmp_reward_stream(
+			RawOrigin::Signed(sponsor.clone()).into(),
+			trading_pair,
+			quote_asset,
+			Compact::from(1000 * UNIT_BALANCE),
+		).unwrap();
+	}: _(RawOrigin::Signed(sponsor.clone()), 0)
+	verify {
+		assert_eq!(
+			T::OtherAssets::balance(quote_asset.asset_id().unwrap(), &sponsor),
+			(1000 * UNIT_BALANCE).saturated_into()
+		);
+	}
+
+	schedule_lmp_epoch_config {
+		let base_asset = AssetId::Polkadex;
+		let quote_asset = AssetId::Asset(1);
+		let trading_pair = TradingPair { base: base_asset, quote: quote_asset };
+		let mut allowliested_tokens = AllowlistedToken::<T>::get();
+		allowliested_tokens.try_insert(base_asset).unwrap();
+		allowliested_tokens.try_insert(quote_asset).unwrap();
+		AllowlistedToken::<T>::put(allowliested_tokens);
+		<ExchangeState<T>>::put(true);
+		Ocex::<T>::register_trading_pair(
+		RawOrigin::Root.into(),
+		base_asset,
+		quote_asset,
+		(1_0000_0000_u128 * 1_000_000_u128).saturated_into(),
+		(1_000_000_000_000_000_u128 * 1_000_u128).saturated_into(),
+		1_000_000_u128.saturated_into(),
+		1_0000_0000_u128.saturated_into(),
+		).unwrap();
+		let lmp_config = LMPMarketConfigWrapper {
+		trading_pair,
+		market_weightage: UNIT_BALANCE,
+		min_fees_paid: UNIT_BALANCE,
+		min_maker_volume: UNIT_BALANCE,
+		max_spread: UNIT_BALANCE,
+		min_depth: UNIT_BALANCE,
+		reward_streams: vec![],
+		};
+		let epoch = MAX_SCHEDULED_LMP_EPOCHS;
+	}: _(RawOrigin::Root, epoch, Some(Compact::from(1000 * UNIT_BALANCE)), Some(Compact::from(1000 * UNIT_BALANCE)), vec![lmp_config], vec![], Some(10), Some(10))
+	verify {
+		assert!(<ScheduledLMPConfigs<T>>::contains_key(epoch));
+	}
+
+	cancel_scheduled_lmp_config {
+		<ScheduledLMPConfigs<T>>::insert(1, LMPEpochConfigDiff::default());
+	}: _(RawOrigin::Root, 1)
+	verify {
+		assert!(!<ScheduledLMPConfigs<T>>::contains_key(1));
+	}
+
+	set_lmp_epoch_duration {
+		let duration = LMPEpochDuration { epoch_length: 100800, withdrawal_stop_offset: 3600 };
+	}: _(RawOrigin::Root, duration)
+	verify {
+		assert_eq!(<PendingLMPEpochDuration<T>>::get(), Some(duration));
+	}
+
+	set_lmp_vesting_policy {
+		<ExpectedLMPConfig<T>>::put(LMPEpochConfig::default());
+		let vesting = LMPVestingPolicy {
+			cliff: 7200,
+			linear_period: 201600,
+			immediate_fraction: Decimal::from_f32(0.25).unwrap(),
+		};
+	}: _(RawOrigin::Root, Some(vesting))
+	verify {
+		assert_eq!(<ExpectedLMPConfig<T>>::get().unwrap().vesting, Some(vesting));
+	}
+
+	claim_lmp_rewards_multi {
+		let x in 1 .. MAX_LMP_CLAIMS;
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let trader = T::AccountId::decode(&mut &[1; 32][..]).unwrap();
+		let _imbalance = T::NativeCurrency::deposit_creating(&trader, UNIT_BALANCE.saturated_into());
+		let mut config = LMPEpochConfig::default();
+		config.total_liquidity_mining_rewards = Decimal::from(10);
+		config.total_trading_rewards = Decimal::from(10);
+		let mut claims = Vec::new();
+		for epoch in 0..x as u16 {
+			<LMPConfig<T>>::insert(epoch, config.clone());
+			<LMPClaimBlk<T>>::insert(epoch, BlockNumberFor::<T>::zero());
+			<TotalScores<T>>::insert(epoch, trading_pair, (Decimal::from(1000), Decimal::from(1000)));
+			<TraderMetrics<T>>::insert(
+				(epoch, trading_pair, trader.clone()),
+				(Decimal::from(100), Decimal::from(100), false),
+			);
+			claims.push((epoch, trading_pair));
+		}
+		let reward_account = T::LMPRewardsPalletId::get().into_account_truncating();
+		let _imbalance = T::NativeCurrency::deposit_creating(&reward_account, (100 * UNIT_BALANCE).saturated_into());
+		let claims: BoundedVec<_, _> = claims.try_into().unwrap();
+	}: _(RawOrigin::Signed(trader.clone()), claims)
+	verify {
+		assert!(<TraderMetrics<T>>::get((0, trading_pair, trader)).2);
+	}
+
 	place_bid {
 		let old_bidder = T::AccountId::decode(&mut &[1; 32][..]).unwrap();
 		let auction_info: AuctionInfo<T::AccountId, BalanceOf<T>> =
@@ -515,6 +768,7 @@ verify {
 		min_maker_volume: UNIT_BALANCE,
 		max_spread: UNIT_BALANCE,
 		min_depth: UNIT_BALANCE,
+		reward_streams: vec![],
 		};
 		Ocex::<T>::set_lmp_epoch_config(
 		RawOrigin::Root.into(),
@@ -530,9 +784,12 @@ verify {
 		let auction_duration: BlockNumberFor<T> = 100u32.into();
 		let burn_ration = 10u8;
 		let fee_distribution = FeeDistribution {
-			recipient_address,
+			recipients: vec![FeeRecipient { account: recipient_address, share: 100 }]
+				.try_into()
+				.unwrap(),
 			auction_duration,
 			burn_ration,
+			mode: FeeDistributionMode::Auction,
 		};
 		Ocex::<T>::set_fee_distribution(RawOrigin::Root.into(), fee_distribution).unwrap();
 		let mut allowlisted_tokens = <AllowlistedToken<T>>::get();
@@ -602,9 +859,9 @@ fn get_dummy_snapshot<T: Config>() -> SnapshotSummary<T::AccountId> {
 #[cfg(test)]
 use frame_benchmarking::impl_benchmark_test_suite;
 use frame_support::traits::fungibles::Create;
-use orderbook_primitives::lmp::LMPMarketConfigWrapper;
+use orderbook_primitives::lmp::{LMPEpochConfig, LMPMarketConfigWrapper, LMPVestingPolicy};
 use orderbook_primitives::ocex::TradingPairConfig;
-use polkadex_primitives::auction::AuctionInfo;
+use polkadex_primitives::auction::{AuctionInfo, MAX_FEE_RECIPIENTS};
 
 #[cfg(test)]
 impl_benchmark_test_suite!(Ocex, crate::mock::new_test_ext(), crate::mock::Test);
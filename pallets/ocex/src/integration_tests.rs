@@ -37,7 +37,7 @@ use orderbook_primitives::types::{
 };
 use orderbook_primitives::SnapshotSummary;
 use parity_scale_codec::{Compact, Encode};
-use polkadex_primitives::auction::FeeDistribution;
+use polkadex_primitives::auction::{FeeDistribution, FeeDistributionMode, FeeRecipient};
 use polkadex_primitives::{AssetId, UNIT_BALANCE};
 use rust_decimal::Decimal;
 use sequential_test::sequential;
@@ -156,9 +156,12 @@ fn test_on_chain_validation_with_auction() {
 		let auction_duration = 100;
 		let burn_ration = 50;
 		let fee_distribution = FeeDistribution {
-			recipient_address: recipient_address.clone(),
+			recipients: vec![FeeRecipient { account: recipient_address.clone(), share: 100 }]
+				.try_into()
+				.unwrap(),
 			auction_duration,
 			burn_ration,
+			mode: FeeDistributionMode::Auction,
 		};
 		assert_ok!(OCEX::set_fee_distribution(RuntimeOrigin::root(), fee_distribution));
 		set_lmp_config();
@@ -247,6 +250,7 @@ pub fn set_lmp_config() {
 		min_maker_volume: UNIT_BALANCE,
 		max_spread: UNIT_BALANCE,
 		min_depth: UNIT_BALANCE,
+		reward_streams: vec![],
 	};
 	assert_ok!(OCEX::set_lmp_epoch_config(
 		RuntimeOrigin::root(),
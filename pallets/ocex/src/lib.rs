@@ -41,7 +41,11 @@ use num_traits::Zero;
 pub use pallet::*;
 use pallet_timestamp as timestamp;
 use parity_scale_codec::Encode;
-use polkadex_primitives::{assets::AssetId, auction::FeeDistribution, AccountId, UNIT_BALANCE};
+use polkadex_primitives::{
+	assets::AssetId,
+	auction::{FeeDistribution, FeeDistributionMode, FeeRecipient, FeeRecipients},
+	AccountId, UNIT_BALANCE,
+};
 use rust_decimal::Decimal;
 use sp_application_crypto::RuntimeAppPublic;
 use sp_core::crypto::KeyTypeId;
@@ -53,7 +57,10 @@ use sp_std::{ops::Div, prelude::*};
 // Re-export pallet items so that they can be accessed from the crate namespace.
 use frame_support::traits::fungible::Inspect as InspectNative;
 use frame_system::pallet_prelude::BlockNumberFor;
-use orderbook_primitives::lmp::LMPMarketConfig;
+use orderbook_primitives::lmp::{
+	LMPEpochConfigDiff, LMPEpochDuration, LMPMarketConfig, LMPRewardStream, LMPVestingPolicy,
+	MAX_SCHEDULED_LMP_EPOCHS,
+};
 use orderbook_primitives::ocex::TradingPairConfig;
 use orderbook_primitives::{
 	types::{AccountAsset, TradingPair},
@@ -99,6 +106,7 @@ pub mod aggregator;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 mod lmp;
+pub mod migration;
 pub mod rpc;
 mod session;
 mod settlement;
@@ -113,6 +121,8 @@ type BalanceOf<T> =
 const DEPOSIT_MAX: u128 = 1_000_000_000_000_000_000_000_000_000;
 const WITHDRAWAL_MAX: u128 = 1_000_000_000_000_000_000_000_000_000;
 const TRADE_OPERATION_MIN_VALUE: u128 = 10000;
+/// Maximum number of (epoch, market) pairs claimed in one `claim_lmp_rewards_multi` call
+pub const MAX_LMP_CLAIMS: u32 = 20;
 
 /// Weight abstraction required for "ocex" pallet.
 pub trait OcexWeightInfo {
@@ -134,6 +144,15 @@ pub trait OcexWeightInfo {
 	fn claim_lmp_rewards() -> Weight;
 	fn set_lmp_epoch_config() -> Weight;
 	fn set_fee_distribution() -> Weight;
+	fn set_fee_recipients() -> Weight;
+	fn refund_lmp_reward_streams() -> Weight;
+	fn fund_lmp_reward_stream() -> Weight;
+	fn withdraw_lmp_reward_stream() -> Weight;
+	fn schedule_lmp_epoch_config() -> Weight;
+	fn cancel_scheduled_lmp_config() -> Weight;
+	fn set_lmp_epoch_duration() -> Weight;
+	fn set_lmp_vesting_policy() -> Weight;
+	fn claim_lmp_rewards_multi(x: u32) -> Weight;
 	fn place_bid() -> Weight;
 	fn on_initialize() -> Weight;
 }
@@ -143,7 +162,7 @@ pub trait OcexWeightInfo {
 #[allow(clippy::too_many_arguments)]
 #[frame_support::pallet]
 pub mod pallet {
-	use orderbook_primitives::traits::LiquidityMiningCrowdSourcePallet;
+	use orderbook_primitives::traits::{LMPRewardsVesting, LiquidityMiningCrowdSourcePallet};
 	use sp_std::collections::btree_map::BTreeMap;
 	// Import various types used to declare pallet in scope.
 	use super::*;
@@ -243,6 +262,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type LMPRewardsPalletId: Get<PalletId>;
 
+		/// Number of blocks after rewards become claimable during which sponsored LMP reward
+		/// streams can be claimed, after that unclaimed rewards are refunded to sponsors
+		#[pallet::constant]
+		type LMPRewardStreamsClaimPeriod: Get<u32>;
+
 		/// Orderbook withdrawal Limit
 		#[pallet::constant]
 		type OBWithdrawalLimit: Get<u32>;
@@ -273,18 +297,32 @@ pub mod pallet {
 		/// Governance Origin
 		type GovernanceOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+		/// Vesting used for the locked part of claimed LMP rewards
+		type LMPRewardsVesting: LMPRewardsVesting<<Self as frame_system::Config>::AccountId>;
+
 		/// Liquidity Crowd Sourcing pallet
 		type CrowdSourceLiqudityMining: LiquidityMiningCrowdSourcePallet<
 			<Self as frame_system::Config>::AccountId,
 		>;
 
+		/// Asset conversion used to buy back PDEX with the collected trading fees
+		type Swap: pallet_asset_conversion::Swap<
+			<Self as frame_system::Config>::AccountId,
+			u128,
+			AssetId,
+		>;
+
 		/// Type representing the weight of this pallet
 		type WeightInfo: OcexWeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	// Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
 	// method.
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -396,6 +434,28 @@ pub mod pallet {
 		WithdrawalFeeBurnFailed,
 		/// Trading fees burn failed
 		TradingFeesBurnFailed,
+		/// Price not found in price oracle
+		PriceNotFound,
+		/// Fee recipient shares do not add up to 100 percent
+		InvalidFeeDistribution,
+		/// Claim period of sponsored reward streams is not over
+		RewardStreamsClaimPeriodNotOver,
+		/// Sponsored reward streams are already refunded
+		RewardStreamsAlreadyRefunded,
+		/// LMP configs can only be scheduled for a limited number of upcoming epochs
+		InvalidLMPScheduleEpoch,
+		/// No LMP config is scheduled for this epoch
+		ScheduledLMPConfigNotFound,
+		/// Withdrawal stop offset must be shorter than the epoch
+		InvalidLMPEpochDuration,
+		/// Immediate fraction must be within 0 and 1 and linear period not zero
+		InvalidLMPVestingPolicy,
+		/// Reward stream is not funded or is already used by an LMP config
+		LMPRewardStreamNotFunded,
+		/// Reward stream amount is zero or the stream belongs to another market
+		InvalidLMPRewardStream,
+		/// Only the sponsor can withdraw the reward stream
+		NotLMPRewardStreamSponsor,
 	}
 
 	#[pallet::hooks]
@@ -412,9 +472,22 @@ pub mod pallet {
 			let len = <OnChainEvents<T>>::get().len();
 			if let Some(auction_block) = <AuctionBlockNumber<T>>::get() {
 				if n == auction_block {
-					if let Err(err) = Self::close_auction() {
-						log::error!(target:"ocex","Error consuming auction: {:?}",err);
-						Self::deposit_event(Event::<T>::FailedToConsumeAuction);
+					let buy_back = matches!(
+						<FeeDistributionConfig<T>>::get().map(|config| config.mode),
+						Some(FeeDistributionMode::BuyBack { .. })
+					);
+					// An auction can still be live if the mode was switched during the cycle
+					if !buy_back || <Auction<T>>::exists() {
+						if let Err(err) = Self::close_auction() {
+							log::error!(target:"ocex","Error consuming auction: {:?}",err);
+							Self::deposit_event(Event::<T>::FailedToConsumeAuction);
+						}
+					}
+					if buy_back {
+						if let Err(err) = Self::buy_back_fees() {
+							log::error!(target:"ocex","Error buying back fees: {:?}",err);
+							Self::deposit_event(Event::<T>::FailedToBuyBackFees);
+						}
 					}
 					if let Err(err) = Self::create_auction() {
 						log::error!(target:"ocex","Error creating auction: {:?}",err);
@@ -978,26 +1051,17 @@ pub mod pallet {
 			}
 			let mut total_percent: u128 = 0u128;
 			for market_config in lmp_config {
-				ensure!(
-					<TradingPairs<T>>::get(
-						market_config.trading_pair.base,
-						market_config.trading_pair.quote
-					)
-					.is_some(),
-					Error::<T>::TradingPairNotRegistered
-				);
 				total_percent = total_percent.saturating_add(market_config.market_weightage);
-
-				config.config.insert(
-					market_config.trading_pair,
-					LMPMarketConfig {
-						weightage: Decimal::from(market_config.market_weightage).div(unit),
-						min_fees_paid: Decimal::from(market_config.min_fees_paid).div(unit),
-						min_maker_volume: Decimal::from(market_config.min_maker_volume).div(unit),
-						max_spread: Decimal::from(market_config.max_spread).div(unit),
-						min_depth: Decimal::from(market_config.min_depth).div(unit),
-					},
-				);
+				let trading_pair = market_config.trading_pair;
+				let (market_config, reward_streams) = Self::take_lmp_market_config(market_config)?;
+				config.config.insert(trading_pair, market_config);
+				// Streams being replaced become available to other configs again
+				let old_streams = if reward_streams.is_empty() {
+					config.reward_streams.remove(&trading_pair)
+				} else {
+					config.reward_streams.insert(trading_pair, reward_streams)
+				};
+				Self::release_lmp_reward_streams(old_streams.unwrap_or_default());
 			}
 			ensure!(total_percent == UNIT_BALANCE, Error::<T>::InvalidMarketWeightage);
 			if let Some(max_accounts_rewarded) = max_accounts_rewarded {
@@ -1019,6 +1083,7 @@ pub mod pallet {
 			fee_distribution: FeeDistribution<T::AccountId, BlockNumberFor<T>>,
 		) -> DispatchResult {
 			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(fee_distribution.verify(), Error::<T>::InvalidFeeDistribution);
 			<FeeDistributionConfig<T>>::put(fee_distribution);
 			Ok(())
 		}
@@ -1055,6 +1120,240 @@ pub mod pallet {
 			Self::start_new_epoch(current_blk);
 			Ok(())
 		}
+
+		/// Updates the weighted split of the distributed trading fees
+		#[pallet::call_index(24)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_fee_recipients())]
+		pub fn set_fee_recipients(
+			origin: OriginFor<T>,
+			recipients: FeeRecipients<T::AccountId>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				FeeDistribution::<T::AccountId, BlockNumberFor<T>>::verify_recipients(&recipients),
+				Error::<T>::InvalidFeeDistribution
+			);
+			<FeeDistributionConfig<T>>::try_mutate(|config| {
+				let config = config.as_mut().ok_or(Error::<T>::FeeDistributionConfigNotFound)?;
+				config.recipients = recipients.clone();
+				Ok::<(), Error<T>>(())
+			})?;
+			Self::deposit_event(Event::<T>::FeeRecipientsUpdated(recipients.into_inner()));
+			Ok(())
+		}
+
+		/// Schedules changes to the LMP configuration of a future epoch
+		#[pallet::call_index(26)]
+		#[pallet::weight(< T as Config >::WeightInfo::schedule_lmp_epoch_config())]
+		pub fn schedule_lmp_epoch_config(
+			origin: OriginFor<T>,
+			epoch: u16,
+			total_liquidity_mining_rewards: Option<Compact<u128>>,
+			total_trading_rewards: Option<Compact<u128>>,
+			add_markets: Vec<LMPMarketConfigWrapper>,
+			remove_markets: Vec<TradingPair>,
+			max_accounts_rewarded: Option<u16>,
+			claim_safety_period: Option<u32>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let current_epoch = <LMPEpoch<T>>::get();
+			ensure!(
+				epoch > current_epoch
+					&& epoch <= current_epoch.saturating_add(MAX_SCHEDULED_LMP_EPOCHS),
+				Error::<T>::InvalidLMPScheduleEpoch
+			);
+			// Rescheduling an epoch replaces its previous diff
+			if let Some(old_diff) = <ScheduledLMPConfigs<T>>::take(epoch) {
+				Self::release_lmp_reward_streams(old_diff.reward_streams.into_values().flatten());
+			}
+			let unit: Decimal = Decimal::from(UNIT_BALANCE);
+			let mut diff = LMPEpochConfigDiff {
+				total_liquidity_mining_rewards: total_liquidity_mining_rewards
+					.map(|rewards| Decimal::from(rewards.0).div(unit)),
+				total_trading_rewards: total_trading_rewards
+					.map(|rewards| Decimal::from(rewards.0).div(unit)),
+				remove_markets,
+				max_accounts_rewarded,
+				claim_safety_period,
+				..Default::default()
+			};
+			for market_config in add_markets {
+				let trading_pair = market_config.trading_pair;
+				let (market_config, reward_streams) = Self::take_lmp_market_config(market_config)?;
+				diff.add_markets.insert(trading_pair, market_config);
+				if let Some(old_streams) = diff.reward_streams.insert(trading_pair, reward_streams)
+				{
+					Self::release_lmp_reward_streams(old_streams);
+				}
+			}
+			// The config must be valid on top of everything scheduled before it
+			let mut config = <ExpectedLMPConfig<T>>::get().unwrap_or_default();
+			for scheduled_epoch in current_epoch.saturating_add(1)..epoch {
+				if let Some(scheduled_diff) = <ScheduledLMPConfigs<T>>::get(scheduled_epoch) {
+					config.apply(scheduled_diff);
+				}
+			}
+			config.apply(diff.clone());
+			ensure!(config.verify(), Error::<T>::InvalidLMPConfig);
+			<ScheduledLMPConfigs<T>>::insert(epoch, diff);
+			Self::deposit_event(Event::<T>::LMPConfigScheduled(epoch));
+			Ok(())
+		}
+
+		/// Cancels the LMP configuration changes scheduled for an epoch
+		#[pallet::call_index(27)]
+		#[pallet::weight(< T as Config >::WeightInfo::cancel_scheduled_lmp_config())]
+		pub fn cancel_scheduled_lmp_config(origin: OriginFor<T>, epoch: u16) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let diff = <ScheduledLMPConfigs<T>>::take(epoch)
+				.ok_or(Error::<T>::ScheduledLMPConfigNotFound)?;
+			Self::release_lmp_reward_streams(diff.reward_streams.into_values().flatten());
+			Self::deposit_event(Event::<T>::LMPScheduledConfigCancelled(epoch));
+			Ok(())
+		}
+
+		/// Sets the LMP epoch length and withdrawal stop offset, applied from the next epoch
+		#[pallet::call_index(28)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_lmp_epoch_duration())]
+		pub fn set_lmp_epoch_duration(
+			origin: OriginFor<T>,
+			duration: LMPEpochDuration,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(duration.verify(), Error::<T>::InvalidLMPEpochDuration);
+			<PendingLMPEpochDuration<T>>::put(duration);
+			Self::deposit_event(Event::<T>::LMPEpochDurationScheduled(duration));
+			Ok(())
+		}
+
+		/// Sets the vesting policy of the claimed rewards, applied from the next epoch
+		#[pallet::call_index(29)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_lmp_vesting_policy())]
+		pub fn set_lmp_vesting_policy(
+			origin: OriginFor<T>,
+			vesting: Option<LMPVestingPolicy>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				vesting.map_or(true, |vesting| vesting.verify()),
+				Error::<T>::InvalidLMPVestingPolicy
+			);
+			<ExpectedLMPConfig<T>>::try_mutate(|config| {
+				let config = config.as_mut().ok_or(Error::<T>::LMPConfigNotFound)?;
+				config.vesting = vesting;
+				Ok::<(), Error<T>>(())
+			})?;
+			Self::deposit_event(Event::<T>::LMPVestingPolicyUpdated(vesting));
+			Ok(())
+		}
+
+		/// Claim LMP rewards of several epochs and markets at once
+		#[pallet::call_index(30)]
+		#[pallet::weight(< T as Config >::WeightInfo::claim_lmp_rewards_multi(claims.len() as u32))]
+		pub fn claim_lmp_rewards_multi(
+			origin: OriginFor<T>,
+			claims: BoundedVec<(u16, TradingPair), ConstU32<MAX_LMP_CLAIMS>>,
+		) -> DispatchResult {
+			let main = ensure_signed(origin)?;
+			for (epoch, market) in claims {
+				Self::do_claim_lmp_rewards(main.clone(), epoch, market)?;
+			}
+			Ok(())
+		}
+
+		/// Refunds the unclaimed sponsored LMP rewards of a market once the claim period is over
+		#[pallet::call_index(25)]
+		#[pallet::weight(< T as Config >::WeightInfo::refund_lmp_reward_streams())]
+		pub fn refund_lmp_reward_streams(
+			origin: OriginFor<T>,
+			epoch: u16,
+			market: TradingPair,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			let claim_blk = <LMPClaimBlk<T>>::get(epoch).ok_or(Error::<T>::RewardsNotReady)?;
+			let current_blk = frame_system::Pallet::<T>::current_block_number();
+			ensure!(
+				current_blk
+					>= claim_blk
+						.saturating_add(T::LMPRewardStreamsClaimPeriod::get().saturated_into()),
+				Error::<T>::RewardStreamsClaimPeriodNotOver
+			);
+			ensure!(
+				!<LMPRewardStreamsRefunded<T>>::get(epoch, market),
+				Error::<T>::RewardStreamsAlreadyRefunded
+			);
+			let config: LMPEpochConfig<T::AccountId> =
+				<LMPConfig<T>>::get(epoch).ok_or(Error::<T>::LMPConfigNotFound)?;
+			ensure!(config.config.contains_key(&market), Error::<T>::TradingPairNotRegistered);
+			let claimed = <LMPRewardStreamsClaimed<T>>::take(epoch, market);
+			let rewards_account: T::AccountId =
+				T::LMPRewardsPalletId::get().into_account_truncating();
+			let reward_streams = config.reward_streams.get(&market).cloned().unwrap_or_default();
+			for (index, stream) in reward_streams.iter().enumerate() {
+				let unclaimed =
+					stream.amount.saturating_sub(claimed.get(index).copied().unwrap_or_default());
+				Self::refund_lmp_reward_stream(&rewards_account, stream, unclaimed)?;
+			}
+			<LMPRewardStreamsRefunded<T>>::insert(epoch, market, true);
+			Ok(())
+		}
+
+		/// Escrows sponsored rewards for the market makers of a market in the LMP rewards
+		/// account, the funded stream can then be used by an LMP config
+		#[pallet::call_index(31)]
+		#[pallet::weight(< T as Config >::WeightInfo::fund_lmp_reward_stream())]
+		pub fn fund_lmp_reward_stream(
+			origin: OriginFor<T>,
+			market: TradingPair,
+			asset: AssetId,
+			amount: Compact<u128>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(
+				<TradingPairs<T>>::get(market.base, market.quote).is_some(),
+				Error::<T>::TradingPairNotRegistered
+			);
+			ensure!(amount.0 > 0, Error::<T>::InvalidLMPRewardStream);
+			let rewards_account: T::AccountId =
+				T::LMPRewardsPalletId::get().into_account_truncating();
+			Self::transfer_asset(&sponsor, &rewards_account, amount.0.saturated_into(), asset)?;
+			let id = <NextLMPRewardStreamId<T>>::mutate(|id| {
+				let current = *id;
+				*id = id.saturating_add(1);
+				current
+			});
+			let stream = LMPRewardStream {
+				id,
+				market,
+				asset,
+				amount: Decimal::from(amount.0).div(Decimal::from(UNIT_BALANCE)),
+				sponsor: sponsor.clone(),
+			};
+			<FundedLMPRewardStreams<T>>::insert(id, stream);
+			Self::deposit_event(Event::<T>::LMPRewardStreamFunded {
+				id,
+				market,
+				asset,
+				sponsor,
+				amount: amount.0,
+			});
+			Ok(())
+		}
+
+		/// Returns a funded reward stream that is not used by any LMP config to its sponsor
+		#[pallet::call_index(32)]
+		#[pallet::weight(< T as Config >::WeightInfo::withdraw_lmp_reward_stream())]
+		pub fn withdraw_lmp_reward_stream(origin: OriginFor<T>, id: u32) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			let stream =
+				<FundedLMPRewardStreams<T>>::get(id).ok_or(Error::<T>::LMPRewardStreamNotFunded)?;
+			ensure!(stream.sponsor == sponsor, Error::<T>::NotLMPRewardStreamSponsor);
+			<FundedLMPRewardStreams<T>>::remove(id);
+			let rewards_account: T::AccountId =
+				T::LMPRewardsPalletId::get().into_account_truncating();
+			Self::refund_lmp_reward_stream(&rewards_account, &stream, stream.amount)?;
+			Ok(())
+		}
 	}
 
 	/// Events are a simple means of reporting specific conditions and
@@ -1147,6 +1446,75 @@ pub mod pallet {
 			main: T::AccountId,
 			reward: u128,
 		},
+		/// Part of the claimed LMP reward is vested
+		LMPRewardVested {
+			epoch: u16,
+			market: TradingPair,
+			main: T::AccountId,
+			amount: u128,
+			start: u32,
+		},
+		/// Vesting policy of the next LMP epoch updated
+		LMPVestingPolicyUpdated(Option<LMPVestingPolicy>),
+		/// Sponsored LMP reward stream escrowed
+		LMPRewardStreamFunded {
+			id: u32,
+			market: TradingPair,
+			asset: AssetId,
+			sponsor: T::AccountId,
+			amount: u128,
+		},
+		/// Sponsored LMP reward stream claimed
+		LMPRewardStreamClaimed {
+			epoch: u16,
+			market: TradingPair,
+			main: T::AccountId,
+			asset: AssetId,
+			reward: u128,
+		},
+		/// Unclaimed sponsored LMP rewards refunded to the sponsor
+		LMPRewardStreamRefunded {
+			id: u32,
+			market: TradingPair,
+			asset: AssetId,
+			sponsor: T::AccountId,
+			amount: u128,
+		},
+		/// LMP config changes scheduled for an epoch
+		LMPConfigScheduled(u16),
+		/// Scheduled LMP config changes cancelled
+		LMPScheduledConfigCancelled(u16),
+		/// Scheduled LMP config changes applied for the upcoming epoch
+		LMPScheduledConfigApplied(u16),
+		/// Scheduled LMP config changes are no longer valid and were discarded
+		LMPScheduledConfigDiscarded(u16),
+		/// New LMP epoch duration takes effect from the next epoch
+		LMPEpochDurationScheduled(LMPEpochDuration),
+		/// Failed to buy back PDEX with the collected fees
+		FailedToBuyBackFees,
+		/// Collected fees of an asset swapped to PDEX
+		FeesBoughtBack {
+			asset: AssetId,
+			sold: Compact<BalanceOf<T>>,
+			bought: Compact<BalanceOf<T>>,
+		},
+		/// Swap of collected fees failed, fees are rolled over to the next cycle
+		FeesBuyBackFailed {
+			asset: AssetId,
+			amount: Compact<BalanceOf<T>>,
+		},
+		/// PDEX bought back in this cycle is distributed
+		FeesBuyBackCompleted {
+			burned: Compact<BalanceOf<T>>,
+			paid_to_operator: Compact<BalanceOf<T>>,
+		},
+		/// Share of the trading fees paid to a fee recipient
+		FeesDistributed {
+			recipient: T::AccountId,
+			amount: Compact<BalanceOf<T>>,
+		},
+		/// Fee recipients updated
+		FeeRecipientsUpdated(Vec<FeeRecipient<T::AccountId>>),
 	}
 
 	///Allowlisted tokens
@@ -1283,12 +1651,36 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn lmp_config)]
 	pub(super) type LMPConfig<T: Config> =
-		StorageMap<_, Identity, u16, LMPEpochConfig, OptionQuery>;
+		StorageMap<_, Identity, u16, LMPEpochConfig<T::AccountId>, OptionQuery>;
 
 	/// Expected Configuration for LMP for next epoch
 	#[pallet::storage]
 	#[pallet::getter(fn expected_lmp_config)]
-	pub(super) type ExpectedLMPConfig<T: Config> = StorageValue<_, LMPEpochConfig, OptionQuery>;
+	pub(super) type ExpectedLMPConfig<T: Config> =
+		StorageValue<_, LMPEpochConfig<T::AccountId>, OptionQuery>;
+
+	/// LMP config changes scheduled for future epochs
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_lmp_configs)]
+	pub(super) type ScheduledLMPConfigs<T: Config> =
+		StorageMap<_, Identity, u16, LMPEpochConfigDiff<T::AccountId>, OptionQuery>;
+
+	/// Duration of the current LMP epoch
+	#[pallet::storage]
+	#[pallet::getter(fn lmp_epoch_duration)]
+	pub(super) type LMPEpochDurationConfig<T: Config> =
+		StorageValue<_, LMPEpochDuration, ValueQuery>;
+
+	/// Duration to use from the next LMP epoch
+	#[pallet::storage]
+	#[pallet::getter(fn pending_lmp_epoch_duration)]
+	pub(super) type PendingLMPEpochDuration<T: Config> =
+		StorageValue<_, LMPEpochDuration, OptionQuery>;
+
+	/// Block at which the next LMP epoch starts
+	#[pallet::storage]
+	#[pallet::getter(fn next_lmp_epoch_block)]
+	pub(super) type NextLMPEpochBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
 
 	/// Block at which rewards for each epoch can be claimed
 	#[pallet::storage]
@@ -1296,6 +1688,28 @@ pub mod pallet {
 	pub(super) type LMPClaimBlk<T: Config> =
 		StorageMap<_, Identity, u16, BlockNumberFor<T>, OptionQuery>;
 
+	/// Sponsored rewards claimed so far from each reward stream of a market
+	#[pallet::storage]
+	#[pallet::getter(fn lmp_reward_streams_claimed)]
+	pub(super) type LMPRewardStreamsClaimed<T: Config> =
+		StorageDoubleMap<_, Identity, u16, Identity, TradingPair, Vec<Decimal>, ValueQuery>;
+
+	/// Id of the next funded reward stream
+	#[pallet::storage]
+	pub(super) type NextLMPRewardStreamId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Funded reward streams not used by any LMP config yet
+	#[pallet::storage]
+	#[pallet::getter(fn funded_lmp_reward_streams)]
+	pub(super) type FundedLMPRewardStreams<T: Config> =
+		StorageMap<_, Identity, u32, LMPRewardStream<T::AccountId>, OptionQuery>;
+
+	/// Flag set once the unclaimed sponsored rewards of a market are refunded
+	#[pallet::storage]
+	#[pallet::getter(fn lmp_reward_streams_refunded)]
+	pub(super) type LMPRewardStreamsRefunded<T: Config> =
+		StorageDoubleMap<_, Identity, u16, Identity, TradingPair, bool, ValueQuery>;
+
 	/// Price Map showing the average prices ( value = (avg_price, ticks)
 	#[pallet::storage]
 	pub type PriceOracle<T: Config> =
@@ -1349,27 +1763,49 @@ pub mod pallet {
 				<crate::pallet::LMPClaimBlk<T>>::get(epoch).ok_or(Error::<T>::RewardsNotReady)?;
 			let current_blk = frame_system::Pallet::<T>::current_block_number();
 			ensure!(current_blk >= claim_blk.saturated_into(), Error::<T>::RewardsNotReady);
-			let config: LMPEpochConfig =
+			let config: LMPEpochConfig<T::AccountId> =
 				<LMPConfig<T>>::get(epoch).ok_or(Error::<T>::LMPConfigNotFound)?;
 			// Calculate the total eligible rewards
 			let (mm_rewards, trading_rewards, is_claimed) =
-				Self::calculate_lmp_rewards(&main, epoch, market, config);
+				Self::calculate_lmp_rewards(&main, epoch, market, config.clone());
 			ensure!(!is_claimed, Error::<T>::RewardAlreadyClaimed);
 			let total = mm_rewards.saturating_add(trading_rewards);
-			let total_in_u128 = total
+			let total_in_u128: u128 = total
 				.saturating_mul(Decimal::from(UNIT_BALANCE))
 				.to_u128()
-				.ok_or(Error::<T>::FailedToConvertDecimaltoBalance)?
-				.saturated_into();
+				.ok_or(Error::<T>::FailedToConvertDecimaltoBalance)?;
+			// Only the immediate portion is paid out, the rest is vested linearly
+			let (immediate, per_block) = match config.vesting {
+				Some(vesting) => Self::split_vested_lmp_rewards(&vesting, total_in_u128)?,
+				None => (total_in_u128, 0),
+			};
 			// Transfer it to main from pallet account.
 			let rewards_account: T::AccountId =
 				T::LMPRewardsPalletId::get().into_account_truncating();
 			T::NativeCurrency::transfer(
 				&rewards_account,
 				&main,
-				total_in_u128,
+				immediate.saturated_into(),
 				ExistenceRequirement::AllowDeath,
 			)?;
+			if let Some(vesting) = config.vesting.filter(|_| per_block > 0) {
+				let start = current_blk.saturated_into::<u32>().saturating_add(vesting.cliff);
+				T::LMPRewardsVesting::vested_transfer(
+					&rewards_account,
+					&main,
+					start,
+					1,
+					vesting.linear_period,
+					per_block,
+				)?;
+				Self::deposit_event(Event::<T>::LMPRewardVested {
+					epoch,
+					market,
+					main: main.clone(),
+					amount: per_block.saturating_mul(vesting.linear_period.into()),
+					start,
+				});
+			}
 
 			// Update the is_claimed flag
 			<TraderMetrics<T>>::mutate((epoch, market, main.clone()), |(_, _, is_claimed)| {
@@ -1377,11 +1813,140 @@ pub mod pallet {
 			});
 			Self::deposit_event(Event::<T>::LMPRewardClaimed {
 				epoch,
-				main,
+				main: main.clone(),
 				market,
-				reward: total_in_u128.saturated_into(),
+				reward: total_in_u128,
+			});
+			// Sponsored streams are paid until they are refunded to the sponsors
+			if !<LMPRewardStreamsRefunded<T>>::get(epoch, market) {
+				Self::claim_lmp_reward_streams(&rewards_account, &main, epoch, market, &config)?;
+			}
+			Ok(total_in_u128.saturated_into())
+		}
+
+		/// Splits the rewards into the immediate payout and the amount unlocked per block
+		fn split_vested_lmp_rewards(
+			vesting: &LMPVestingPolicy,
+			rewards: u128,
+		) -> Result<(u128, u128), DispatchError> {
+			let (_, vested) = vesting.split(Decimal::from(rewards));
+			let vested = vested.to_u128().ok_or(Error::<T>::FailedToConvertDecimaltoBalance)?;
+			// Dust that does not fit into whole per block unlocks is paid immediately
+			let per_block = vested.saturating_div(vesting.linear_period.into());
+			let immediate =
+				rewards.saturating_sub(per_block.saturating_mul(vesting.linear_period.into()));
+			Ok((immediate, per_block))
+		}
+
+		/// Pays the share of every sponsored reward stream of the market to `main`
+		fn claim_lmp_reward_streams(
+			rewards_account: &T::AccountId,
+			main: &T::AccountId,
+			epoch: u16,
+			market: TradingPair,
+			config: &LMPEpochConfig<T::AccountId>,
+		) -> DispatchResult {
+			let Some(reward_streams) = config.reward_streams.get(&market) else { return Ok(()) };
+			if reward_streams.is_empty() {
+				return Ok(());
+			}
+			let (total_score, _) = <TotalScores<T>>::get(epoch, market);
+			let (score, _, _) = <TraderMetrics<T>>::get((epoch, market, main.clone()));
+			let market_making_portion = score.checked_div(total_score).unwrap_or_default();
+			let mut claimed = <LMPRewardStreamsClaimed<T>>::get(epoch, market);
+			claimed.resize(reward_streams.len(), Decimal::zero());
+			for (index, stream) in reward_streams.iter().enumerate() {
+				let reward = stream.amount.saturating_mul(market_making_portion);
+				let reward_in_u128 = reward
+					.saturating_mul(Decimal::from(UNIT_BALANCE))
+					.to_u128()
+					.ok_or(Error::<T>::FailedToConvertDecimaltoBalance)?;
+				if reward_in_u128 == 0 {
+					continue;
+				}
+				Self::transfer_asset(
+					rewards_account,
+					main,
+					reward_in_u128.saturated_into(),
+					stream.asset,
+				)?;
+				claimed[index] = claimed[index].saturating_add(reward);
+				Self::deposit_event(Event::<T>::LMPRewardStreamClaimed {
+					epoch,
+					market,
+					main: main.clone(),
+					asset: stream.asset,
+					reward: reward_in_u128,
+				});
+			}
+			<LMPRewardStreamsClaimed<T>>::insert(epoch, market, claimed);
+			Ok(())
+		}
+
+		/// Converts the market config and takes its reward streams out of the funded streams
+		fn take_lmp_market_config(
+			market_config: LMPMarketConfigWrapper,
+		) -> Result<(LMPMarketConfig, Vec<LMPRewardStream<T::AccountId>>), DispatchError> {
+			let market = market_config.trading_pair;
+			ensure!(
+				<TradingPairs<T>>::get(market.base, market.quote).is_some(),
+				Error::<T>::TradingPairNotRegistered
+			);
+			let mut reward_streams = Vec::new();
+			for id in market_config.reward_streams {
+				let stream = <FundedLMPRewardStreams<T>>::take(id)
+					.ok_or(Error::<T>::LMPRewardStreamNotFunded)?;
+				ensure!(stream.market == market, Error::<T>::InvalidLMPRewardStream);
+				reward_streams.push(stream);
+			}
+			let unit: Decimal = Decimal::from(UNIT_BALANCE);
+			let config = LMPMarketConfig {
+				weightage: Decimal::from(market_config.market_weightage).div(unit),
+				min_fees_paid: Decimal::from(market_config.min_fees_paid).div(unit),
+				min_maker_volume: Decimal::from(market_config.min_maker_volume).div(unit),
+				max_spread: Decimal::from(market_config.max_spread).div(unit),
+				min_depth: Decimal::from(market_config.min_depth).div(unit),
+			};
+			Ok((config, reward_streams))
+		}
+
+		/// Makes reward streams no longer used by an LMP config available again, their
+		/// sponsors can withdraw them or governance can reuse them
+		pub(crate) fn release_lmp_reward_streams(
+			reward_streams: impl IntoIterator<Item = LMPRewardStream<T::AccountId>>,
+		) {
+			for stream in reward_streams {
+				<FundedLMPRewardStreams<T>>::insert(stream.id, stream);
+			}
+		}
+
+		/// Returns `amount` of an escrowed reward stream to its sponsor
+		fn refund_lmp_reward_stream(
+			rewards_account: &T::AccountId,
+			stream: &LMPRewardStream<T::AccountId>,
+			amount: Decimal,
+		) -> DispatchResult {
+			let amount_in_u128 = amount
+				.saturating_mul(Decimal::from(UNIT_BALANCE))
+				.to_u128()
+				.ok_or(Error::<T>::FailedToConvertDecimaltoBalance)?;
+			if amount_in_u128 == 0 {
+				return Ok(());
+			}
+			Self::transfer_asset(
+				rewards_account,
+				&stream.sponsor,
+				amount_in_u128.saturated_into(),
+				stream.asset,
+			)?;
+			Self::deposit_event(Event::<T>::LMPRewardStreamRefunded {
+				id: stream.id,
+				market: stream.market,
+				asset: stream.asset,
+				sponsor: stream.sponsor.clone(),
+				amount: amount_in_u128,
 			});
-			Ok(total_in_u128)
+			Ok(())
 		}
 
 		pub fn settle_withdrawal_fees(fees: Vec<Fees>) -> DispatchResult {
@@ -1543,11 +2108,10 @@ pub mod pallet {
 											ExistenceRequirement::KeepAlive,
 										)
 										.map_err(|_| Error::<T>::TradingFeesBurnFailed)?;
-										Self::transfer_asset(
+										Self::distribute_fees(
 											&Self::get_pallet_account(),
-											&distribution.recipient_address,
+											&distribution.recipients,
 											fee_to_be_distributed.saturated_into(),
-											*asset,
 										)?;
 									} else {
 										// Burn here itself
@@ -1591,6 +2155,14 @@ pub mod pallet {
 						lp,
 						base_free,
 						quote_free,
+					)
+					| EgressMessages::RemoveLiquidityInstantlyResult(
+						market,
+						pool,
+						lp,
+						base_free,
+						quote_free,
+						_,
 					) => {
 						let unit = Decimal::from(UNIT_BALANCE);
 						// Transfer the assets from exchange to pool_id
@@ -1615,35 +2187,72 @@ pub mod pallet {
 							market.quote_asset,
 						)?;
 						// TODO: Emit events for indexer and frontend @Emmanuel.
-						T::CrowdSourceLiqudityMining::remove_liquidity_success(
+						let market = TradingPair::from(market.quote_asset, market.base_asset);
+						if let EgressMessages::RemoveLiquidityInstantlyResult(.., request_id) = msg
+						{
+							T::CrowdSourceLiqudityMining::remove_liquidity_instantly_success(
+								market,
+								pool,
+								lp,
+								*base_free,
+								*quote_free,
+								*request_id,
+							)?;
+						} else {
+							T::CrowdSourceLiqudityMining::remove_liquidity_success(
+								market,
+								pool,
+								lp,
+								*base_free,
+								*quote_free,
+							)?;
+						}
+					},
+					EgressMessages::RemoveLiquidityFailed(
+						market,
+						pool,
+						lp,
+						frac,
+						total_shares,
+						base_free,
+						quote_free,
+						base_reserved,
+						quote_reserved,
+					) => {
+						T::CrowdSourceLiqudityMining::remove_liquidity_failed(
 							TradingPair::from(market.quote_asset, market.base_asset),
 							pool,
 							lp,
+							*frac,
+							*total_shares,
 							*base_free,
 							*quote_free,
+							*base_reserved,
+							*quote_reserved,
 						)?;
 					},
-					EgressMessages::RemoveLiquidityFailed(
+					EgressMessages::RemoveLiquidityInstantlyFailed(
 						market,
 						pool,
 						lp,
 						frac,
-						total_shares,
+						_total_shares,
 						base_free,
 						quote_free,
 						base_reserved,
 						quote_reserved,
+						request_id,
 					) => {
-						T::CrowdSourceLiqudityMining::remove_liquidity_failed(
+						T::CrowdSourceLiqudityMining::remove_liquidity_instantly_failed(
 							TradingPair::from(market.quote_asset, market.base_asset),
 							pool,
 							lp,
 							*frac,
-							*total_shares,
 							*base_free,
 							*quote_free,
 							*base_reserved,
 							*quote_reserved,
+							*request_id,
 						)?;
 					},
 					EgressMessages::PoolForceClosed(market, pool, base_freed, quote_freed) => {
@@ -1678,6 +2287,19 @@ pub mod pallet {
 							*quote_freed,
 						)?;
 					},
+					EgressMessages::PoolValued(
+						market,
+						pool,
+						epoch,
+						total_inventory,
+						total_shares,
+					) => T::CrowdSourceLiqudityMining::pool_valued(
+						TradingPair::from(market.quote_asset, market.base_asset),
+						pool,
+						*epoch,
+						*total_inventory,
+						*total_shares,
+					)?,
 					EgressMessages::PriceOracle(price_map) => {
 						let mut old_price_map = <PriceOracle<T>>::get();
 						for (pair, price) in price_map {
@@ -2014,12 +2636,41 @@ pub mod pallet {
 			main: &T::AccountId,
 			epoch: u16,
 			market: TradingPair,
-			config: LMPEpochConfig,
+			config: LMPEpochConfig<T::AccountId>,
 		) -> (Decimal, Decimal, bool) {
 			// Get the score and fees paid portion of this 'main' account
 			let (total_score, total_fees_paid) = <TotalScores<T>>::get(epoch, market);
 			let (score, fees_paid, is_claimed) =
 				<TraderMetrics<T>>::get((epoch, market, main.clone()));
+			let (mm_rewards, trading_rewards) = Self::compute_lmp_rewards(
+				&config,
+				(score, fees_paid),
+				(total_score, total_fees_paid),
+			);
+			(mm_rewards, trading_rewards, is_claimed)
+		}
+
+		/// Returns the projected rewards of the running LMP epoch if it ended now - called by RPC
+		pub fn get_projected_lmp_rewards(
+			main: T::AccountId,
+			market: TradingPair,
+		) -> (Decimal, Decimal) {
+			let epoch = <LMPEpoch<T>>::get();
+			let config = match <LMPConfig<T>>::get(epoch) {
+				Some(config) => config,
+				None => return (Decimal::zero(), Decimal::zero()),
+			};
+			let (score, fees_paid, _) = Self::get_trader_metrics(epoch, market, main);
+			let totals = Self::get_total_score(epoch, market);
+			Self::compute_lmp_rewards(&config, (score, fees_paid), totals)
+		}
+
+		/// Splits the epoch rewards by the trader's share of scores and fees paid
+		fn compute_lmp_rewards(
+			config: &LMPEpochConfig<T::AccountId>,
+			(score, fees_paid): (Decimal, Decimal),
+			(total_score, total_fees_paid): (Decimal, Decimal),
+		) -> (Decimal, Decimal) {
 			let market_making_portion = score.checked_div(total_score).unwrap_or_default();
 			let trading_rewards_portion =
 				fees_paid.checked_div(total_fees_paid).unwrap_or_default();
@@ -2027,7 +2678,45 @@ pub mod pallet {
 				config.total_liquidity_mining_rewards.saturating_mul(market_making_portion);
 			let trading_rewards =
 				config.total_trading_rewards.saturating_mul(trading_rewards_portion);
-			(mm_rewards, trading_rewards, is_claimed)
+			(mm_rewards, trading_rewards)
+		}
+
+		/// Returns the Q score of every minute index the main account was scored in - called by
+		/// RPC. The number of entries is the uptime of the account.
+		pub fn get_lmp_score_timeline(
+			epoch: u16,
+			market: TradingPair,
+			main: T::AccountId,
+		) -> BTreeMap<u16, Decimal> {
+			let mut root = crate::storage::load_trie_root();
+			let mut storage = crate::storage::State;
+			let mut state = OffchainState::load(&mut storage, &mut root);
+			let main_concrete: AccountId = Decode::decode(&mut &main.encode()[..]).unwrap();
+			crate::lmp::get_q_score_and_uptime_for_checkpoint(
+				&mut state,
+				epoch,
+				&market,
+				&main_concrete,
+			)
+			.unwrap_or_default()
+		}
+
+		/// Returns the immediate and vested portions of the LMP rewards - called by RPC
+		pub fn get_lmp_claimable_rewards(
+			main: &T::AccountId,
+			epoch: u16,
+			market: TradingPair,
+		) -> (Decimal, Decimal, bool) {
+			let (mm_rewards, trading_rewards, is_claimed) =
+				Self::get_lmp_rewards(main, epoch, market);
+			let total = mm_rewards.saturating_add(trading_rewards);
+			match <LMPConfig<T>>::get(epoch).and_then(|config| config.vesting) {
+				Some(vesting) => {
+					let (immediate, vested) = vesting.split(total);
+					(immediate, vested, is_claimed)
+				},
+				None => (total, Decimal::zero(), is_claimed),
+			}
 		}
 
 		/// Returns Rewards for LMP - called by RPC
@@ -2148,6 +2837,16 @@ pub mod pallet {
 		}
 
 		pub fn create_auction() -> DispatchResult {
+			let fee_config = <FeeDistributionConfig<T>>::get()
+				.ok_or(Error::<T>::FeeDistributionConfigNotFound)?;
+			let next_auction_block = frame_system::Pallet::<T>::current_block_number()
+				.saturating_add(fee_config.auction_duration);
+			<AuctionBlockNumber<T>>::put(next_auction_block);
+			if let FeeDistributionMode::BuyBack { .. } = fee_config.mode {
+				// Fees are swapped at the end of the cycle, no bids are accepted.
+				<Auction<T>>::kill();
+				return Ok(());
+			}
 			let mut auction_info: AuctionInfo<T::AccountId, BalanceOf<T>> = AuctionInfo::default();
 			let tokens = <AllowlistedToken<T>>::get();
 			for asset in tokens {
@@ -2165,11 +2864,6 @@ pub mod pallet {
 					auction_info.fee_info.insert(asset_id, asset_reducible_balance);
 				}
 			}
-			let fee_config = <FeeDistributionConfig<T>>::get()
-				.ok_or(Error::<T>::FeeDistributionConfigNotFound)?;
-			let next_auction_block = frame_system::Pallet::<T>::current_block_number()
-				.saturating_add(fee_config.auction_duration);
-			<AuctionBlockNumber<T>>::put(next_auction_block);
 			<Auction<T>>::put(auction_info);
 			Ok(())
 		}
@@ -2195,12 +2889,7 @@ pub mod pallet {
 					Percent::from_percent(fee_config.burn_ration) * total_bidder_reserve_balance;
 				let transferable_amount =
 					total_bidder_reserve_balance.saturating_sub(amount_to_be_burnt);
-				T::NativeCurrency::transfer(
-					&bidder,
-					&fee_config.recipient_address,
-					transferable_amount,
-					ExistenceRequirement::KeepAlive,
-				)?;
+				Self::distribute_fees(&bidder, &fee_config.recipients, transferable_amount)?;
 
 				// Burn the fee
 				let imbalance = T::NativeCurrency::burn(amount_to_be_burnt.saturated_into());
@@ -2220,6 +2909,142 @@ pub mod pallet {
 			}
 			Ok(())
 		}
+
+		/// Swaps the non-native fees in the pot account to PDEX and distributes the PDEX bought.
+		/// Swaps that fail leave the fees in the pot account for the next cycle.
+		pub fn buy_back_fees() -> DispatchResult {
+			let fee_config = <FeeDistributionConfig<T>>::get()
+				.ok_or(Error::<T>::FeeDistributionConfigNotFound)?;
+			let max_slippage = match fee_config.mode {
+				FeeDistributionMode::BuyBack { max_slippage } => max_slippage,
+				FeeDistributionMode::Auction => return Ok(()),
+			};
+			let pot_account = Self::get_pot_account();
+			let mut total_bought: BalanceOf<T> = Zero::zero();
+			for asset in <AllowlistedToken<T>>::get() {
+				let asset_id = match asset {
+					AssetId::Polkadex => continue,
+					AssetId::Asset(id) => id,
+				};
+				let amount = T::OtherAssets::reducible_balance(
+					asset_id,
+					&pot_account,
+					Preservation::Preserve,
+					Fortitude::Polite,
+				);
+				if amount <= T::OtherAssets::minimum_balance(asset_id) {
+					continue;
+				}
+				let swap_result = Self::min_buy_back_amount(asset, amount, max_slippage).and_then(
+					|amount_out_min| {
+						T::Swap::swap_exact_tokens_for_tokens(
+							pot_account.clone(),
+							sp_std::vec![asset, AssetId::Polkadex],
+							amount.saturated_into(),
+							Some(amount_out_min),
+							pot_account.clone(),
+							true,
+						)
+					},
+				);
+				match swap_result {
+					Ok(bought) => {
+						let bought: BalanceOf<T> = bought.saturated_into();
+						total_bought = total_bought.saturating_add(bought);
+						Self::deposit_event(Event::<T>::FeesBoughtBack {
+							asset,
+							sold: Compact::from(amount),
+							bought: Compact::from(bought),
+						});
+					},
+					Err(err) => {
+						log::error!(target:"ocex","Error swapping fees of {:?}: {:?}",asset,err);
+						Self::deposit_event(Event::<T>::FeesBuyBackFailed {
+							asset,
+							amount: Compact::from(amount),
+						});
+					},
+				}
+			}
+			if total_bought.is_zero() {
+				return Ok(());
+			}
+			let amount_to_be_burnt = Percent::from_percent(fee_config.burn_ration) * total_bought;
+			let transferable_amount = total_bought.saturating_sub(amount_to_be_burnt);
+			Self::distribute_fees(&pot_account, &fee_config.recipients, transferable_amount)?;
+			// Burn the fee
+			let imbalance = T::NativeCurrency::burn(amount_to_be_burnt);
+			T::NativeCurrency::settle(
+				&pot_account,
+				imbalance,
+				WithdrawReasons::all(),
+				ExistenceRequirement::KeepAlive,
+			)
+			.map_err(|_| Error::<T>::TradingFeesBurnFailed)?;
+			Self::deposit_event(Event::<T>::FeesBuyBackCompleted {
+				burned: Compact::from(amount_to_be_burnt),
+				paid_to_operator: Compact::from(transferable_amount),
+			});
+			Ok(())
+		}
+
+		/// Splits `amount` of PDEX paid by `payer` between the fee recipients according to their
+		/// shares. The last recipient receives the rounding remainder.
+		pub fn distribute_fees(
+			payer: &T::AccountId,
+			recipients: &FeeRecipients<T::AccountId>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let mut remaining = amount;
+			let last_index = recipients.len().saturating_sub(1);
+			for (index, recipient) in recipients.iter().enumerate() {
+				let share = if index == last_index {
+					remaining
+				} else {
+					(Percent::from_percent(recipient.share) * amount).min(remaining)
+				};
+				remaining = remaining.saturating_sub(share);
+				if share.is_zero() {
+					continue;
+				}
+				T::NativeCurrency::transfer(
+					payer,
+					&recipient.account,
+					share,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				Self::deposit_event(Event::<T>::FeesDistributed {
+					recipient: recipient.account.clone(),
+					amount: Compact::from(share),
+				});
+			}
+			Ok(())
+		}
+
+		/// Returns the minimum PDEX expected for selling `amount` of `asset`, based on the
+		/// average price reported by the price oracle and the allowed slippage.
+		pub fn min_buy_back_amount(
+			asset: AssetId,
+			amount: BalanceOf<T>,
+			max_slippage: u8,
+		) -> Result<u128, DispatchError> {
+			let prices = <PriceOracle<T>>::get();
+			let amount = Decimal::from(amount.saturated_into::<u128>());
+			let expected = if let Some((price, _ticks)) = prices.get(&(asset, AssetId::Polkadex)) {
+				amount.saturating_mul(*price)
+			} else if let Some((price, _ticks)) = prices.get(&(AssetId::Polkadex, asset)) {
+				amount.checked_div(*price).ok_or(Error::<T>::PriceNotFound)?
+			} else {
+				return Err(Error::<T>::PriceNotFound.into());
+			};
+			let min_fraction =
+				Decimal::from(100u8.saturating_sub(max_slippage)).div(Decimal::from(100u8));
+			let min_amount = expected
+				.saturating_mul(min_fraction)
+				.to_u128()
+				.ok_or(Error::<T>::FailedToConvertDecimaltoBalance)?;
+			Ok(min_amount)
+		}
 	}
 }
 
@@ -453,6 +453,29 @@ impl<T: Config> Pallet<T> {
 
 		accounts
 	}
+
+	/// Returns a page of the finalized LMP leaderboard for the given epoch and market with
+	/// the score and fees paid of each account, in descending order of the chosen metric.
+	///
+	/// Only the top `max_accounts_rewarded` traders whose metrics were submitted on-chain at the
+	/// end of the epoch are listed, so the running epoch always returns an empty page.
+	pub fn lmp_rewarded_leaderboard(
+		epoch: u16,
+		trading_pair: TradingPair,
+		sorted_by_mm_score: bool,
+		offset: u32,
+		limit: u16,
+	) -> Vec<(T::AccountId, Decimal, Decimal)> {
+		let mut accounts = <TraderMetrics<T>>::iter_prefix((epoch, trading_pair))
+			.map(|(main, (mm_score, fees_paid, _))| (main, mm_score, fees_paid))
+			.collect::<Vec<(T::AccountId, Decimal, Decimal)>>();
+		if sorted_by_mm_score {
+			accounts.sort_by(|a, b| b.1.cmp(&a.1));
+		} else {
+			accounts.sort_by(|a, b| b.2.cmp(&a.2));
+		}
+		accounts.into_iter().skip(offset as usize).take(limit as usize).collect()
+	}
 }
 
 impl<T: Config> LiquidityMining<T::AccountId, BalanceOf<T>> for Pallet<T> {
@@ -525,6 +548,33 @@ impl<T: Config> LiquidityMining<T::AccountId, BalanceOf<T>> for Pallet<T> {
 		});
 	}
 
+	fn remove_liquidity_instantly(
+		market: TradingPair,
+		pool: T::AccountId,
+		lp: T::AccountId,
+		burned: BalanceOf<T>,
+		total: BalanceOf<T>,
+		request_id: u64,
+	) {
+		let burned = Decimal::from(burned.saturated_into::<u128>());
+		let total = Decimal::from(total.saturated_into::<u128>());
+		let burn_frac = burned.checked_div(total).unwrap_or_default();
+
+		let current_blk = frame_system::Pallet::<T>::current_block_number();
+		<IngressMessages<T>>::mutate(current_blk, |messages| {
+			messages.push(
+				orderbook_primitives::ingress::IngressMessages::RemoveLiquidityInstantly(
+					TradingPairConfig::default(market.base, market.quote),
+					pool,
+					lp,
+					burn_frac,
+					total,
+					request_id,
+				),
+			);
+		});
+	}
+
 	fn force_close_pool(market: TradingPair, pool: T::AccountId) {
 		let current_blk = frame_system::Pallet::<T>::current_block_number();
 		<IngressMessages<T>>::mutate(current_blk, |messages| {
@@ -535,6 +585,25 @@ impl<T: Config> LiquidityMining<T::AccountId, BalanceOf<T>> for Pallet<T> {
 		});
 	}
 
+	fn value_pool(
+		market: TradingPair,
+		pool: T::AccountId,
+		epoch: u16,
+		price: Decimal,
+		total_shares_issued: Decimal,
+	) {
+		let current_blk = frame_system::Pallet::<T>::current_block_number();
+		<IngressMessages<T>>::mutate(current_blk, |messages| {
+			messages.push(orderbook_primitives::ingress::IngressMessages::ValuePool(
+				TradingPairConfig::default(market.base, market.quote),
+				pool,
+				epoch,
+				price,
+				total_shares_issued,
+			));
+		});
+	}
+
 	fn claim_rewards(
 		main: T::AccountId,
 		epoch: u16,
@@ -542,4 +611,24 @@ impl<T: Config> LiquidityMining<T::AccountId, BalanceOf<T>> for Pallet<T> {
 	) -> Result<BalanceOf<T>, DispatchError> {
 		Self::do_claim_lmp_rewards(main, epoch, market)
 	}
+
+	fn get_pool_balances(pool: T::AccountId, market: TradingPair) -> (Decimal, Decimal) {
+		let base = Self::get_balance(pool.clone(), market.base).unwrap_or_default();
+		let quote = Self::get_balance(pool, market.quote).unwrap_or_default();
+		(base, quote)
+	}
+
+	fn get_pool_rewards(
+		pool: T::AccountId,
+		epoch: u16,
+		market: TradingPair,
+	) -> (BalanceOf<T>, bool) {
+		let (mm_rewards, trading_rewards, is_claimed) = Self::get_lmp_rewards(&pool, epoch, market);
+		let total = mm_rewards
+			.saturating_add(trading_rewards)
+			.saturating_mul(Decimal::from(UNIT_BALANCE))
+			.to_u128()
+			.unwrap_or_default();
+		(total.saturated_into(), is_claimed)
+	}
 }
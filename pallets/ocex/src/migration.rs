@@ -0,0 +1,145 @@
+// This file is part of Polkadex.
+//
+// Copyright (c) 2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the OCEX pallet.
+
+use crate::{
+	pallet::{ExpectedLMPConfig, FeeDistributionConfig, LMPConfig},
+	Config, Pallet,
+};
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+use orderbook_primitives::{
+	lmp::{LMPEpochConfig, LMPMarketConfig},
+	types::TradingPair,
+};
+use polkadex_primitives::auction::{FeeDistribution, FeeDistributionMode, FeeRecipient};
+use rust_decimal::Decimal;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+pub mod v1 {
+	use super::*;
+
+	/// `FeeDistribution` before fees could be split between recipients or bought back
+	#[derive(Decode)]
+	struct OldFeeDistribution<AccountId, BlockNo> {
+		recipient_address: AccountId,
+		auction_duration: BlockNo,
+		burn_ration: u8,
+	}
+
+	/// `LMPEpochConfig` before vesting and sponsored reward streams
+	#[derive(Decode)]
+	struct OldLMPEpochConfig {
+		total_liquidity_mining_rewards: Decimal,
+		total_trading_rewards: Decimal,
+		config: BTreeMap<TradingPair, LMPMarketConfig>,
+		max_accounts_rewarded: u16,
+		claim_safety_period: u32,
+	}
+
+	impl<AccountId> From<OldLMPEpochConfig> for LMPEpochConfig<AccountId> {
+		fn from(old: OldLMPEpochConfig) -> Self {
+			LMPEpochConfig {
+				total_liquidity_mining_rewards: old.total_liquidity_mining_rewards,
+				total_trading_rewards: old.total_trading_rewards,
+				config: old.config,
+				max_accounts_rewarded: old.max_accounts_rewarded,
+				claim_safety_period: old.claim_safety_period,
+				vesting: None,
+				reward_streams: BTreeMap::new(),
+			}
+		}
+	}
+
+	/// Moves the fee distribution config to a single auctioned recipient and the LMP configs
+	/// to the layout with vesting and reward streams
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() >= 1 {
+				log::info!(target: "ocex", "Skipping migration to v1, already applied");
+				return T::DbWeight::get().reads(1);
+			}
+			let fee_config = <FeeDistributionConfig<T>>::translate::<
+				OldFeeDistribution<T::AccountId, BlockNumberFor<T>>,
+				_,
+			>(|old| {
+				old.map(|old| FeeDistribution {
+					recipients: BoundedVec::truncate_from(sp_std::vec![FeeRecipient {
+						account: old.recipient_address,
+						share: 100,
+					}]),
+					auction_duration: old.auction_duration,
+					burn_ration: old.burn_ration,
+					mode: FeeDistributionMode::Auction,
+				})
+			});
+			if fee_config.is_err() {
+				log::error!(target: "ocex", "Unable to decode the fee distribution config");
+			}
+			let mut translated = 0u64;
+			<LMPConfig<T>>::translate::<OldLMPEpochConfig, _>(|_, old| {
+				translated += 1;
+				Some(old.into())
+			});
+			let expected = <ExpectedLMPConfig<T>>::translate::<OldLMPEpochConfig, _>(|old| {
+				old.map(Into::into)
+			});
+			if expected.is_err() {
+				log::error!(target: "ocex", "Unable to decode the expected LMP config");
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: "ocex", "Migrated {:?} LMP epoch configs to v1", translated);
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(3), translated.saturating_add(3))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let lmp_configs = <LMPConfig<T>>::iter_keys().count() as u32;
+			let fee_config = <FeeDistributionConfig<T>>::exists();
+			let expected = <ExpectedLMPConfig<T>>::exists();
+			Ok((lmp_configs, fee_config, expected).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (lmp_configs, fee_config, expected): (u32, bool, bool) =
+				Decode::decode(&mut &state[..])
+					.map_err(|_| "Unable to decode the pre upgrade state")?;
+			ensure!(StorageVersion::get::<Pallet<T>>() == 1, "Storage version not updated");
+			ensure!(
+				<LMPConfig<T>>::iter().count() as u32 == lmp_configs,
+				"LMP epoch configs lost in migration"
+			);
+			ensure!(
+				<FeeDistributionConfig<T>>::get().is_some() == fee_config,
+				"Fee distribution config lost in migration"
+			);
+			ensure!(
+				<ExpectedLMPConfig<T>>::get().is_some() == expected,
+				"Expected LMP config lost in migration"
+			);
+			Ok(())
+		}
+	}
+}
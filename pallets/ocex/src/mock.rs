@@ -21,7 +21,7 @@
 use crate::*;
 use frame_support::{
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU128, ConstU64, OnTimestampSet},
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, OnTimestampSet},
 	PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSigned};
@@ -52,6 +52,8 @@ frame_support::construct_runtime!(
 		Timestamp: pallet_timestamp,
 		LiqudityMining: pallet_lmp::pallet,
 		OCEX: crate,
+		AssetConversion: pallet_asset_conversion,
+		OrmlVesting: orml_vesting,
 	}
 );
 
@@ -126,6 +128,7 @@ parameter_types! {
 	//pub const TreasuryPalletId: PalletId = PalletId(*b"OCEX_CRW");
 	pub const MsPerDay: u64 = 86_400_000;
 	pub const OBWithdrawalLimit: u32 = 50;
+	pub const LMPRewardStreamsClaimPeriod: u32 = 100;
 }
 
 impl pallet_lmp::pallet::Config for Test {
@@ -141,16 +144,89 @@ impl Config for Test {
 	type PalletId = OcexPalletId;
 	type TreasuryPalletId = TreasuryPalletId;
 	type LMPRewardsPalletId = OcexPalletId;
+	type LMPRewardStreamsClaimPeriod = LMPRewardStreamsClaimPeriod;
 	type NativeCurrency = Balances;
 	type OtherAssets = Assets;
 	type EnclaveOrigin = EnsureRoot<sp_runtime::AccountId32>;
 	type AuthorityId = crate::sr25519::AuthorityId;
 	type GovernanceOrigin = EnsureRoot<sp_runtime::AccountId32>;
+	type LMPRewardsVesting = LMPRewardsVesting;
 	type CrowdSourceLiqudityMining = LiqudityMining;
+	type Swap = AssetConversion;
 	type WeightInfo = crate::weights::WeightInfo<Test>;
 	type OBWithdrawalLimit = OBWithdrawalLimit;
 }
 
+impl orml_vesting::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type MinVestedTransfer = ConstU128<1>;
+	type VestedTransferOrigin = EnsureSigned<sp_runtime::AccountId32>;
+	type WeightInfo = ();
+	type MaxVestingSchedules = ConstU32<10>;
+	type BlockNumberProvider = System;
+}
+
+pub struct LMPRewardsVesting;
+impl orderbook_primitives::traits::LMPRewardsVesting<sp_runtime::AccountId32>
+	for LMPRewardsVesting
+{
+	fn vested_transfer(
+		from: &sp_runtime::AccountId32,
+		to: &sp_runtime::AccountId32,
+		start: u32,
+		period: u32,
+		period_count: u32,
+		per_period: u128,
+	) -> DispatchResult {
+		OrmlVesting::vested_transfer(
+			RuntimeOrigin::signed(from.clone()),
+			to.clone(),
+			orml_vesting::VestingSchedule {
+				start: start.into(),
+				period: period.into(),
+				period_count,
+				per_period,
+			},
+		)
+	}
+}
+
+frame_support::ord_parameter_types! {
+	pub const AssetConversionOrigin: sp_runtime::AccountId32 = sp_runtime::AccountId32::new([9; 32]);
+}
+
+parameter_types! {
+	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
+	pub AllowMultiAssetPools: bool = true;
+	pub const PoolSetupFee: u128 = 1000000000000; // should be more or equal to the existential deposit
+	pub const MintMinLiquidity: u128 = 100;  // 100 is good enough when the main currency has 10-12 decimals.
+	pub const LiquidityWithdrawalFee: sp_runtime::Permill = sp_runtime::Permill::from_percent(0);  // should be non-zero if AllowMultiAssetPools is true, otherwise can be zero.
+}
+
+impl pallet_asset_conversion::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Balance = u128;
+	type AssetBalance = u128;
+	type HigherPrecisionBalance = u128;
+	type AssetId = u128;
+	type MultiAssetId = polkadex_primitives::AssetId;
+	type MultiAssetIdConverter = polkadex_primitives::AssetIdConverter;
+	type PoolAssetId = u128;
+	type Assets = Assets;
+	type PoolAssets = Assets;
+	type LPFee = ConstU32<3>; // means 0.3%
+	type PoolSetupFee = PoolSetupFee;
+	type PoolSetupFeeReceiver = AssetConversionOrigin;
+	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
+	type MintMinLiquidity = MintMinLiquidity;
+	type MaxSwapPathLength = ConstU32<4>;
+	type PalletId = AssetConversionPalletId;
+	type AllowMultiAssetPools = AllowMultiAssetPools;
+	type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Test>;
+}
+
 parameter_types! {
 	pub const AssetDeposit: u128 = 100;
 	pub const ApprovalDeposit: u128 = 1;
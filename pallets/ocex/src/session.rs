@@ -18,27 +18,44 @@
 
 use crate::pallet::IngressMessages;
 use crate::{
-	pallet::{Config, ExpectedLMPConfig, LMPConfig, Pallet},
+	pallet::{
+		Config, Event, ExpectedLMPConfig, LMPConfig, LMPEpochDurationConfig, NextLMPEpochBlock,
+		Pallet, PendingLMPEpochDuration, ScheduledLMPConfigs,
+	},
 	FinalizeLMPScore, LMPEpoch,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
+use orderbook_primitives::lmp::{LMPEpochDuration, LMPScheduledEpoch, MAX_SCHEDULED_LMP_EPOCHS};
 use orderbook_primitives::traits::LiquidityMiningCrowdSourcePallet;
 use sp_runtime::SaturatedConversion;
-
-const EPOCH_LENGTH: u32 = 201600u32; // 28 days in blocks
+use sp_std::vec::Vec;
 
 impl<T: Config> Pallet<T> {
 	pub(crate) fn should_start_new_epoch(n: BlockNumberFor<T>) -> bool {
-		n.saturated_into::<u32>() % EPOCH_LENGTH == 0
+		match <NextLMPEpochBlock<T>>::get() {
+			Some(next_epoch_blk) => n >= next_epoch_blk,
+			// Follow the default cadence until the first epoch is started
+			None => n.saturated_into::<u32>() % LMPEpochDuration::default().epoch_length == 0,
+		}
 	}
 
 	/// Starts new liquidity mining epoch
 	pub fn start_new_epoch(n: BlockNumberFor<T>) {
+		// Duration changes only take effect at epoch boundaries
+		if let Some(duration) = <PendingLMPEpochDuration<T>>::take() {
+			<LMPEpochDurationConfig<T>>::put(duration);
+		}
+		let duration = <LMPEpochDurationConfig<T>>::get();
+		<NextLMPEpochBlock<T>>::put(n.saturating_add(duration.epoch_length.saturated_into()));
+		Self::apply_scheduled_lmp_config(<LMPEpoch<T>>::get().saturating_add(1));
 		if let Some(config) = <ExpectedLMPConfig<T>>::get() {
+			// Sponsored reward streams are escrowed for the upcoming epoch only
+			let mut next_config = config.clone();
+			next_config.clear_reward_streams();
 			let mut current_epoch: u16 = <LMPEpoch<T>>::get();
 			//This is to handle the corner case when epoch is 0
 			if current_epoch == 0 && !<LMPConfig<T>>::contains_key(current_epoch) {
-				<LMPConfig<T>>::insert(current_epoch, config.clone());
+				<LMPConfig<T>>::insert(current_epoch, next_config.clone());
 			}
 			if <FinalizeLMPScore<T>>::get().is_none() {
 				<FinalizeLMPScore<T>>::put(current_epoch);
@@ -46,6 +63,7 @@ impl<T: Config> Pallet<T> {
 			current_epoch = current_epoch.saturating_add(1);
 			<LMPEpoch<T>>::put(current_epoch);
 			<LMPConfig<T>>::insert(current_epoch, config.clone());
+			<ExpectedLMPConfig<T>>::put(next_config);
 			// Notify Liquidity Crowd sourcing pallet about new epoch
 			T::CrowdSourceLiqudityMining::new_epoch(current_epoch);
 
@@ -53,19 +71,78 @@ impl<T: Config> Pallet<T> {
 				ingress_messages.push(orderbook_primitives::ingress::IngressMessages::NewLMPEpoch(
 					current_epoch,
 				));
-				ingress_messages
-					.push(orderbook_primitives::ingress::IngressMessages::LMPConfig(config))
+				ingress_messages.push(orderbook_primitives::ingress::IngressMessages::LMPConfig(
+					(&config).into(),
+				))
 			});
 		}
 	}
 
+	/// Applies the config changes scheduled for `epoch` on top of the expected config
+	fn apply_scheduled_lmp_config(epoch: u16) {
+		let Some(diff) = <ScheduledLMPConfigs<T>>::take(epoch) else { return };
+		let mut config = <ExpectedLMPConfig<T>>::get().unwrap_or_default();
+		let mut released = config.apply(diff.clone());
+		if config.verify() {
+			<ExpectedLMPConfig<T>>::put(config);
+			Self::deposit_event(Event::<T>::LMPScheduledConfigApplied(epoch));
+		} else {
+			// Changes made after scheduling can invalidate the diff, so it is dropped as a whole
+			released = diff.reward_streams.into_values().flatten().collect();
+			Self::deposit_event(Event::<T>::LMPScheduledConfigDiscarded(epoch));
+		}
+		Self::release_lmp_reward_streams(released);
+	}
+
 	pub(crate) fn should_stop_accepting_lmp_withdrawals(n: BlockNumberFor<T>) -> bool {
-		// Triggers 7200 blocks ( or approx 1 day before epoch change)
-		n.saturated_into::<u32>().saturating_add(7200) % EPOCH_LENGTH == 0
+		let offset = <LMPEpochDurationConfig<T>>::get().withdrawal_stop_offset;
+		match <NextLMPEpochBlock<T>>::get() {
+			Some(next_epoch_blk) => n.saturating_add(offset.saturated_into()) == next_epoch_blk,
+			// Triggers 7200 blocks ( or approx 1 day before epoch change)
+			None => {
+				let default = LMPEpochDuration::default();
+				n.saturated_into::<u32>().saturating_add(default.withdrawal_stop_offset)
+					% default.epoch_length
+					== 0
+			},
+		}
 	}
 
 	pub(crate) fn stop_accepting_lmp_withdrawals() {
 		let current_epoch: u16 = <LMPEpoch<T>>::get();
 		T::CrowdSourceLiqudityMining::stop_accepting_lmp_withdrawals(current_epoch)
 	}
+
+	/// Returns the configs and expected start blocks of the upcoming epochs - called by RPC
+	pub fn get_lmp_schedule() -> Vec<LMPScheduledEpoch<T::AccountId>> {
+		let current_blk: u32 = frame_system::Pallet::<T>::current_block_number().saturated_into();
+		let duration = <LMPEpochDurationConfig<T>>::get();
+		let mut start_block: u32 = match <NextLMPEpochBlock<T>>::get() {
+			Some(next_epoch_blk) => next_epoch_blk.saturated_into(),
+			None => current_blk
+				.saturating_add(duration.epoch_length)
+				.saturating_sub(current_blk % duration.epoch_length),
+		};
+		let epoch_length = <PendingLMPEpochDuration<T>>::get().unwrap_or(duration).epoch_length;
+		let current_epoch = <LMPEpoch<T>>::get();
+		let mut config = <ExpectedLMPConfig<T>>::get();
+		let mut schedule = Vec::new();
+		for epoch in
+			current_epoch.saturating_add(1)..=current_epoch.saturating_add(MAX_SCHEDULED_LMP_EPOCHS)
+		{
+			if let Some(diff) = <ScheduledLMPConfigs<T>>::get(epoch) {
+				let mut updated = config.clone().unwrap_or_default();
+				updated.apply(diff);
+				if updated.verify() {
+					config = Some(updated);
+				}
+			}
+			if let Some(config) = config.as_mut() {
+				schedule.push(LMPScheduledEpoch { epoch, start_block, config: config.clone() });
+				config.clear_reward_streams();
+			}
+			start_block = start_block.saturating_add(epoch_length);
+		}
+		schedule
+	}
 }
@@ -33,7 +33,9 @@ use frame_system::EventRecord;
 use orderbook_primitives::ingress::{EgressMessages, IngressMessages};
 use orderbook_primitives::ocex::AccountInfo;
 use parity_scale_codec::{Compact, Decode};
-use polkadex_primitives::auction::{AuctionInfo, FeeDistribution};
+use polkadex_primitives::auction::{
+	AuctionInfo, FeeDistribution, FeeDistributionMode, FeeRecipient, FeeRecipients,
+};
 use polkadex_primitives::AccountId;
 use rust_decimal::Decimal;
 use sp_core::{
@@ -2315,6 +2317,7 @@ fn test_set_lmp_epoch_config_happy_path() {
 			min_maker_volume: UNIT_BALANCE,
 			max_spread: UNIT_BALANCE,
 			min_depth: UNIT_BALANCE,
+			reward_streams: vec![],
 		};
 		assert_ok!(OCEX::set_lmp_epoch_config(
 			RuntimeOrigin::root(),
@@ -2348,6 +2351,7 @@ fn test_set_lmp_epoch_config_invalid_market_weightage() {
 			min_maker_volume: UNIT_BALANCE,
 			max_spread: UNIT_BALANCE,
 			min_depth: UNIT_BALANCE,
+			reward_streams: vec![],
 		};
 		assert_noop!(
 			OCEX::set_lmp_epoch_config(
@@ -2384,6 +2388,7 @@ fn test_set_lmp_epoch_config_invalid_invalid_lmpconfig() {
 			min_maker_volume: UNIT_BALANCE,
 			max_spread: UNIT_BALANCE,
 			min_depth: UNIT_BALANCE,
+			reward_streams: vec![],
 		};
 		assert_noop!(
 			OCEX::set_lmp_epoch_config(
@@ -2469,6 +2474,462 @@ fn test_do_claim_lmp_rewards_happy_path() {
 	})
 }
 
+#[test]
+fn test_claim_lmp_rewards_with_sponsored_reward_stream() {
+	new_test_ext().execute_with(|| {
+		let sponsor = AccountId32::new([2; 32]);
+		let trader = AccountId32::new([1; 32]);
+		let quote_asset = AssetId::Asset(1);
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: quote_asset };
+		crete_base_and_quote_asset();
+		register_trading_pair();
+		let reward_account: AccountId32 =
+			<mock::Test as pallet::Config>::LMPRewardsPalletId::get().into_account_truncating();
+		Balances::mint_into(&reward_account, 300 * UNIT_BALANCE).unwrap();
+		Balances::mint_into(&sponsor, UNIT_BALANCE).unwrap();
+		Assets::mint_into(quote_asset.asset_id().unwrap(), &sponsor, 500 * UNIT_BALANCE).unwrap();
+		// Sponsored rewards are escrowed in the LMP rewards account by the sponsor
+		assert_ok!(OCEX::fund_lmp_reward_stream(
+			RuntimeOrigin::signed(sponsor.clone()),
+			trading_pair,
+			quote_asset,
+			Compact::from(500 * UNIT_BALANCE)
+		));
+		assert_eq!(Assets::balance(quote_asset.asset_id().unwrap(), &sponsor), 0);
+		assert_eq!(
+			Assets::balance(quote_asset.asset_id().unwrap(), &reward_account),
+			500 * UNIT_BALANCE
+		);
+		let mut lmp_config = LMPMarketConfigWrapper {
+			trading_pair,
+			market_weightage: UNIT_BALANCE,
+			min_fees_paid: UNIT_BALANCE,
+			min_maker_volume: UNIT_BALANCE,
+			max_spread: UNIT_BALANCE,
+			min_depth: UNIT_BALANCE,
+			reward_streams: vec![1],
+		};
+		// Governance can only use streams that are funded
+		assert_noop!(
+			OCEX::set_lmp_epoch_config(
+				RuntimeOrigin::root(),
+				Some(Compact::from(1000 * UNIT_BALANCE)),
+				Some(Compact::from(1000 * UNIT_BALANCE)),
+				vec![lmp_config.clone()],
+				Some(10),
+				Some(0)
+			),
+			Error::<Test>::LMPRewardStreamNotFunded
+		);
+		lmp_config.reward_streams = vec![0];
+		assert_ok!(OCEX::set_lmp_epoch_config(
+			RuntimeOrigin::root(),
+			Some(Compact::from(1000 * UNIT_BALANCE)),
+			Some(Compact::from(1000 * UNIT_BALANCE)),
+			vec![lmp_config],
+			Some(10),
+			Some(0)
+		));
+		// Streams used by a config can no longer be withdrawn
+		assert_eq!(<FundedLMPRewardStreams<Test>>::get(0), None);
+		assert_noop!(
+			OCEX::withdraw_lmp_reward_stream(RuntimeOrigin::signed(sponsor.clone()), 0),
+			Error::<Test>::LMPRewardStreamNotFunded
+		);
+		// Streams are funded for the upcoming epoch only
+		OCEX::start_new_epoch(1);
+		assert_eq!(<LMPConfig<Test>>::get(1).unwrap().reward_streams[&trading_pair].len(), 1);
+		assert!(<ExpectedLMPConfig<Test>>::get().unwrap().reward_streams.is_empty());
+		<FinalizeLMPScore<Test>>::put(1);
+		let mut trader_metrics: TraderMetricsMap<AccountId32> = BTreeMap::new();
+		trader_metrics.insert(trader.clone(), (Decimal::from(100), Decimal::from(100)));
+		let mut trading_pair_metrics_map: TradingPairMetricsMap<AccountId32> = BTreeMap::new();
+		trading_pair_metrics_map
+			.insert(trading_pair, (trader_metrics, (Decimal::from(1000), Decimal::from(1000))));
+		assert_ok!(OCEX::update_lmp_scores(&trading_pair_metrics_map));
+		assert_ok!(OCEX::do_claim_lmp_rewards(trader.clone(), 1, trading_pair));
+		// Trader owns 10% of the market making score
+		assert_eq!(Assets::balance(quote_asset.asset_id().unwrap(), &trader), 50 * UNIT_BALANCE);
+		// Unclaimed rewards are refunded once the claim period is over
+		assert_noop!(
+			OCEX::refund_lmp_reward_streams(
+				RuntimeOrigin::signed(sponsor.clone()),
+				1,
+				trading_pair
+			),
+			Error::<Test>::RewardStreamsClaimPeriodNotOver
+		);
+		System::set_block_number(101);
+		assert_ok!(OCEX::refund_lmp_reward_streams(
+			RuntimeOrigin::signed(sponsor.clone()),
+			1,
+			trading_pair
+		));
+		assert_eq!(Assets::balance(quote_asset.asset_id().unwrap(), &sponsor), 450 * UNIT_BALANCE);
+		assert_noop!(
+			OCEX::refund_lmp_reward_streams(RuntimeOrigin::signed(sponsor), 1, trading_pair),
+			Error::<Test>::RewardStreamsAlreadyRefunded
+		);
+	})
+}
+
+#[test]
+fn test_fund_and_withdraw_lmp_reward_stream() {
+	new_test_ext().execute_with(|| {
+		let sponsor = AccountId32::new([2; 32]);
+		let quote_asset = AssetId::Asset(1);
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: quote_asset };
+		crete_base_and_quote_asset();
+		let reward_account: AccountId32 =
+			<mock::Test as pallet::Config>::LMPRewardsPalletId::get().into_account_truncating();
+		Balances::mint_into(&sponsor, UNIT_BALANCE).unwrap();
+		Assets::mint_into(quote_asset.asset_id().unwrap(), &sponsor, 500 * UNIT_BALANCE).unwrap();
+		assert_noop!(
+			OCEX::fund_lmp_reward_stream(
+				RuntimeOrigin::signed(sponsor.clone()),
+				trading_pair,
+				quote_asset,
+				Compact::from(500 * UNIT_BALANCE)
+			),
+			Error::<Test>::TradingPairNotRegistered
+		);
+		register_trading_pair();
+		assert_noop!(
+			OCEX::fund_lmp_reward_stream(
+				RuntimeOrigin::signed(sponsor.clone()),
+				trading_pair,
+				quote_asset,
+				Compact::from(0)
+			),
+			Error::<Test>::InvalidLMPRewardStream
+		);
+		assert_ok!(OCEX::fund_lmp_reward_stream(
+			RuntimeOrigin::signed(sponsor.clone()),
+			trading_pair,
+			quote_asset,
+			Compact::from(500 * UNIT_BALANCE)
+		));
+		assert_eq!(
+			Assets::balance(quote_asset.asset_id().unwrap(), &reward_account),
+			500 * UNIT_BALANCE
+		);
+		assert_noop!(
+			OCEX::withdraw_lmp_reward_stream(RuntimeOrigin::signed(AccountId32::new([3; 32])), 0),
+			Error::<Test>::NotLMPRewardStreamSponsor
+		);
+		assert_ok!(OCEX::withdraw_lmp_reward_stream(RuntimeOrigin::signed(sponsor.clone()), 0));
+		assert_eq!(Assets::balance(quote_asset.asset_id().unwrap(), &sponsor), 500 * UNIT_BALANCE);
+		assert_eq!(<FundedLMPRewardStreams<Test>>::get(0), None);
+	})
+}
+
+#[test]
+fn test_schedule_lmp_epoch_config() {
+	new_test_ext().execute_with(|| {
+		add_lmp_config();
+		assert_eq!(OCEX::lmp_epoch(), 2);
+		assert_noop!(
+			OCEX::schedule_lmp_epoch_config(
+				RuntimeOrigin::root(),
+				2,
+				None,
+				Some(Compact::from(500 * UNIT_BALANCE)),
+				vec![],
+				vec![],
+				None,
+				None
+			),
+			Error::<Test>::InvalidLMPScheduleEpoch
+		);
+		assert_ok!(OCEX::schedule_lmp_epoch_config(
+			RuntimeOrigin::root(),
+			4,
+			None,
+			Some(Compact::from(500 * UNIT_BALANCE)),
+			vec![],
+			vec![],
+			Some(5),
+			None
+		));
+		// Removing the only market leaves an invalid config
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		assert_noop!(
+			OCEX::schedule_lmp_epoch_config(
+				RuntimeOrigin::root(),
+				5,
+				None,
+				None,
+				vec![],
+				vec![trading_pair],
+				None,
+				None
+			),
+			Error::<Test>::InvalidLMPConfig
+		);
+		let schedule = OCEX::get_lmp_schedule();
+		assert_eq!(schedule.len(), MAX_SCHEDULED_LMP_EPOCHS as usize);
+		assert_eq!(schedule[0].config.total_trading_rewards, Decimal::from(1000));
+		assert_eq!(schedule[1].epoch, 4);
+		assert_eq!(schedule[1].config.total_trading_rewards, Decimal::from(500));
+		assert_eq!(schedule[1].start_block, schedule[0].start_block + 201600);
+		OCEX::start_new_epoch(3);
+		assert_eq!(OCEX::lmp_config(3).unwrap().total_trading_rewards, Decimal::from(1000));
+		OCEX::start_new_epoch(4);
+		let config = OCEX::lmp_config(4).unwrap();
+		assert_eq!(config.total_trading_rewards, Decimal::from(500));
+		assert_eq!(config.max_accounts_rewarded, 5);
+		assert!(OCEX::scheduled_lmp_configs(4).is_none());
+	})
+}
+
+#[test]
+fn test_cancel_scheduled_lmp_config() {
+	new_test_ext().execute_with(|| {
+		add_lmp_config();
+		assert_noop!(
+			OCEX::cancel_scheduled_lmp_config(RuntimeOrigin::root(), 4),
+			Error::<Test>::ScheduledLMPConfigNotFound
+		);
+		assert_ok!(OCEX::schedule_lmp_epoch_config(
+			RuntimeOrigin::root(),
+			4,
+			None,
+			Some(Compact::from(500 * UNIT_BALANCE)),
+			vec![],
+			vec![],
+			None,
+			None
+		));
+		assert_ok!(OCEX::cancel_scheduled_lmp_config(RuntimeOrigin::root(), 4));
+		OCEX::start_new_epoch(3);
+		OCEX::start_new_epoch(4);
+		assert_eq!(OCEX::lmp_config(4).unwrap().total_trading_rewards, Decimal::from(1000));
+	})
+}
+
+#[test]
+fn test_set_lmp_epoch_duration() {
+	new_test_ext().execute_with(|| {
+		add_lmp_config();
+		assert_noop!(
+			OCEX::set_lmp_epoch_duration(
+				RuntimeOrigin::root(),
+				LMPEpochDuration { epoch_length: 100, withdrawal_stop_offset: 100 }
+			),
+			Error::<Test>::InvalidLMPEpochDuration
+		);
+		let duration = LMPEpochDuration { epoch_length: 100, withdrawal_stop_offset: 10 };
+		assert_ok!(OCEX::set_lmp_epoch_duration(RuntimeOrigin::root(), duration));
+		// Current epoch keeps its length
+		assert_eq!(OCEX::lmp_epoch_duration(), LMPEpochDuration::default());
+		assert!(!OCEX::should_start_new_epoch(100));
+		OCEX::start_new_epoch(5);
+		assert_eq!(OCEX::lmp_epoch_duration(), duration);
+		assert_eq!(OCEX::next_lmp_epoch_block(), Some(105));
+		assert!(OCEX::should_stop_accepting_lmp_withdrawals(95));
+		assert!(!OCEX::should_stop_accepting_lmp_withdrawals(96));
+		assert!(!OCEX::should_start_new_epoch(104));
+		assert!(OCEX::should_start_new_epoch(105));
+	})
+}
+
+#[test]
+fn test_claim_lmp_rewards_multi_with_vesting() {
+	new_test_ext().execute_with(|| {
+		add_lmp_config();
+		update_lmp_score();
+		let main_account = AccountId32::new([1; 32]);
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let invalid_vesting = LMPVestingPolicy {
+			cliff: 10,
+			linear_period: 0,
+			immediate_fraction: Decimal::from_str("0.25").unwrap(),
+		};
+		assert_noop!(
+			OCEX::set_lmp_vesting_policy(RuntimeOrigin::root(), Some(invalid_vesting)),
+			Error::<Test>::InvalidLMPVestingPolicy
+		);
+		let vesting = LMPVestingPolicy { linear_period: 100, ..invalid_vesting };
+		assert_ok!(OCEX::set_lmp_vesting_policy(RuntimeOrigin::root(), Some(vesting)));
+		// Vesting applies from the next epoch only
+		assert_eq!(OCEX::lmp_config(2).unwrap().vesting, None);
+		OCEX::start_new_epoch(3);
+		assert_eq!(OCEX::lmp_config(3).unwrap().vesting, Some(vesting));
+		<FinalizeLMPScore<Test>>::put(3);
+		update_lmp_score();
+		let reward_account =
+			<mock::Test as pallet::Config>::LMPRewardsPalletId::get().into_account_truncating();
+		Balances::mint_into(&reward_account, 500 * UNIT_BALANCE).unwrap();
+		assert_eq!(
+			OCEX::get_lmp_claimable_rewards(&main_account, 3, trading_pair),
+			(Decimal::from(50), Decimal::from(150), false)
+		);
+		assert_ok!(OCEX::claim_lmp_rewards_multi(
+			RuntimeOrigin::signed(main_account.clone()),
+			vec![(0, trading_pair), (3, trading_pair)].try_into().unwrap()
+		));
+		assert_eq!(Balances::free_balance(&main_account), 400999999999900u128);
+		// 150 PDEX of epoch 3 unlock linearly over 100 blocks after the cliff
+		let schedules = orml_vesting::VestingSchedules::<Test>::get(&main_account);
+		assert_eq!(schedules.len(), 1);
+		assert_eq!(schedules[0].start, 11);
+		assert_eq!(schedules[0].per_period, 1_500_000_000_000);
+		assert_eq!(schedules[0].period_count, 100);
+		assert_noop!(
+			OCEX::claim_lmp_rewards_multi(
+				RuntimeOrigin::signed(main_account),
+				vec![(3, trading_pair)].try_into().unwrap()
+			),
+			Error::<Test>::RewardAlreadyClaimed
+		);
+	})
+}
+
+#[test]
+fn test_verify_q_scores_from_depth_sample() {
+	let mut ext = new_test_ext();
+	ext.persist_offchain_overlay();
+	register_offchain_ext(&mut ext);
+	ext.execute_with(|| {
+		add_lmp_config();
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let alice = AccountId32::new([1; 32]);
+		let bob = AccountId32::new([2; 32]);
+		let level = |side, price: &str, qty| LMPDepthLevel {
+			side,
+			price: Decimal::from_str(price).unwrap(),
+			qty: Decimal::from(qty),
+		};
+		let sample = LMPDepthSample {
+			levels: BTreeMap::from([
+				(
+					alice.clone(),
+					vec![
+						level(OrderSide::Bid, "0.995", 10),
+						level(OrderSide::Ask, "1.005", 10),
+						// Outside the max spread of 1%
+						level(OrderSide::Ask, "1.02", 1000),
+					],
+				),
+				(bob.clone(), vec![level(OrderSide::Bid, "0.995", 60)]),
+			]),
+		};
+		let scores = BTreeMap::from([
+			(alice.clone(), Decimal::from_str("2.4875").unwrap()),
+			(bob.clone(), Decimal::from_str("4.975").unwrap()),
+		]);
+		let total = Decimal::from_str("7.4625").unwrap();
+		let mut root = crate::storage::load_trie_root();
+		let mut trie_state = crate::storage::State;
+		let mut state = OffchainState::load(&mut trie_state, &mut root);
+		crate::lmp::store_lmp_config(
+			&mut state,
+			orderbook_primitives::lmp::LMPConfig { epoch: 2, index: 0 },
+		);
+		assert_ok!(OCEX::verify_q_scores(&mut state, trading_pair, total, &scores, &sample, 2));
+		let mut inflated_scores = scores.clone();
+		inflated_scores.insert(bob, Decimal::from(10));
+		assert_eq!(
+			OCEX::verify_q_scores(&mut state, trading_pair, total, &inflated_scores, &sample, 2),
+			Err("Q scores mismatch")
+		);
+		assert_eq!(
+			OCEX::verify_q_scores(&mut state, trading_pair, Decimal::from(8), &scores, &sample, 2),
+			Err("Total Q score mismatch")
+		);
+	});
+}
+
+#[test]
+fn test_legacy_lmp_reports_do_not_change_q_scores() {
+	let mut ext = new_test_ext();
+	ext.persist_offchain_overlay();
+	register_offchain_ext(&mut ext);
+	ext.execute_with(|| {
+		add_lmp_config();
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let alice = AccountId32::new([1; 32]);
+		let mut root = crate::storage::load_trie_root();
+		let mut trie_state = crate::storage::State;
+		let mut state = OffchainState::load(&mut trie_state, &mut root);
+		crate::lmp::store_lmp_config(
+			&mut state,
+			orderbook_primitives::lmp::LMPConfig { epoch: 2, index: 0 },
+		);
+		let batch = UserActionBatch {
+			actions: vec![orderbook_primitives::types::UserActions::OneMinLMPReport(
+				trading_pair,
+				Decimal::from(100),
+				BTreeMap::from([(alice.clone(), Decimal::from(100))]),
+			)],
+			stid: 1,
+			snapshot_id: 0,
+			signature: sp_core::ecdsa::Signature::default(),
+		};
+		let mut state_info =
+			crate::snapshot::StateInfo { last_block: 0, worker_nonce: 0, stid: 0, snapshot_id: 0 };
+		assert_ok!(OCEX::process_batch(&mut state, &batch, &mut state_info));
+		assert_eq!(
+			crate::lmp::get_q_score_and_uptime(&mut state, 2, &trading_pair, &alice),
+			Ok((Decimal::ZERO, 0))
+		);
+		assert_eq!(
+			crate::lmp::get_lmp_config(&mut state, 2),
+			Ok(orderbook_primitives::lmp::LMPConfig { epoch: 2, index: 0 })
+		);
+	});
+}
+
+#[test]
+fn test_get_pool_balances() {
+	let mut ext = new_test_ext();
+	ext.persist_offchain_overlay();
+	register_offchain_ext(&mut ext);
+	ext.execute_with(|| {
+		let pool = create_account_id();
+		let market = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let mut root = crate::storage::load_trie_root();
+		let mut trie_state = crate::storage::State;
+		let mut state = OffchainState::load(&mut trie_state, &mut root);
+		assert_ok!(add_balance(&mut state, &pool, market.base, Decimal::from(10)));
+		assert_ok!(add_balance(&mut state, &pool, market.quote, Decimal::from(50)));
+		state.commit().unwrap();
+		drop(state);
+		store_trie_root(root);
+		assert_eq!(
+			<OCEX as orderbook_primitives::LiquidityMining<AccountId32, u128>>::get_pool_balances(
+				pool, market
+			),
+			(Decimal::from(10), Decimal::from(50))
+		);
+	});
+}
+
+#[test]
+fn test_lmp_rewarded_leaderboard_pagination() {
+	new_test_ext().execute_with(|| {
+		let trading_pair = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let metrics = [(1u8, 10u32, 40u32), (2, 30, 20), (3, 20, 30), (4, 30, 10)];
+		for (id, score, fees_paid) in metrics {
+			<TraderMetrics<Test>>::insert(
+				(0, trading_pair, AccountId32::new([id; 32])),
+				(Decimal::from(score), Decimal::from(fees_paid), false),
+			);
+		}
+		let page = |sorted_by_mm_score, offset, limit| {
+			OCEX::lmp_rewarded_leaderboard(0, trading_pair, sorted_by_mm_score, offset, limit)
+				.into_iter()
+				.map(|(main, _, _)| main)
+				.collect::<Vec<AccountId32>>()
+		};
+		// Accounts with equal scores are all listed
+		assert_eq!(page(true, 0, 2), vec![AccountId32::new([2; 32]), AccountId32::new([4; 32])]);
+		assert_eq!(page(true, 2, 2), vec![AccountId32::new([3; 32]), AccountId32::new([1; 32])]);
+		assert_eq!(page(false, 1, 2), vec![AccountId32::new([3; 32]), AccountId32::new([2; 32])]);
+		assert!(page(true, 4, 2).is_empty());
+	})
+}
+
 #[test]
 fn test_process_egress_msg_trading_fee() {
 	new_test_ext().execute_with(|| {
@@ -2553,11 +3014,91 @@ fn test_set_fee_distribution() {
 		let recipient_address = AccountId32::new([1; 32]);
 		let auction_duration = 100;
 		let burn_ration = 50;
-		let fee_distribution = FeeDistribution { recipient_address, auction_duration, burn_ration };
+		let fee_distribution = FeeDistribution {
+			recipients: vec![FeeRecipient { account: recipient_address, share: 100 }]
+				.try_into()
+				.unwrap(),
+			auction_duration,
+			burn_ration,
+			mode: FeeDistributionMode::Auction,
+		};
 		assert_ok!(OCEX::set_fee_distribution(RuntimeOrigin::root(), fee_distribution));
 	})
 }
 
+#[test]
+fn test_set_fee_distribution_with_invalid_shares() {
+	new_test_ext().execute_with(|| {
+		let fee_distribution = FeeDistribution {
+			recipients: vec![
+				FeeRecipient { account: AccountId32::new([1; 32]), share: 60 },
+				FeeRecipient { account: AccountId32::new([2; 32]), share: 30 },
+			]
+			.try_into()
+			.unwrap(),
+			auction_duration: 100,
+			burn_ration: 50,
+			mode: FeeDistributionMode::Auction,
+		};
+		assert_noop!(
+			OCEX::set_fee_distribution(RuntimeOrigin::root(), fee_distribution),
+			crate::pallet::Error::<Test>::InvalidFeeDistribution
+		);
+	})
+}
+
+#[test]
+fn test_set_fee_distribution_with_invalid_slippage() {
+	new_test_ext().execute_with(|| {
+		let fee_distribution = FeeDistribution {
+			recipients: vec![FeeRecipient { account: AccountId32::new([1; 32]), share: 100 }]
+				.try_into()
+				.unwrap(),
+			auction_duration: 100,
+			burn_ration: 50,
+			mode: FeeDistributionMode::BuyBack { max_slippage: 101 },
+		};
+		assert_noop!(
+			OCEX::set_fee_distribution(RuntimeOrigin::root(), fee_distribution),
+			crate::pallet::Error::<Test>::InvalidFeeDistribution
+		);
+	})
+}
+
+#[test]
+fn test_set_fee_recipients() {
+	new_test_ext().execute_with(|| {
+		let recipients: FeeRecipients<AccountId32> = vec![
+			FeeRecipient { account: AccountId32::new([1; 32]), share: 40 },
+			FeeRecipient { account: AccountId32::new([2; 32]), share: 60 },
+		]
+		.try_into()
+		.unwrap();
+		assert_noop!(
+			OCEX::set_fee_recipients(RuntimeOrigin::root(), recipients.clone()),
+			crate::pallet::Error::<Test>::FeeDistributionConfigNotFound
+		);
+		create_fee_config();
+		assert_noop!(
+			OCEX::set_fee_recipients(
+				RuntimeOrigin::signed(AccountId32::new([1; 32])),
+				recipients.clone()
+			),
+			BadOrigin
+		);
+		assert_ok!(OCEX::set_fee_recipients(RuntimeOrigin::root(), recipients.clone()));
+		assert_eq!(<FeeDistributionConfig<Test>>::get().unwrap().recipients, recipients);
+		let invalid_recipients: FeeRecipients<AccountId32> =
+			vec![FeeRecipient { account: AccountId32::new([1; 32]), share: 99 }]
+				.try_into()
+				.unwrap();
+		assert_noop!(
+			OCEX::set_fee_recipients(RuntimeOrigin::root(), invalid_recipients),
+			crate::pallet::Error::<Test>::InvalidFeeDistribution
+		);
+	})
+}
+
 #[test]
 fn test_create_auction_happy_path() {
 	new_test_ext().execute_with(|| {
@@ -2676,6 +3217,43 @@ fn test_close_auction_happy_path() {
 	})
 }
 
+#[test]
+fn test_close_auction_with_multiple_recipients() {
+	new_test_ext().execute_with(|| {
+		let usdt_asset = AssetId::Asset(1);
+		let treasury = AccountId32::new([1; 32]);
+		let staking_pot = AccountId32::new([3; 32]);
+		let insurance_fund = AccountId32::new([4; 32]);
+		let bidder = AccountId32::new([2; 32]);
+		let bidding_amount = 50 * UNIT_BALANCE;
+		create_assets_and_mint_pot_account(vec![usdt_asset]);
+		Balances::mint_into(&bidder, 100 * UNIT_BALANCE).unwrap();
+		create_fee_config();
+		let recipients: FeeRecipients<AccountId32> = vec![
+			FeeRecipient { account: treasury.clone(), share: 50 },
+			FeeRecipient { account: staking_pot.clone(), share: 30 },
+			FeeRecipient { account: insurance_fund.clone(), share: 20 },
+		]
+		.try_into()
+		.unwrap();
+		assert_ok!(OCEX::set_fee_recipients(RuntimeOrigin::root(), recipients));
+		let mut fee_info = BTreeMap::new();
+		fee_info.insert(usdt_asset.asset_id().unwrap(), 10 * UNIT_BALANCE);
+		let auction_info = AuctionInfo {
+			fee_info,
+			highest_bidder: Some(bidder.clone()),
+			highest_bid: bidding_amount,
+		};
+		<Auction<Test>>::put(auction_info);
+		assert_ok!(OCEX::close_auction());
+		// Half of the bid is burned and the other half is split between the recipients
+		assert_eq!(Balances::free_balance(&treasury), 12_500_000_000_000);
+		assert_eq!(Balances::free_balance(&staking_pot), 7_500_000_000_000);
+		assert_eq!(Balances::free_balance(&insurance_fund), 5 * UNIT_BALANCE);
+		assert_eq!(Balances::free_balance(&bidder), 50 * UNIT_BALANCE);
+	})
+}
+
 #[test]
 fn test_close_auction_error_transfer_zero_fee() {
 	new_test_ext().execute_with(|| {
@@ -2699,6 +3277,104 @@ fn test_close_auction_error_transfer_zero_fee() {
 	})
 }
 
+#[test]
+fn test_create_auction_buy_back_mode() {
+	new_test_ext().execute_with(|| {
+		let usdt_asset = AssetId::Asset(1);
+		create_buy_back_fee_config(2);
+		let mut allowlisted_tokens = <AllowlistedToken<Test>>::get();
+		allowlisted_tokens.try_insert(usdt_asset).unwrap();
+		<AllowlistedToken<Test>>::put(allowlisted_tokens);
+		create_assets_and_mint_pot_account(vec![usdt_asset]);
+		assert_ok!(OCEX::create_auction());
+		assert_eq!(<Auction<Test>>::get(), None);
+		assert_eq!(<AuctionBlockNumber<Test>>::get(), Some(101));
+	})
+}
+
+#[test]
+fn test_buy_back_fees_happy_path() {
+	new_test_ext().execute_with(|| {
+		let usdt_asset = AssetId::Asset(1);
+		let recipient_address = AccountId32::new([1; 32]);
+		create_buy_back_fee_config(2);
+		let mut allowlisted_tokens = <AllowlistedToken<Test>>::get();
+		allowlisted_tokens.try_insert(usdt_asset).unwrap();
+		<AllowlistedToken<Test>>::put(allowlisted_tokens);
+		create_assets_and_mint_pot_account(vec![usdt_asset]);
+		create_pool_with_liquidity(usdt_asset);
+		let mut prices = BTreeMap::new();
+		prices.insert((usdt_asset, AssetId::Polkadex), (Decimal::from(1), Decimal::from(1)));
+		<PriceOracle<Test>>::put(prices);
+		assert_ok!(OCEX::buy_back_fees());
+		let pot_account = OCEX::get_pot_account();
+		// Only the minimum balance is left in the pot account
+		assert_eq!(Assets::balance(usdt_asset.asset_id().unwrap(), &pot_account), 1);
+		// Half of the PDEX bought is burned and the other half is paid to the recipient
+		let recipient_balance = Balances::free_balance(&recipient_address);
+		assert!(recipient_balance > 49 * UNIT_BALANCE);
+		assert!(recipient_balance < 50 * UNIT_BALANCE);
+	})
+}
+
+#[test]
+fn test_buy_back_fees_rolls_over_on_slippage() {
+	new_test_ext().execute_with(|| {
+		let usdt_asset = AssetId::Asset(1);
+		let recipient_address = AccountId32::new([1; 32]);
+		create_buy_back_fee_config(2);
+		let mut allowlisted_tokens = <AllowlistedToken<Test>>::get();
+		allowlisted_tokens.try_insert(usdt_asset).unwrap();
+		<AllowlistedToken<Test>>::put(allowlisted_tokens);
+		create_assets_and_mint_pot_account(vec![usdt_asset]);
+		create_pool_with_liquidity(usdt_asset);
+		// Oracle expects twice the PDEX the pool can offer
+		let mut prices = BTreeMap::new();
+		prices.insert((usdt_asset, AssetId::Polkadex), (Decimal::from(2), Decimal::from(1)));
+		<PriceOracle<Test>>::put(prices);
+		assert_ok!(OCEX::buy_back_fees());
+		let pot_account = OCEX::get_pot_account();
+		assert_eq!(
+			Assets::balance(usdt_asset.asset_id().unwrap(), &pot_account),
+			100 * UNIT_BALANCE
+		);
+		assert_eq!(Balances::free_balance(&recipient_address), 0);
+		assert_last_event::<Test>(
+			crate::Event::FeesBuyBackFailed {
+				asset: usdt_asset,
+				amount: Compact::from(100 * UNIT_BALANCE - 1),
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn test_buy_back_fees_without_oracle_price() {
+	new_test_ext().execute_with(|| {
+		let usdt_asset = AssetId::Asset(1);
+		create_buy_back_fee_config(2);
+		let mut allowlisted_tokens = <AllowlistedToken<Test>>::get();
+		allowlisted_tokens.try_insert(usdt_asset).unwrap();
+		<AllowlistedToken<Test>>::put(allowlisted_tokens);
+		create_assets_and_mint_pot_account(vec![usdt_asset]);
+		create_pool_with_liquidity(usdt_asset);
+		assert_ok!(OCEX::buy_back_fees());
+		assert_last_event::<Test>(
+			crate::Event::FeesBuyBackFailed {
+				asset: usdt_asset,
+				amount: Compact::from(100 * UNIT_BALANCE - 1),
+			}
+			.into(),
+		);
+		let pot_account = OCEX::get_pot_account();
+		assert_eq!(
+			Assets::balance(usdt_asset.asset_id().unwrap(), &pot_account),
+			100 * UNIT_BALANCE
+		);
+	})
+}
+
 #[test]
 fn test_place_bid_happy_path() {
 	new_test_ext().execute_with(|| {
@@ -2802,14 +3478,113 @@ fn test_place_bid_error_insufficient_balance() {
 	})
 }
 
+#[test]
+fn test_migration_to_v1() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+	use orderbook_primitives::lmp::{LMPEngineConfig, LMPMarketConfig};
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<OCEX>();
+		let recipient = AccountId32::new([1; 32]);
+		frame_support::storage::unhashed::put_raw(
+			&<FeeDistributionConfig<Test>>::hashed_key(),
+			&(recipient.clone(), 100u64, 50u8).encode(),
+		);
+		let mut config = BTreeMap::new();
+		config.insert(
+			get_trading_pair(),
+			LMPMarketConfig {
+				weightage: Decimal::from(1),
+				max_spread: Decimal::from_str("0.1").unwrap(),
+				min_depth: Decimal::from(10),
+			},
+		);
+		let old_config = LMPEngineConfig {
+			total_liquidity_mining_rewards: Decimal::from(100),
+			total_trading_rewards: Decimal::from(100),
+			config,
+			max_accounts_rewarded: 20,
+			claim_safety_period: 10,
+		};
+		frame_support::storage::unhashed::put_raw(
+			&<LMPConfig<Test>>::hashed_key_for(1),
+			&old_config.encode(),
+		);
+		frame_support::storage::unhashed::put_raw(
+			&<ExpectedLMPConfig<Test>>::hashed_key(),
+			&old_config.encode(),
+		);
+		crate::migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<OCEX>(), 1);
+		let fee_config = <FeeDistributionConfig<Test>>::get().unwrap();
+		assert_eq!(
+			fee_config.recipients.to_vec(),
+			vec![FeeRecipient { account: recipient, share: 100 }]
+		);
+		assert_eq!(fee_config.auction_duration, 100);
+		assert_eq!(fee_config.burn_ration, 50);
+		assert_eq!(fee_config.mode, FeeDistributionMode::Auction);
+		for migrated in
+			[<LMPConfig<Test>>::get(1).unwrap(), <ExpectedLMPConfig<Test>>::get().unwrap()]
+		{
+			assert_eq!(LMPEngineConfig::from(&migrated), old_config);
+			assert_eq!(migrated.vesting, None);
+			assert!(migrated.reward_streams.is_empty());
+		}
+	});
+}
+
 pub fn create_fee_config() {
 	let recipient_address = AccountId32::new([1; 32]);
 	let auction_duration = 100;
 	let burn_ration = 50;
-	let fee_distribution = FeeDistribution { recipient_address, auction_duration, burn_ration };
+	let fee_distribution = FeeDistribution {
+		recipients: vec![FeeRecipient { account: recipient_address, share: 100 }]
+			.try_into()
+			.unwrap(),
+		auction_duration,
+		burn_ration,
+		mode: FeeDistributionMode::Auction,
+	};
 	assert_ok!(OCEX::set_fee_distribution(RuntimeOrigin::root(), fee_distribution));
 }
 
+pub fn create_buy_back_fee_config(max_slippage: u8) {
+	let recipient_address = AccountId32::new([1; 32]);
+	let auction_duration = 100;
+	let burn_ration = 50;
+	let fee_distribution = FeeDistribution {
+		recipients: vec![FeeRecipient { account: recipient_address, share: 100 }]
+			.try_into()
+			.unwrap(),
+		auction_duration,
+		burn_ration,
+		mode: FeeDistributionMode::BuyBack { max_slippage },
+	};
+	assert_ok!(OCEX::set_fee_distribution(RuntimeOrigin::root(), fee_distribution));
+}
+
+pub fn create_pool_with_liquidity(asset: AssetId) {
+	let admin = AccountId32::new([5; 32]);
+	let liquidity = 1_000_000 * UNIT_BALANCE;
+	Balances::mint_into(&admin, 2 * liquidity).unwrap();
+	Assets::mint_into(asset.asset_id().unwrap(), &admin, 2 * liquidity).unwrap();
+	assert_ok!(AssetConversion::create_pool(
+		RuntimeOrigin::signed(admin.clone()),
+		asset,
+		AssetId::Polkadex
+	));
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(admin.clone()),
+		asset,
+		AssetId::Polkadex,
+		liquidity,
+		liquidity,
+		1u128,
+		1u128,
+		admin
+	));
+}
+
 pub fn create_assets_and_mint_pot_account(assets: Vec<AssetId>) {
 	let pot_account = OCEX::get_pot_account();
 	// Mint Native token
@@ -2863,6 +3638,7 @@ pub fn add_lmp_config() {
 		min_maker_volume: UNIT_BALANCE,
 		max_spread: UNIT_BALANCE,
 		min_depth: UNIT_BALANCE,
+		reward_streams: vec![],
 	};
 	assert_ok!(OCEX::set_lmp_epoch_config(
 		RuntimeOrigin::root(),
@@ -2877,7 +3653,10 @@ pub fn add_lmp_config() {
 }
 
 use frame_support::traits::fungible::Mutate;
-use orderbook_primitives::lmp::LMPMarketConfigWrapper;
+use orderbook_primitives::lmp::{
+	LMPDepthLevel, LMPDepthSample, LMPEpochDuration, LMPMarketConfigWrapper, LMPVestingPolicy,
+	MAX_SCHEDULED_LMP_EPOCHS,
+};
 use polkadex_primitives::fees::FeeConfig;
 
 fn crete_base_and_quote_asset() {
@@ -39,6 +39,7 @@ use orderbook_primitives::ingress::{EgressMessages, IngressMessages};
 use orderbook_primitives::types::Order;
 use orderbook_primitives::{
 	constants::FEE_POT_PALLET_ID,
+	lmp::LMPDepthSample,
 	types::{
 		ApprovedSnapshot, Trade, TradingPair, UserActionBatch, UserActions, WithdrawalRequest,
 	},
@@ -417,6 +418,14 @@ impl<T: Config> Pallet<T> {
 					ref lp,
 					burn_frac,
 					_total_shares,
+				)
+				| IngressMessages::RemoveLiquidityInstantly(
+					market,
+					ref pool,
+					ref lp,
+					burn_frac,
+					_total_shares,
+					_,
 				) => {
 					let base_balance = get_balance(
 						state,
@@ -485,7 +494,8 @@ impl<T: Config> Pallet<T> {
 							)?;
 
 							// Egress message is verified
-							verified_egress_messages.push(engine_message.clone());
+							verified_egress_messages
+								.push(Self::tag_instant_removal(&message, engine_message.clone()));
 						},
 						EgressMessages::RemoveLiquidityFailed(
 							_market,
@@ -533,7 +543,8 @@ impl<T: Config> Pallet<T> {
 							}
 
 							// Egress message is verified
-							verified_egress_messages.push(engine_message.clone());
+							verified_egress_messages
+								.push(Self::tag_instant_removal(&message, engine_message.clone()));
 						},
 						_ => return Err("Invalid engine message"),
 					}
@@ -578,6 +589,31 @@ impl<T: Config> Pallet<T> {
 						quote_balance,
 					));
 				},
+				IngressMessages::ValuePool(market, pool, epoch, price, total_shares) => {
+					let base_balance = get_balance(
+						state,
+						&Decode::decode(&mut &pool.encode()[..])
+							.map_err(|_| "account id decode error")?,
+						market.base_asset,
+					)?;
+
+					let quote_balance = get_balance(
+						state,
+						&Decode::decode(&mut &pool.encode()[..])
+							.map_err(|_| "account id decode error")?,
+						market.quote_asset,
+					)?;
+
+					let total_inventory_in_quote =
+						quote_balance.saturating_add(price.saturating_mul(base_balance));
+					verified_egress_messages.push(EgressMessages::PoolValued(
+						market,
+						pool,
+						epoch,
+						total_inventory_in_quote,
+						total_shares,
+					));
+				},
 				IngressMessages::WithdrawTradingFees => {
 					let assets = <AllowlistedToken<T>>::get();
 					let pot_account: AccountId = FEE_POT_PALLET_ID.into_account_truncating();
@@ -662,6 +698,52 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Tags the engine result of an instant liquidity removal with its request id
+	fn tag_instant_removal(
+		message: &IngressMessages<T::AccountId>,
+		result: EgressMessages<T::AccountId>,
+	) -> EgressMessages<T::AccountId> {
+		let request_id = match message {
+			IngressMessages::RemoveLiquidityInstantly(.., request_id) => *request_id,
+			_ => return result,
+		};
+		match result {
+			EgressMessages::RemoveLiquidityResult(market, pool, lp, base_freed, quote_freed) => {
+				EgressMessages::RemoveLiquidityInstantlyResult(
+					market,
+					pool,
+					lp,
+					base_freed,
+					quote_freed,
+					request_id,
+				)
+			},
+			EgressMessages::RemoveLiquidityFailed(
+				market,
+				pool,
+				lp,
+				burn_frac,
+				total_shares,
+				base_free,
+				quote_free,
+				base_required,
+				quote_required,
+			) => EgressMessages::RemoveLiquidityInstantlyFailed(
+				market,
+				pool,
+				lp,
+				burn_frac,
+				total_shares,
+				base_free,
+				quote_free,
+				base_required,
+				quote_required,
+				request_id,
+			),
+			result => result,
+		}
+	}
+
 	/// Processes a withdrawal request, updating the account balances accordingly.
 	fn withdraw(
 		request: &WithdrawalRequest<T::AccountId>,
@@ -694,7 +776,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Processes a batch of user actions, updating the offchain state accordingly.
-	fn process_batch(
+	pub(crate) fn process_batch(
 		state: &mut OffchainState,
 		batch: &UserActionBatch<T::AccountId>,
 		state_info: &mut StateInfo,
@@ -731,8 +813,19 @@ impl<T: Config> Pallet<T> {
 					let withdrawal = Self::withdraw(request, state, *stid)?;
 					withdrawals.push(withdrawal);
 				},
-				UserActions::OneMinLMPReport(market, _total, scores) => {
+				// Scores of legacy reports can not be verified without a depth sample, so they
+				// are ignored
+				UserActions::OneMinLMPReport(..) => {},
+				UserActions::OneMinLMPReportV1(market, total, scores, sample) => {
 					let current_on_chain_epoch = <LMPEpoch<T>>::get();
+					Self::verify_q_scores(
+						state,
+						*market,
+						*total,
+						scores,
+						sample,
+						current_on_chain_epoch,
+					)?;
 					Self::store_q_scores(state, *market, scores, current_on_chain_epoch)?;
 				},
 			}
@@ -741,6 +834,31 @@ impl<T: Config> Pallet<T> {
 		Ok((withdrawals, egress_messages, trader_metrics))
 	}
 
+	/// Recomputes the Q scores of a one minute report from its depth sample and checks them
+	/// against the scores reported by the offchain engine
+	pub fn verify_q_scores(
+		state: &mut OffchainState,
+		market: TradingPair,
+		total: Decimal,
+		scores: &BTreeMap<T::AccountId, Decimal>,
+		sample: &LMPDepthSample<T::AccountId>,
+		current_on_chain_epoch: u16,
+	) -> Result<(), &'static str> {
+		let epoch = get_lmp_config(state, current_on_chain_epoch)?.epoch;
+		let config = <LMPConfig<T>>::get(epoch).ok_or("LMPConfig not defined for this epoch")?;
+		let market_config = config.config.get(&market).ok_or("Market not enabled for LMP")?;
+		let computed = sample.compute_q_scores(market_config.max_spread, market_config.min_depth);
+		if &computed != scores {
+			return Err("Q scores mismatch");
+		}
+		let computed_total =
+			computed.values().fold(Decimal::zero(), |acc, score| acc.saturating_add(*score));
+		if computed_total != total {
+			return Err("Total Q score mismatch");
+		}
+		Ok(())
+	}
+
 	/// Stores the Q scores generated by the offchain engine in the Offchain state trie
 	pub fn store_q_scores(
 		state: &mut OffchainState,
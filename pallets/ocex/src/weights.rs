@@ -337,6 +337,130 @@ impl<T: frame_system::Config> crate::OcexWeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `OCEX::FeeDistributionConfig` (r:1 w:1)
+	/// Proof: `OCEX::FeeDistributionConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_fee_recipients() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(16_750_000, 0)
+			.saturating_add(Weight::from_parts(0, 1594))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `OCEX::LMPClaimBlk` (r:1 w:0)
+	/// Proof: `OCEX::LMPClaimBlk` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::LMPRewardStreamsRefunded` (r:1 w:1)
+	/// Proof: `OCEX::LMPRewardStreamsRefunded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::LMPConfig` (r:1 w:0)
+	/// Proof: `OCEX::LMPConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::LMPRewardStreamsClaimed` (r:1 w:1)
+	/// Proof: `OCEX::LMPRewardStreamsClaimed` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn refund_lmp_reward_streams() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(114_860_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `OCEX::TradingPairs` (r:1 w:0)
+	/// Proof: `OCEX::TradingPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::NextLMPRewardStreamId` (r:1 w:1)
+	/// Proof: `OCEX::NextLMPRewardStreamId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::FundedLMPRewardStreams` (r:0 w:1)
+	/// Proof: `OCEX::FundedLMPRewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn fund_lmp_reward_stream() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(100_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `OCEX::FundedLMPRewardStreams` (r:1 w:1)
+	/// Proof: `OCEX::FundedLMPRewardStreams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn withdraw_lmp_reward_stream() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(100_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `OCEX::LMPEpoch` (r:1 w:0)
+	/// Proof: `OCEX::LMPEpoch` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::ScheduledLMPConfigs` (r:12 w:1)
+	/// Proof: `OCEX::ScheduledLMPConfigs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::TradingPairs` (r:1 w:0)
+	/// Proof: `OCEX::TradingPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::ExpectedLMPConfig` (r:1 w:0)
+	/// Proof: `OCEX::ExpectedLMPConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn schedule_lmp_epoch_config() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(99_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 30417))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `OCEX::ScheduledLMPConfigs` (r:1 w:1)
+	/// Proof: `OCEX::ScheduledLMPConfigs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_scheduled_lmp_config() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(22_210_000, 0)
+			.saturating_add(Weight::from_parts(0, 3741))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `OCEX::PendingLMPEpochDuration` (r:0 w:1)
+	/// Proof: `OCEX::PendingLMPEpochDuration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_lmp_epoch_duration() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(12_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `OCEX::ExpectedLMPConfig` (r:1 w:1)
+	/// Proof: `OCEX::ExpectedLMPConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_lmp_vesting_policy() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(20_630_000, 0)
+			.saturating_add(Weight::from_parts(0, 1726))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `OCEX::LMPClaimBlk` (r:20 w:0)
+	/// Proof: `OCEX::LMPClaimBlk` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::LMPConfig` (r:20 w:0)
+	/// Proof: `OCEX::LMPConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::TotalScores` (r:20 w:0)
+	/// Proof: `OCEX::TotalScores` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::TraderMetrics` (r:20 w:20)
+	/// Proof: `OCEX::TraderMetrics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OCEX::LMPRewardStreamsRefunded` (r:20 w:0)
+	/// Proof: `OCEX::LMPRewardStreamsRefunded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 20]`.
+	fn claim_lmp_rewards_multi(x: u32, ) -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(76_412_913, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 61_297
+			.saturating_add(Weight::from_parts(81_674_295, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2636).saturating_mul(x.into()))
+	}
 	/// Storage: `OCEX::Auction` (r:1 w:1)
 	/// Proof: `OCEX::Auction` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -0,0 +1,36 @@
+// This file is part of Polkadex.
+//
+// Copyright (c) 2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+use thea_primitives::{
+	types::{FailedDeposits, RateLimit},
+	Network,
+};
+
+sp_api::decl_runtime_apis! {
+	pub trait TheaExecutorRuntimeApi<AccountId> where AccountId: Codec {
+		// Returns the deposits of the network that couldn't be executed by incoming nonce
+		fn get_failed_deposits(network: Network) -> Vec<(u64, FailedDeposits<AccountId>)>;
+		// Returns the rate limit of the asset with the amounts deposited and withdrawn within the
+		// current rolling window
+		fn get_rate_limit_usage(network: Network, asset_id: u128) -> Option<(RateLimit, u128, u128)>;
+	}
+}
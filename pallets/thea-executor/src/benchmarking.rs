@@ -30,7 +30,9 @@ use parity_scale_codec::Decode;
 use polkadex_primitives::UNIT_BALANCE;
 use sp_runtime::{traits::AccountIdConversion, SaturatedConversion};
 use sp_std::{boxed::Box, collections::btree_set::BTreeSet, vec, vec::Vec};
-use thea_primitives::types::{AssetMetadata, Deposit, Withdraw};
+use thea_primitives::types::{
+	AssetMetadata, Deposit, FailedDeposits, PendingReview, RateLimit, Withdraw,
+};
 use xcm::VersionedMultiLocation;
 
 fn create_deposit<T: Config>(recipient: T::AccountId) -> Vec<Deposit<T::AccountId>> {
@@ -186,6 +188,123 @@ benchmarks! {
 		let current_balance = <T as pallet::Config>::Assets::balance(asset_id.into(), &account);
 		assert_eq!(current_balance, 1_000_000_000_000_000u128.saturated_into());
 	}
+
+	retry_failed_deposits {
+		let x in 1 .. 100;
+		let account = account::<T::AccountId>("alice", 1, x);
+		let asset_id: <T as pallet::Config>::AssetId = 100u128.into();
+		let deposits = (0..x).map(|_| Deposit {
+			id: vec![],
+			recipient: account.clone(),
+			asset_id: 100,
+			amount: 1_000_000_000_000,
+			extra: vec![],
+		}).collect::<Vec<_>>();
+		let metadata = AssetMetadata::new(10).unwrap();
+		<Metadata<T>>::insert(100, metadata);
+		<T as pallet::Config>::Currency::mint_into(&account, 100_000_000_000_000u128.saturated_into()).unwrap();
+		let failed = FailedDeposits {
+			payload: vec![],
+			deposits,
+			error: Error::<T>::AssetNotRegistered.into(),
+		};
+		<FailedDepositsQueue<T>>::insert(1, 1, failed);
+	}: _(RawOrigin::Signed(account.clone()), 1, 1, x)
+	verify {
+		assert!(<FailedDepositsQueue<T>>::get(1, 1).is_none());
+		let current_balance = <T as pallet::Config>::Assets::balance(asset_id.into(), &account);
+		assert_eq!(current_balance, (x as u128 * 100_000_000_000_000u128).saturated_into());
+	}
+
+	refund_failed_deposit {
+		let account = account::<T::AccountId>("alice", 1, 1);
+		let deposit = Deposit {
+			id: vec![1],
+			recipient: account.clone(),
+			asset_id: 100,
+			amount: 1_000_000_000_000,
+			extra: vec![],
+		};
+		let failed = FailedDeposits {
+			payload: vec![],
+			deposits: vec![deposit],
+			error: Error::<T>::AssetNotRegistered.into(),
+		};
+		<FailedDepositsQueue<T>>::insert(1, 1, failed);
+	}: _(RawOrigin::Root, 1, 1, vec![1], vec![1; 20])
+	verify {
+		assert!(<FailedDepositsQueue<T>>::get(1, 1).is_none());
+		assert_eq!(<PendingWithdrawals<T>>::get(1).len(), 1);
+	}
+
+	set_rate_limit {
+		let limit = RateLimit { window: 100, deposit_cap: UNIT_BALANCE, withdrawal_cap: UNIT_BALANCE };
+	}: _(RawOrigin::Root, 1, 100, Some(limit))
+	verify {
+		assert_eq!(<RateLimits<T>>::get(1, 100), Some(limit));
+	}
+
+	approve_review {
+		let account = account::<T::AccountId>("alice", 1, 1);
+		let asset_id: <T as pallet::Config>::AssetId = 100u128.into();
+		let metadata = AssetMetadata::new(10).unwrap();
+		<Metadata<T>>::insert(100, metadata);
+		<T as pallet::Config>::Currency::mint_into(&account, 100_000_000_000_000u128.saturated_into()).unwrap();
+		let limit = RateLimit { window: 100, deposit_cap: 1, withdrawal_cap: 1 };
+		<RateLimits<T>>::insert(1, 100, limit);
+		let deposit = Deposit {
+			id: vec![1],
+			recipient: account.clone(),
+			asset_id: 100,
+			amount: 1_000_000_000_000,
+			extra: vec![],
+		};
+		<ReviewQueue<T>>::insert(0, PendingReview::Deposit { network: 1, nonce: 1, deposit });
+	}: _(RawOrigin::Root, 0)
+	verify {
+		assert!(<ReviewQueue<T>>::get(0).is_none());
+		let current_balance = <T as pallet::Config>::Assets::balance(asset_id.into(), &account);
+		assert_eq!(current_balance, 100_000_000_000_000u128.saturated_into());
+	}
+
+	reject_review {
+		let account = account::<T::AccountId>("alice", 1, 1);
+		let asset_id: <T as pallet::Config>::AssetId = 100u128.into();
+		<T as pallet::Config>::Currency::mint_into(&account, 100_000_000_000_000u128.saturated_into()).unwrap();
+		let withdraw = Withdraw {
+			id: vec![1],
+			asset_id: 100,
+			amount: 1_000_000_000_000,
+			destination: vec![1; 20],
+			is_blocked: false,
+			extra: vec![],
+		};
+		let thea_account = TheaExecutor::<T>::thea_account();
+		<T as pallet::Config>::Currency::mint_into(&thea_account, 100_000_000_000_000u128.saturated_into()).unwrap();
+		let review = PendingReview::Withdrawal {
+			network: 1,
+			user: account.clone(),
+			amount: 100_000_000_000_000,
+			withdraw,
+			pay_for_remaining: false,
+			fees: 1_000_000_000_000,
+		};
+		<ReviewQueue<T>>::insert(0, review);
+	}: _(RawOrigin::Root, 0)
+	verify {
+		assert!(<ReviewQueue<T>>::get(0).is_none());
+		let current_balance = <T as pallet::Config>::Assets::balance(asset_id.into(), &account);
+		assert_eq!(current_balance, 100_000_000_000_000u128.saturated_into());
+	}
+
+	fund_relayer_rewards {
+		let thea_account = TheaExecutor::<T>::thea_account();
+		<T as pallet::Config>::Currency::mint_into(&thea_account, 100_000_000_000_000u128.saturated_into()).unwrap();
+	}: _(RawOrigin::Root, 1_000_000_000_000)
+	verify {
+		let balance = <T as pallet::Config>::Currency::balance(&T::RelayerRewardsAccount::get());
+		assert_eq!(balance, 1_000_000_000_000u128.saturated_into());
+	}
 }
 
 #[cfg(test)]
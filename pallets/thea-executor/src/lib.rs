@@ -19,6 +19,10 @@
 //! # Thea executor pallet.
 //!
 //! Pallet performs operations with balance (e.g. withdraw, claim deposit and set withdraw fee).
+//!
+//! A `RelayerRewardsShare` of the fees of every queued withdrawal is paid to the account paying
+//! Thea relayer rewards. Governance can move more of the fees kept by Thea to it with
+//! `fund_relayer_rewards`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -44,6 +48,12 @@ pub trait TheaExecutorWeightInfo {
 	fn on_initialize(x: u32, y: u32) -> Weight;
 	fn burn_native_tokens() -> Weight;
 	fn claim_deposit(_r: u32) -> Weight;
+	fn retry_failed_deposits(x: u32) -> Weight;
+	fn refund_failed_deposit() -> Weight;
+	fn set_rate_limit() -> Weight;
+	fn approve_review() -> Weight;
+	fn reject_review() -> Weight;
+	fn fund_relayer_rewards() -> Weight;
 }
 
 #[frame_support::pallet]
@@ -63,11 +73,15 @@ pub mod pallet {
 	use pallet_asset_conversion::Swap;
 	use polkadex_primitives::{AssetId, Resolver};
 	use sp_core::{H160, H256};
-	use sp_runtime::{traits::AccountIdConversion, Saturating};
+	use sp_runtime::{traits::AccountIdConversion, Perbill, Saturating};
 	use sp_std::vec::Vec;
 	use thea_primitives::{
-		types::{AssetMetadata, Deposit, Withdraw},
-		Network, TheaBenchmarkHelper, TheaIncomingExecutor, TheaOutgoingExecutor, NATIVE_NETWORK,
+		types::{
+			AssetMetadata, Deposit, FailedDeposits, PendingReview, RateLimit, RateLimitUsage,
+			Withdraw,
+		},
+		Network, TheaBenchmarkHelper, TheaIncomingExecutor, TheaOutgoingExecutor, TheaPauseSwitch,
+		NATIVE_NETWORK,
 	};
 	use xcm::VersionedMultiLocation;
 
@@ -129,6 +143,14 @@ pub mod pallet {
 		type ParaId: Get<u32>;
 		/// Governance Origin
 		type GovernanceOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Pause switches of networks and assets
+		type PauseSwitch: thea_primitives::TheaPauseSwitch;
+		/// Account paying Thea relayer rewards, funded from the collected withdrawal fees
+		#[pallet::constant]
+		type RelayerRewardsAccount: Get<Self::AccountId>;
+		/// Share of the fees of every queued withdrawal paid to `RelayerRewardsAccount`
+		#[pallet::constant]
+		type RelayerRewardsShare: Get<Perbill>;
 		/// Type representing the weight of this pallet
 		type TheaExecWeightInfo: TheaExecutorWeightInfo;
 	}
@@ -172,6 +194,68 @@ pub mod pallet {
 	#[pallet::getter(fn asset_metadata)]
 	pub type Metadata<T: Config> = StorageMap<_, Identity, u128, AssetMetadata, OptionQuery>;
 
+	/// Deposits that couldn't be executed ( network, incoming nonce => FailedDeposits )
+	#[pallet::storage]
+	#[pallet::getter(fn failed_deposits)]
+	pub type FailedDepositsQueue<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Network,
+		Blake2_128Concat,
+		u64,
+		FailedDeposits<T::AccountId>,
+		OptionQuery,
+	>;
+
+	/// Failed deposits waiting for the metadata of an asset ( asset_id => [(network, nonce)] )
+	#[pallet::storage]
+	#[pallet::getter(fn awaiting_asset_metadata)]
+	pub(super) type AwaitingAssetMetadata<T: Config> =
+		StorageMap<_, Identity, u128, Vec<(Network, u64)>, ValueQuery>;
+
+	/// Rate limits of assets ( network, asset_id => RateLimit )
+	#[pallet::storage]
+	#[pallet::getter(fn rate_limits)]
+	pub type RateLimits<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, Network, Identity, u128, RateLimit, OptionQuery>;
+
+	/// Amounts moved through the bridge for rate limited assets ( network, asset_id => usage )
+	#[pallet::storage]
+	#[pallet::getter(fn rate_limit_usage)]
+	pub type RateLimitUsages<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, Network, Identity, u128, RateLimitUsage, ValueQuery>;
+
+	/// Deposits and withdrawals over the rate limit waiting for governance review
+	#[pallet::storage]
+	#[pallet::getter(fn review_queue)]
+	pub type ReviewQueue<T: Config> =
+		StorageMap<_, Identity, u64, PendingReview<T::AccountId>, OptionQuery>;
+
+	/// Identifier of the next operation put under review
+	#[pallet::storage]
+	#[pallet::getter(fn next_review_id)]
+	pub(super) type NextReviewId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Failed deposits waiting for the ingress of an asset to be resumed
+	/// ( asset_id => [(network, nonce)] )
+	#[pallet::storage]
+	#[pallet::getter(fn awaiting_asset_resume)]
+	pub(super) type AwaitingAssetResume<T: Config> =
+		StorageMap<_, Identity, u128, Vec<(Network, u64)>, ValueQuery>;
+
+	/// Failed deposits waiting for the ingress of a network to be resumed
+	/// ( network => [nonce] )
+	#[pallet::storage]
+	#[pallet::getter(fn awaiting_network_resume)]
+	pub(super) type AwaitingNetworkResume<T: Config> =
+		StorageMap<_, Identity, Network, Vec<u64>, ValueQuery>;
+
+	/// Failed deposits to be retried automatically when the block has spare weight
+	#[pallet::storage]
+	#[pallet::getter(fn failed_deposits_retry_queue)]
+	pub(super) type FailedDepositsRetryQueue<T: Config> =
+		StorageValue<_, Vec<(Network, u64)>, ValueQuery>;
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
@@ -185,6 +269,22 @@ pub mod pallet {
 		DepositClaimed(T::AccountId, u128, u128, Vec<u8>),
 		/// Deposit failed event ( network, encoded deposit)
 		DepositFailed(Network, Vec<u8>),
+		/// Failed deposits retried ( network, incoming nonce, deposits still failing )
+		FailedDepositsRetried(Network, u64, u32),
+		/// Failed deposit refunded to the foreign chain ( network, incoming nonce, deposit id,
+		/// beneficiary )
+		FailedDepositRefunded(Network, u64, Vec<u8>, Vec<u8>),
+		/// Rate limit updated ( network, asset id, rate limit )
+		RateLimitSet(Network, u128, Option<RateLimit>),
+		/// Deposit over the rate limit held back for review ( review id, network, deposit id )
+		DepositUnderReview(u64, Network, Vec<u8>),
+		/// Withdrawal over the rate limit held back for review ( review id, network,
+		/// withdrawal id )
+		WithdrawalUnderReview(u64, Network, Vec<u8>),
+		/// Operation under review approved and executed ( review id )
+		ReviewApproved(u64),
+		/// Operation under review rejected ( review id )
+		ReviewRejected(u64),
 		/// Withdrawal Queued ( network, from, beneficiary, assetId, amount, id )
 		WithdrawalQueued(Network, T::AccountId, Vec<u8>, u128, u128, Vec<u8>),
 		/// Withdrawal Ready (Network id )
@@ -199,6 +299,10 @@ pub mod pallet {
 		NativeTokenBurned(T::AccountId, u128),
 		/// Withdrawal Sent (Network, Withdrawal Id,Batch Outgoing Nonce, Withdrawal Index)
 		WithdrawalSent(Network, Vec<u8>, u64, u8),
+		/// Failed deposits queued for retry or refund ( network, incoming nonce, error )
+		FailedDepositsQueued(Network, u64, DispatchError),
+		/// Withdrawal fees moved to the relayer rewards account ( amount )
+		RelayerRewardsFunded(u128),
 	}
 
 	// Errors inform users that something went wrong.
@@ -236,17 +340,50 @@ pub mod pallet {
 		WrongNetwork,
 		/// Not able to get price for fee swap
 		CannotSwapForFees,
+		/// No failed deposits stored for the given network and nonce
+		FailedDepositNotFound,
+		/// Caller is not the recipient of the failed deposit
+		NotDepositRecipient,
+		/// Rate limit window cannot be zero
+		InvalidRateLimit,
+		/// No operation under review with the given id
+		ReviewNotFound,
+		/// Withdrawals to the network are paused
+		NetworkEgressPaused,
+		/// Withdrawals of the asset are paused
+		AssetEgressPaused,
+		/// Deposits from the network are paused
+		NetworkIngressPaused,
+		/// Deposits of the asset are paused
+		AssetIngressPaused,
+		/// Deposit rejected by governance review
+		DepositRejected,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(block_no: BlockNumberFor<T>) -> Weight {
 			let pending_withdrawals =
-				<ReadyWithdrawals<T>>::iter_prefix(block_no.saturating_sub(1u8.into()));
+				<ReadyWithdrawals<T>>::iter_prefix(block_no.saturating_sub(1u8.into()))
+					.collect::<Vec<_>>();
 			let mut withdrawal_len = 0;
 			let mut network_len = 0;
+			let mut paused_len = 0u64;
+			let pause_checks = pending_withdrawals.len() as u64;
 			for (network_id, withdrawals) in pending_withdrawals {
 				withdrawal_len += withdrawals.len();
+				if T::PauseSwitch::network_pause(network_id).map_or(false, |state| state.egress()) {
+					// Move the batch to this block until the egress of the network is resumed
+					let withdrawals = <ReadyWithdrawals<T>>::take(
+						block_no.saturating_sub(1u8.into()),
+						network_id,
+					);
+					<ReadyWithdrawals<T>>::mutate(block_no, network_id, |ready| {
+						ready.extend(withdrawals)
+					});
+					paused_len += 1;
+					continue;
+				}
 				let batch_nonce = T::Executor::get_outgoing_nonce(network_id);
 				for (index, withdrawal) in withdrawals.iter().enumerate() {
 					Self::deposit_event(Event::<T>::WithdrawalSent(
@@ -265,6 +402,34 @@ pub mod pallet {
 				network_len += 1;
 			}
 			T::TheaExecWeightInfo::on_initialize(network_len as u32, withdrawal_len as u32)
+				.saturating_add(
+					T::DbWeight::get()
+						.reads_writes(pause_checks.saturating_add(paused_len), 2 * paused_len),
+				)
+		}
+
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut queue = <FailedDepositsRetryQueue<T>>::get();
+			let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+			if queue.is_empty() || remaining_weight.any_lt(consumed) {
+				return T::DbWeight::get().reads(1);
+			}
+			while let Some((network, nonce)) = queue.first().copied() {
+				let len = <FailedDepositsQueue<T>>::get(network, nonce)
+					.map(|failed| failed.deposits.len())
+					.unwrap_or_default();
+				let weight = T::TheaExecWeightInfo::retry_failed_deposits(len.saturated_into());
+				if remaining_weight.any_lt(consumed.saturating_add(weight)) {
+					break;
+				}
+				consumed.saturating_accrue(weight);
+				queue.remove(0);
+				if let Err(err) = Self::do_retry_failed_deposits(network, nonce, None, u32::MAX) {
+					log::warn!(target:"thea","Retry of failed deposits {:?} {:?} failed: {:?}", network, nonce, err);
+				}
+			}
+			<FailedDepositsRetryQueue<T>>::put(queue);
+			consumed
 		}
 	}
 
@@ -356,6 +521,8 @@ pub mod pallet {
 			T::GovernanceOrigin::ensure_origin(origin)?;
 			let metadata = AssetMetadata::new(decimal).ok_or(Error::<T>::InvalidDecimal)?;
 			<Metadata<T>>::insert(asset_id, metadata);
+			// Deposits that failed because the asset was unknown can be executed now
+			Self::schedule_retries(<AwaitingAssetMetadata<T>>::take(asset_id));
 			Self::deposit_event(Event::<T>::AssetMetadataSet(metadata));
 			Ok(())
 		}
@@ -458,6 +625,242 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Retries the failed deposits of an incoming message.
+		///
+		/// Governance retries every failed deposit, a signed origin only the ones it is the
+		/// recipient of.
+		///
+		/// # Parameters
+		///
+		/// * `network`: Network the deposits came from.
+		/// * `nonce`: Nonce of the incoming message.
+		/// * `max_deposits`: Maximum number of deposits to retry.
+		#[pallet::call_index(7)]
+		#[pallet::weight(< T as Config >::TheaExecWeightInfo::retry_failed_deposits(*max_deposits))]
+		pub fn retry_failed_deposits(
+			origin: OriginFor<T>,
+			network: Network,
+			nonce: u64,
+			max_deposits: u32,
+		) -> DispatchResult {
+			let recipient = Self::ensure_governance_or_signed(origin)?;
+			let (executed, _) =
+				Self::do_retry_failed_deposits(network, nonce, recipient.as_ref(), max_deposits)?;
+			if executed == 0 {
+				// Nothing changed, surface the reason to the caller
+				let failed = <FailedDepositsQueue<T>>::get(network, nonce)
+					.ok_or(Error::<T>::FailedDepositNotFound)?;
+				return Err(failed.error);
+			}
+			Ok(())
+		}
+
+		/// Refunds a failed deposit to a beneficiary on the network it came from.
+		///
+		/// # Parameters
+		///
+		/// * `network`: Network the deposit came from.
+		/// * `nonce`: Nonce of the incoming message.
+		/// * `deposit_id`: Identifier of the deposit.
+		/// * `beneficiary`: Beneficiary address on the foreign chain.
+		#[pallet::call_index(8)]
+		#[pallet::weight(< T as Config >::TheaExecWeightInfo::refund_failed_deposit())]
+		pub fn refund_failed_deposit(
+			origin: OriginFor<T>,
+			network: Network,
+			nonce: u64,
+			deposit_id: Vec<u8>,
+			beneficiary: Vec<u8>,
+		) -> DispatchResult {
+			let recipient = Self::ensure_governance_or_signed(origin)?;
+			ensure!(beneficiary.len() <= 1000, Error::<T>::BeneficiaryTooLong);
+			let mut failed = <FailedDepositsQueue<T>>::get(network, nonce)
+				.ok_or(Error::<T>::FailedDepositNotFound)?;
+			let index = failed
+				.deposits
+				.iter()
+				.position(|deposit| deposit.id == deposit_id)
+				.ok_or(Error::<T>::FailedDepositNotFound)?;
+			if let Some(recipient) = recipient {
+				ensure!(
+					failed.deposits[index].recipient == recipient,
+					Error::<T>::NotDepositRecipient
+				);
+			}
+			let deposit = failed.deposits.remove(index);
+			if failed.deposits.is_empty() {
+				<FailedDepositsQueue<T>>::remove(network, nonce);
+			} else {
+				<FailedDepositsQueue<T>>::insert(network, nonce, failed);
+			}
+			// Deposit amount is already in the decimals of the foreign chain
+			let withdraw = Withdraw {
+				id: deposit.id.clone(),
+				asset_id: deposit.asset_id,
+				amount: deposit.amount,
+				destination: beneficiary.clone(),
+				is_blocked: false,
+				extra: Vec::new(),
+			};
+			Self::queue_withdrawal(network, withdraw, false)?;
+			Self::deposit_event(Event::<T>::FailedDepositRefunded(
+				network,
+				nonce,
+				deposit.id,
+				beneficiary,
+			));
+			Ok(())
+		}
+
+		/// Sets or removes the rate limit of an asset on a network.
+		///
+		/// # Parameters
+		///
+		/// * `network`: Network Id.
+		/// * `asset_id`: Asset Id.
+		/// * `limit`: Caps in native decimals over a rolling window, `None` to remove them.
+		#[pallet::call_index(9)]
+		#[pallet::weight(< T as Config >::TheaExecWeightInfo::set_rate_limit())]
+		pub fn set_rate_limit(
+			origin: OriginFor<T>,
+			network: Network,
+			asset_id: u128,
+			limit: Option<RateLimit>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			match limit {
+				Some(limit) => {
+					ensure!(limit.window > 0, Error::<T>::InvalidRateLimit);
+					<RateLimits<T>>::insert(network, asset_id, limit);
+				},
+				None => {
+					<RateLimits<T>>::remove(network, asset_id);
+					<RateLimitUsages<T>>::remove(network, asset_id);
+				},
+			}
+			Self::deposit_event(Event::<T>::RateLimitSet(network, asset_id, limit));
+			Ok(())
+		}
+
+		/// Executes a deposit or queues a withdrawal held back by the rate limit.
+		///
+		/// # Parameters
+		///
+		/// * `review_id`: Identifier of the operation under review.
+		#[pallet::call_index(10)]
+		#[pallet::weight(< T as Config >::TheaExecWeightInfo::approve_review())]
+		pub fn approve_review(origin: OriginFor<T>, review_id: u64) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let review = <ReviewQueue<T>>::take(review_id).ok_or(Error::<T>::ReviewNotFound)?;
+			match review {
+				PendingReview::Deposit { network, deposit, .. } => {
+					let metadata = <Metadata<T>>::get(deposit.asset_id)
+						.ok_or(Error::<T>::AssetNotRegistered)?;
+					Self::execute_deposit(deposit.clone())?;
+					Self::record_rate_limit_usage(
+						network,
+						deposit.asset_id,
+						deposit.amount_in_native_decimals(metadata),
+						true,
+					);
+					Self::deposit_event(Event::<T>::DepositApproved(
+						network,
+						deposit.recipient,
+						deposit.asset_id,
+						deposit.amount,
+						deposit.id,
+					));
+				},
+				PendingReview::Withdrawal {
+					network,
+					user,
+					amount,
+					withdraw,
+					pay_for_remaining,
+					fees,
+				} => {
+					Self::record_rate_limit_usage(network, withdraw.asset_id, amount, false);
+					Self::pay_relayer_rewards_share(fees);
+					Self::deposit_event(Event::<T>::WithdrawalQueued(
+						network,
+						user,
+						withdraw.destination.clone(),
+						withdraw.asset_id,
+						amount,
+						withdraw.id.clone(),
+					));
+					Self::queue_withdrawal(network, withdraw, pay_for_remaining)?;
+				},
+			}
+			Self::deposit_event(Event::<T>::ReviewApproved(review_id));
+			Ok(())
+		}
+
+		/// Moves a deposit held back by the rate limit to the failed deposits, so that it can be
+		/// refunded, or returns the funds and fees of a withdrawal held back by the rate limit to
+		/// the user.
+		///
+		/// # Parameters
+		///
+		/// * `review_id`: Identifier of the operation under review.
+		#[pallet::call_index(11)]
+		#[pallet::weight(< T as Config >::TheaExecWeightInfo::reject_review())]
+		pub fn reject_review(origin: OriginFor<T>, review_id: u64) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let review = <ReviewQueue<T>>::take(review_id).ok_or(Error::<T>::ReviewNotFound)?;
+			match review {
+				PendingReview::Deposit { network, nonce, deposit } => {
+					let mut failed =
+						<FailedDepositsQueue<T>>::get(network, nonce).unwrap_or(FailedDeposits {
+							payload: Vec::new(),
+							deposits: Vec::new(),
+							error: Error::<T>::DepositRejected.into(),
+						});
+					failed.deposits.push(deposit);
+					<FailedDepositsQueue<T>>::insert(network, nonce, failed);
+				},
+				PendingReview::Withdrawal { user, amount, withdraw, fees, .. } => {
+					Self::resolver_deposit(
+						withdraw.asset_id.into(),
+						amount,
+						&user,
+						Self::thea_account(),
+						1u128,
+						Self::thea_account(),
+					)?;
+					// Fees are refunded in the native token, whatever they were paid with
+					<T as Config>::Currency::transfer(
+						&Self::thea_account(),
+						&user,
+						fees.saturated_into(),
+						Preservation::Preserve,
+					)?;
+				},
+			}
+			Self::deposit_event(Event::<T>::ReviewRejected(review_id));
+			Ok(())
+		}
+
+		/// Moves withdrawal fees collected by Thea to the account paying relayer rewards, on top
+		/// of the `RelayerRewardsShare` of every withdrawal fee it receives.
+		///
+		/// # Parameters
+		///
+		/// * `amount`: Amount of native tokens to move.
+		#[pallet::call_index(12)]
+		#[pallet::weight(< T as Config >::TheaExecWeightInfo::fund_relayer_rewards())]
+		pub fn fund_relayer_rewards(origin: OriginFor<T>, amount: u128) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			<T as Config>::Currency::transfer(
+				&Self::thea_account(),
+				&T::RelayerRewardsAccount::get(),
+				amount.saturated_into(),
+				Preservation::Preserve,
+			)?;
+			Self::deposit_event(Event::<T>::RelayerRewardsFunded(amount));
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -474,6 +877,26 @@ pub mod pallet {
 			T::TheaPalletId::get().into_account_truncating()
 		}
 
+		/// Pays the `RelayerRewardsShare` of the fees of a queued withdrawal to the relayer
+		/// rewards account, the fees stay with Thea if the share can not be transferred
+		fn pay_relayer_rewards_share(fees: u128) {
+			let share = T::RelayerRewardsShare::get() * fees;
+			if share == 0 {
+				return;
+			}
+			match <T as Config>::Currency::transfer(
+				&Self::thea_account(),
+				&T::RelayerRewardsAccount::get(),
+				share.saturated_into(),
+				Preservation::Preserve,
+			) {
+				Ok(_) => Self::deposit_event(Event::<T>::RelayerRewardsFunded(share)),
+				Err(err) => {
+					log::warn!(target:"thea","Relayer rewards share {:?} not paid: {:?}", share, err)
+				},
+			}
+		}
+
 		#[transactional]
 		pub fn do_withdraw(
 			user: T::AccountId,
@@ -486,7 +909,15 @@ pub mod pallet {
 		) -> Result<(), DispatchError> {
 			ensure!(beneficiary.len() <= 1000, Error::<T>::BeneficiaryTooLong);
 			ensure!(network != 0, Error::<T>::WrongNetwork);
-			let mut pending_withdrawals = <PendingWithdrawals<T>>::get(network);
+			ensure!(
+				!T::PauseSwitch::network_pause(network).map_or(false, |state| state.egress()),
+				Error::<T>::NetworkEgressPaused
+			);
+			ensure!(
+				!T::PauseSwitch::asset_pause(asset_id).map_or(false, |state| state.egress()),
+				Error::<T>::AssetEgressPaused
+			);
+			let pending_withdrawals = <PendingWithdrawals<T>>::get(network);
 			let metadata = <Metadata<T>>::get(asset_id).ok_or(Error::<T>::AssetNotRegistered)?;
 			ensure!(
 				pending_withdrawals.len() < T::WithdrawalSize::get() as usize,
@@ -532,9 +963,6 @@ pub mod pallet {
 				)?;
 			}
 
-			// Withdraw assets
-			Self::resolver_withdraw(asset_id.into(), amount, &user, Self::thea_account())?;
-
 			let mut withdraw = Withdraw {
 				id: Self::new_random_id(),
 				asset_id,
@@ -544,6 +972,32 @@ pub mod pallet {
 				extra: Vec::new(),
 			};
 
+			// Convert back to origin decimals
+			withdraw.amount = metadata.convert_from_native_decimals(amount);
+			// Withdrawals failing to be sent later would lose the burned assets
+			T::Executor::validate_withdrawal(network, &withdraw)?;
+
+			// Withdraw assets
+			Self::resolver_withdraw(asset_id.into(), amount, &user, Self::thea_account())?;
+
+			if Self::rate_limit_exceeded(network, asset_id, amount, false) {
+				let review_id = Self::put_under_review(PendingReview::Withdrawal {
+					network,
+					user,
+					amount,
+					withdraw: withdraw.clone(),
+					pay_for_remaining,
+					fees: total_fees,
+				});
+				Self::deposit_event(Event::<T>::WithdrawalUnderReview(
+					review_id,
+					network,
+					withdraw.id,
+				));
+				return Ok(());
+			}
+			Self::record_rate_limit_usage(network, asset_id, amount, false);
+			Self::pay_relayer_rewards_share(total_fees);
 			Self::deposit_event(Event::<T>::WithdrawalQueued(
 				network,
 				user,
@@ -552,19 +1006,91 @@ pub mod pallet {
 				amount,
 				withdraw.id.clone(),
 			));
+			Self::queue_withdrawal(network, withdraw, pay_for_remaining)
+		}
 
-			// Convert back to origin decimals
-			withdraw.amount = metadata.convert_from_native_decimals(amount);
+		/// Returns true if moving `amount` (native decimals) of the asset would exceed its cap
+		/// within the rolling window.
+		fn rate_limit_exceeded(
+			network: Network,
+			asset_id: u128,
+			amount: u128,
+			is_deposit: bool,
+		) -> bool {
+			let Some(limit) = <RateLimits<T>>::get(network, asset_id) else { return false };
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let (deposited, withdrawn) =
+				<RateLimitUsages<T>>::get(network, asset_id).rolling_usage(now, limit.window);
+			if is_deposit {
+				deposited.saturating_add(amount) > limit.deposit_cap
+			} else {
+				withdrawn.saturating_add(amount) > limit.withdrawal_cap
+			}
+		}
 
+		/// Accounts `amount` (native decimals) in the usage of a rate limited asset
+		fn record_rate_limit_usage(
+			network: Network,
+			asset_id: u128,
+			amount: u128,
+			is_deposit: bool,
+		) {
+			let Some(limit) = <RateLimits<T>>::get(network, asset_id) else { return };
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			<RateLimitUsages<T>>::mutate(network, asset_id, |usage| {
+				usage.roll(now, limit.window);
+				if is_deposit {
+					usage.deposited = usage.deposited.saturating_add(amount);
+				} else {
+					usage.withdrawn = usage.withdrawn.saturating_add(amount);
+				}
+			});
+		}
+
+		/// Stores the operation for governance review and returns its id
+		fn put_under_review(review: PendingReview<T::AccountId>) -> u64 {
+			let review_id = <NextReviewId<T>>::mutate(|id| {
+				let review_id = *id;
+				*id = id.saturating_add(1);
+				review_id
+			});
+			<ReviewQueue<T>>::insert(review_id, review);
+			review_id
+		}
+
+		/// Returns the rate limit of the asset with the amounts (deposited, withdrawn) within the
+		/// current rolling window.
+		pub fn get_rate_limit_usage(
+			network: Network,
+			asset_id: u128,
+		) -> Option<(RateLimit, u128, u128)> {
+			let limit = <RateLimits<T>>::get(network, asset_id)?;
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let (deposited, withdrawn) =
+				<RateLimitUsages<T>>::get(network, asset_id).rolling_usage(now, limit.window);
+			Some((limit, deposited, withdrawn))
+		}
+
+		/// Adds the withdrawal to the pending batch of the network and moves the batch to the
+		/// ready queue once it is full or `force_ready` is set.
+		fn queue_withdrawal(
+			network: Network,
+			withdraw: Withdraw,
+			force_ready: bool,
+		) -> Result<(), DispatchError> {
+			let mut pending_withdrawals = <PendingWithdrawals<T>>::get(network);
+			ensure!(
+				pending_withdrawals.len() < T::WithdrawalSize::get() as usize,
+				Error::<T>::WithdrawalNotAllowed
+			);
 			pending_withdrawals.push(withdraw);
 
-			if (pending_withdrawals.len() >= T::WithdrawalSize::get() as usize) || pay_for_remaining
-			{
+			if (pending_withdrawals.len() >= T::WithdrawalSize::get() as usize) || force_ready {
 				// If it is full then we move it to ready queue and update withdrawal nonce
-				<ReadyWithdrawals<T>>::insert(
+				<ReadyWithdrawals<T>>::mutate(
 					<frame_system::Pallet<T>>::block_number(), //Block No
 					network,
-					pending_withdrawals.clone(),
+					|ready| ready.extend(pending_withdrawals.clone()),
 				);
 				Self::deposit_event(Event::<T>::WithdrawalReady(network));
 				pending_withdrawals = Vec::default();
@@ -573,24 +1099,6 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[transactional]
-		pub fn do_deposit(network: Network, payload: &[u8]) -> Result<(), DispatchError> {
-			let deposits: Vec<Deposit<T::AccountId>> =
-				Decode::decode(&mut &payload[..]).map_err(|_| Error::<T>::FailedToDecode)?;
-			for deposit in deposits {
-				// Execute Deposit
-				Self::execute_deposit(deposit.clone())?;
-				Self::deposit_event(Event::<T>::DepositApproved(
-					network,
-					deposit.recipient,
-					deposit.asset_id,
-					deposit.amount,
-					deposit.id,
-				))
-			}
-			Ok(())
-		}
-
 		#[transactional]
 		pub fn execute_deposit(deposit: Deposit<T::AccountId>) -> Result<(), DispatchError> {
 			// Get the metadata
@@ -644,13 +1152,250 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> Pallet<T> {
+		/// Returns `None` for the governance origin and the account of a signed origin.
+		fn ensure_governance_or_signed(
+			origin: OriginFor<T>,
+		) -> Result<Option<T::AccountId>, DispatchError> {
+			match T::GovernanceOrigin::try_origin(origin) {
+				Ok(_) => Ok(None),
+				Err(origin) => Ok(Some(ensure_signed(origin)?)),
+			}
+		}
+
+		/// Queues failed deposits for an automatic retry
+		fn schedule_retries(keys: Vec<(Network, u64)>) {
+			if keys.is_empty() {
+				return;
+			}
+			<FailedDepositsRetryQueue<T>>::mutate(|queue| {
+				for key in keys {
+					if !queue.contains(&key) {
+						queue.push(key);
+					}
+				}
+			});
+		}
+
+		/// Returns the error to fail deposits of the asset with if its ingress is paused
+		fn ingress_paused(network: Network, asset_id: u128) -> Option<Error<T>> {
+			if T::PauseSwitch::network_pause(network).map_or(false, |state| state.ingress()) {
+				return Some(Error::<T>::NetworkIngressPaused);
+			}
+			if T::PauseSwitch::asset_pause(asset_id).map_or(false, |state| state.ingress()) {
+				return Some(Error::<T>::AssetIngressPaused);
+			}
+			None
+		}
+
+		/// Executes the deposits one by one and returns the failed ones with the last error.
+		fn try_execute_deposits(
+			network: Network,
+			nonce: u64,
+			deposits: Vec<Deposit<T::AccountId>>,
+		) -> (Vec<Deposit<T::AccountId>>, Option<DispatchError>) {
+			let mut failed = Vec::new();
+			let mut last_error = None;
+			for deposit in deposits {
+				if let Some(error) = Self::ingress_paused(network, deposit.asset_id) {
+					match error {
+						Error::<T>::NetworkIngressPaused => {
+							<AwaitingNetworkResume<T>>::mutate(network, |awaiting| {
+								if !awaiting.contains(&nonce) {
+									awaiting.push(nonce);
+								}
+							});
+						},
+						_ => {
+							<AwaitingAssetResume<T>>::mutate(deposit.asset_id, |awaiting| {
+								if !awaiting.contains(&(network, nonce)) {
+									awaiting.push((network, nonce));
+								}
+							});
+						},
+					}
+					failed.push(deposit);
+					last_error = Some(error.into());
+					continue;
+				}
+				// Amount is only known once the asset is registered, execution fails until then
+				let amount = <Metadata<T>>::get(deposit.asset_id)
+					.map(|metadata| deposit.amount_in_native_decimals(metadata));
+				if let Some(amount) = amount {
+					if Self::rate_limit_exceeded(network, deposit.asset_id, amount, true) {
+						let deposit_id = deposit.id.clone();
+						let review_id = Self::put_under_review(PendingReview::Deposit {
+							network,
+							nonce,
+							deposit,
+						});
+						Self::deposit_event(Event::<T>::DepositUnderReview(
+							review_id, network, deposit_id,
+						));
+						continue;
+					}
+				}
+				match Self::execute_deposit(deposit.clone()) {
+					Ok(()) => {
+						Self::record_rate_limit_usage(
+							network,
+							deposit.asset_id,
+							amount.unwrap_or_default(),
+							true,
+						);
+						Self::deposit_event(Event::<T>::DepositApproved(
+							network,
+							deposit.recipient,
+							deposit.asset_id,
+							deposit.amount,
+							deposit.id,
+						))
+					},
+					Err(error) => {
+						if error == Error::<T>::AssetNotRegistered.into() {
+							<AwaitingAssetMetadata<T>>::mutate(deposit.asset_id, |awaiting| {
+								if !awaiting.contains(&(network, nonce)) {
+									awaiting.push((network, nonce));
+								}
+							});
+						}
+						failed.push(deposit);
+						last_error = Some(error);
+					},
+				}
+			}
+			(failed, last_error)
+		}
+
+		/// Retries up to `max_deposits` failed deposits of the incoming message, only the ones of
+		/// `recipient` if given. Returns the number of executed and still failing deposits.
+		pub fn do_retry_failed_deposits(
+			network: Network,
+			nonce: u64,
+			recipient: Option<&T::AccountId>,
+			max_deposits: u32,
+		) -> Result<(u32, u32), DispatchError> {
+			let mut failed = <FailedDepositsQueue<T>>::get(network, nonce)
+				.ok_or(Error::<T>::FailedDepositNotFound)?;
+			if failed.deposits.is_empty() && recipient.is_none() {
+				// Payload couldn't be decoded before, it may be after a runtime upgrade
+				let deposits: Vec<Deposit<T::AccountId>> = Decode::decode(&mut &failed.payload[..])
+					.map_err(|_| Error::<T>::FailedToDecode)?;
+				failed.payload = Vec::new();
+				failed.deposits = deposits;
+			}
+
+			let mut to_retry = Vec::new();
+			let mut skipped = Vec::new();
+			for deposit in failed.deposits {
+				if to_retry.len() < max_deposits as usize
+					&& recipient.map_or(true, |recipient| deposit.recipient == *recipient)
+				{
+					to_retry.push(deposit);
+				} else {
+					skipped.push(deposit);
+				}
+			}
+			ensure!(!to_retry.is_empty(), Error::<T>::NotDepositRecipient);
+			let retried = to_retry.len();
+
+			let (mut still_failing, error) = Self::try_execute_deposits(network, nonce, to_retry);
+			let executed = retried.saturating_sub(still_failing.len());
+			still_failing.append(&mut skipped);
+			let remaining = still_failing.len();
+			if still_failing.is_empty() {
+				<FailedDepositsQueue<T>>::remove(network, nonce);
+			} else {
+				<FailedDepositsQueue<T>>::insert(
+					network,
+					nonce,
+					FailedDeposits {
+						payload: Vec::new(),
+						deposits: still_failing,
+						error: error.unwrap_or(failed.error),
+					},
+				);
+			}
+			Self::deposit_event(Event::<T>::FailedDepositsRetried(
+				network,
+				nonce,
+				remaining.saturated_into(),
+			));
+			Ok((executed.saturated_into(), remaining.saturated_into()))
+		}
+
+		/// Stores the deposits that couldn't be executed so that they can be retried or refunded
+		fn store_failed_deposits(
+			network: Network,
+			nonce: u64,
+			failed: FailedDeposits<T::AccountId>,
+		) {
+			log::error!(target:"thea","Deposit Failed : {:?}", failed.error);
+			let encoded = if failed.payload.is_empty() {
+				failed.deposits.encode()
+			} else {
+				failed.payload.clone()
+			};
+			Self::deposit_event(Event::<T>::DepositFailed(network, encoded));
+			Self::deposit_event(Event::<T>::FailedDepositsQueued(network, nonce, failed.error));
+			<FailedDepositsQueue<T>>::insert(network, nonce, failed);
+		}
+
+		/// Returns the failed deposits of the network by incoming nonce
+		pub fn get_failed_deposits(network: Network) -> Vec<(u64, FailedDeposits<T::AccountId>)> {
+			<FailedDepositsQueue<T>>::iter_prefix(network).collect()
+		}
+	}
+
 	impl<T: Config> TheaIncomingExecutor for Pallet<T> {
-		fn execute_deposits(network: Network, deposits: Vec<u8>) {
-			if let Err(error) = Self::do_deposit(network, &deposits) {
-				Self::deposit_event(Event::<T>::DepositFailed(network, deposits));
-				log::error!(target:"thea","Deposit Failed : {:?}", error);
+		fn execute_deposits(network: Network, nonce: u64, deposits: Vec<u8>) {
+			let decoded: Vec<Deposit<T::AccountId>> = match Decode::decode(&mut &deposits[..]) {
+				Ok(decoded) => decoded,
+				Err(_) => {
+					Self::store_failed_deposits(
+						network,
+						nonce,
+						FailedDeposits {
+							payload: deposits,
+							deposits: Vec::new(),
+							error: Error::<T>::FailedToDecode.into(),
+						},
+					);
+					return;
+				},
+			};
+			if let (failed, Some(error)) = Self::try_execute_deposits(network, nonce, decoded) {
+				Self::store_failed_deposits(
+					network,
+					nonce,
+					FailedDeposits { payload: Vec::new(), deposits: failed, error },
+				);
 			}
 		}
+
+		fn on_asset_ingress_resumed(asset_id: u128) {
+			Self::schedule_retries(<AwaitingAssetResume<T>>::take(asset_id));
+		}
+
+		fn on_network_ingress_resumed(network: Network) {
+			let nonces = <AwaitingNetworkResume<T>>::take(network);
+			Self::schedule_retries(nonces.into_iter().map(|nonce| (network, nonce)).collect());
+		}
+
+		fn deposits_value(_network: Network, deposits: &[u8]) -> u128 {
+			// Undecodable deposits and unknown assets get the longest fork period
+			let Ok(decoded) = Vec::<Deposit<T::AccountId>>::decode(&mut &deposits[..]) else {
+				return u128::MAX;
+			};
+			decoded.iter().fold(0u128, |value, deposit| {
+				match <Metadata<T>>::get(deposit.asset_id) {
+					Some(metadata) => {
+						value.saturating_add(deposit.amount_in_native_decimals(metadata))
+					},
+					None => u128::MAX,
+				}
+			})
+		}
 	}
 
 	// Implement this trait for handing deposits and withdrawals
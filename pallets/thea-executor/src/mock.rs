@@ -18,14 +18,17 @@
 
 use crate::pallet as thea_executor;
 use frame_support::{
-	ord_parameter_types, parameter_types, traits::AsEnsureOriginWithArg, PalletId,
+	ord_parameter_types, parameter_types,
+	traits::{AsEnsureOriginWithArg, Nothing},
+	PalletId,
 };
 use frame_system as system;
 use frame_system::{EnsureRoot, EnsureSigned};
 use sp_core::H256;
 use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Perbill,
 };
 use thea::ecdsa::{AuthorityId, AuthoritySignature};
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -100,6 +103,8 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub const LockPeriod: u64 = 201600;
 	pub const MaxRelayers: u32 = 3;
+	pub const RelayerUnbondingPeriod: u32 = 100;
+	pub const RelayerRotationPeriod: u32 = 10;
 }
 
 parameter_types! {
@@ -177,6 +182,8 @@ use sp_runtime::Permill;
 
 parameter_types! {
 	pub const MaxAuthorities: u32 = 10;
+	pub const RelayerRewardsAccount: u64 = 100;
+	pub const RelayerRewardsShare: Perbill = Perbill::from_percent(50);
 }
 
 impl thea::pallet::Config for Test {
@@ -186,7 +193,15 @@ impl thea::pallet::Config for Test {
 	type MaxAuthorities = crate::mock::MaxAuthorities;
 	type NativeCurrency = Balances;
 	type TheaGovernanceOrigin = EnsureRoot<Self::AccountId>;
+	type PauseOrigin = EnsureRoot<Self::AccountId>;
 	type Executor = TheaExecutor;
+	type MaxRelayers = MaxRelayers;
+	type RelayerUnbondingPeriod = RelayerUnbondingPeriod;
+	type RelayerRotationPeriod = RelayerRotationPeriod;
+	type RelayerRewardsAccount = RelayerRewardsAccount;
+	type ProvenNetworks = Nothing;
+	type FishermanId = TestFishermanId;
+	type ForeignChainSource = thea::fisherman::JsonRpcSource;
 
 	#[cfg(feature = "runtime-benchmarks")]
 	type TheaBenchmarkHelper = TheaExecutor;
@@ -209,6 +224,9 @@ impl thea_executor::Config for Test {
 	type ExistentialDeposit = ExistentialDeposit;
 	type ParaId = ParaId;
 	type GovernanceOrigin = EnsureRoot<Self::AccountId>;
+	type PauseSwitch = Thea;
+	type RelayerRewardsAccount = RelayerRewardsAccount;
+	type RelayerRewardsShare = RelayerRewardsShare;
 	type TheaExecWeightInfo = crate::weights::WeightInfo<Test>;
 }
 
@@ -220,6 +238,33 @@ where
 	type OverarchingCall = RuntimeCall;
 }
 
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: UintAuthorityId,
+		account: u64,
+		_nonce: u32,
+	) -> Option<(RuntimeCall, (u64, (), ()))> {
+		Some((call, (account, (), ())))
+	}
+}
+
+pub struct TestFishermanId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestFishermanId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
@@ -22,7 +22,8 @@ use crate::{
 };
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{fungible::Mutate as FungibleMutate, fungibles::Mutate as FungiblesMutate},
+	traits::{fungible::Mutate as FungibleMutate, fungibles::Mutate as FungiblesMutate, Hooks},
+	weights::Weight,
 };
 use frame_system::EventRecord;
 use parity_scale_codec::Encode;
@@ -31,7 +32,10 @@ use sp_runtime::{
 	traits::{AccountIdConversion, BadOrigin},
 	SaturatedConversion,
 };
-use thea_primitives::types::{AssetMetadata, Deposit, Withdraw};
+use thea_primitives::{
+	types::{AssetMetadata, Deposit, PauseState, PendingReview, RateLimit, Withdraw},
+	TheaIncomingExecutor,
+};
 use xcm::{opaque::lts::Junctions, v3::MultiLocation, VersionedMultiLocation};
 
 fn assert_last_event<T: crate::Config>(generic_event: <T as crate::Config>::RuntimeEvent) {
@@ -108,6 +112,7 @@ fn test_transfer_native_asset() {
 #[test]
 fn test_deposit_with_valid_args_returns_ok() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		let asset_id = 1000u128;
 		let admin = 1u64;
 		let recipient = 2u64;
@@ -128,7 +133,23 @@ fn test_deposit_with_valid_args_returns_ok() {
 			amount: 1_000_000_000_000_000_000u128,
 			extra: vec![],
 		};
-		assert_ok!(TheaExecutor::do_deposit(1, &vec![deposit].encode()));
+		<TheaExecutor as TheaIncomingExecutor>::execute_deposits(
+			1,
+			1,
+			vec![deposit.clone()].encode(),
+		);
+		assert!(<FailedDepositsQueue<Test>>::get(1, 1).is_none());
+		assert_eq!(Assets::balance(asset_id, &recipient), deposit.amount);
+		assert_last_event::<Test>(
+			crate::Event::<Test>::DepositApproved(
+				1,
+				recipient,
+				asset_id,
+				deposit.amount,
+				deposit.id,
+			)
+			.into(),
+		);
 	})
 }
 
@@ -364,7 +385,9 @@ fn test_do_withdrawal() {
 		));
 		assert_eq!(Balances::free_balance(&sender), 1_000_000_000_000_000_000);
 		assert_eq!(Assets::balance(asset_id, &sender), 999_000_000_000_000_000);
-		assert_eq!(Balances::free_balance(&TheaExecutor::thea_account()), 1_000u128);
+		// Half of the fees are paid to the relayer rewards account
+		assert_eq!(Balances::free_balance(&TheaExecutor::thea_account()), 500u128);
+		assert_eq!(Balances::free_balance(&RelayerRewardsAccount::get()), 500u128);
 	})
 }
 
@@ -449,6 +472,51 @@ fn test_evm_withdraw() {
 	})
 }
 
+#[test]
+fn test_withdrawals_to_evm_networks_need_an_evm_address() {
+	new_test_ext().execute_with(|| {
+		let asset_id = 1000u128;
+		let admin = 1u64;
+		let user = 2u64;
+		let network = 2;
+		Balances::set_balance(&admin, 1_000_000_000_000_000_000);
+		assert_ok!(Assets::create(
+			RuntimeOrigin::signed(admin),
+			parity_scale_codec::Compact(asset_id),
+			admin,
+			1u128
+		));
+		assert_ok!(TheaExecutor::update_asset_metadata(RuntimeOrigin::root(), asset_id, 12));
+		Balances::set_balance(&user, 1_000_000_000_000_000_000);
+		assert_ok!(Assets::mint_into(asset_id, &user, 1_000_000_000_000_000_000));
+		assert_ok!(TheaExecutor::set_withdrawal_fee(RuntimeOrigin::root(), network, 0));
+		assert_ok!(Thea::add_thea_network(RuntimeOrigin::root(), network, true, 20, 0, 0));
+		// Nothing is burned for withdrawals that could not be sent
+		assert_noop!(
+			TheaExecutor::withdraw(
+				RuntimeOrigin::signed(user),
+				asset_id,
+				10_000_000_000_000u128,
+				vec![1; 32],
+				false,
+				network,
+				false
+			),
+			thea::Error::<Test>::InvalidEvmDestination
+		);
+		assert_ok!(TheaExecutor::withdraw(
+			RuntimeOrigin::signed(user),
+			asset_id,
+			10_000_000_000_000u128,
+			vec![1; 20],
+			false,
+			network,
+			false
+		));
+		assert_eq!(<PendingWithdrawals<Test>>::get(network).len(), 1);
+	})
+}
+
 #[test]
 fn test_claim_deposit_returns_ok() {
 	new_test_ext().execute_with(|| {
@@ -471,7 +539,8 @@ fn test_claim_deposit_returns_ok() {
 			amount: 1_000_000_000_000_000_000u128,
 			extra: vec![],
 		};
-		assert_ok!(TheaExecutor::do_deposit(1, &vec![deposit].encode()));
+		<TheaExecutor as TheaIncomingExecutor>::execute_deposits(1, 1, vec![deposit].encode());
+		assert!(<FailedDepositsQueue<Test>>::get(1, 1).is_none());
 		assert_ok!(TheaExecutor::claim_deposit(RuntimeOrigin::signed(recipient), 1, recipient));
 	})
 }
@@ -479,6 +548,7 @@ fn test_claim_deposit_returns_ok() {
 #[test]
 fn test_claim_deposit_returns_asset_not_registered() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		let asset_id = 2000u128;
 		let admin = 1u64;
 		let recipient = 2u64;
@@ -497,10 +567,441 @@ fn test_claim_deposit_returns_asset_not_registered() {
 			amount: 1_000_000_000_000_000_000u128,
 			extra: vec![],
 		};
+		<TheaExecutor as TheaIncomingExecutor>::execute_deposits(
+			1,
+			1,
+			vec![deposit.clone()].encode(),
+		);
+		let failed = <FailedDepositsQueue<Test>>::get(1, 1).unwrap();
+		assert_eq!(failed.deposits, vec![deposit.clone()]);
+		assert_eq!(failed.error, crate::Error::<Test>::AssetNotRegistered.into());
+		System::assert_has_event(
+			crate::Event::<Test>::DepositFailed(1, vec![deposit].encode()).into(),
+		);
+		assert_eq!(Assets::balance(asset_id, &recipient), 0);
+	})
+}
+
+#[test]
+fn test_failed_deposits_are_retried_once_metadata_is_registered() {
+	new_test_ext().execute_with(|| {
+		let asset_id = 2000u128;
+		let admin = 1u64;
+		let recipient = 2u64;
+		Balances::set_balance(&admin, 1_000_000_000_000_000_000);
+		assert_ok!(Assets::create(
+			RuntimeOrigin::signed(admin),
+			parity_scale_codec::Compact(asset_id),
+			admin,
+			1u128
+		));
+		Balances::set_balance(&recipient, 1_000_000_000_000_000_000);
+		let deposit = Deposit {
+			id: vec![1],
+			recipient,
+			asset_id,
+			amount: 1_000_000_000_000_000_000u128,
+			extra: vec![],
+		};
+		<TheaExecutor as TheaIncomingExecutor>::execute_deposits(
+			1,
+			5,
+			vec![deposit.clone()].encode(),
+		);
+		let failed = <FailedDepositsQueue<Test>>::get(1, 5).unwrap();
+		assert_eq!(failed.deposits, vec![deposit]);
+		assert_eq!(failed.error, crate::Error::<Test>::AssetNotRegistered.into());
+		assert_eq!(<AwaitingAssetMetadata<Test>>::get(asset_id), vec![(1, 5)]);
+		assert_eq!(TheaExecutor::get_failed_deposits(1).len(), 1);
+
+		assert_ok!(TheaExecutor::update_asset_metadata(RuntimeOrigin::root(), asset_id, 12));
+		assert_eq!(<FailedDepositsRetryQueue<Test>>::get(), vec![(1, 5)]);
+		// No spare weight, nothing is retried
+		TheaExecutor::on_idle(1, Weight::zero());
+		assert!(<FailedDepositsQueue<Test>>::get(1, 5).is_some());
+
+		TheaExecutor::on_idle(1, Weight::MAX);
+		assert!(<FailedDepositsQueue<Test>>::get(1, 5).is_none());
+		assert!(<FailedDepositsRetryQueue<Test>>::get().is_empty());
+		assert_eq!(Assets::balance(asset_id, &recipient), 1_000_000_000_000_000_000u128);
+	})
+}
+
+#[test]
+fn test_retry_and_refund_failed_deposits() {
+	new_test_ext().execute_with(|| {
+		let asset_id = 2000u128;
+		let deposit = |id: u8, recipient: u64| Deposit {
+			id: vec![id],
+			recipient,
+			asset_id,
+			amount: 1_000_000_000_000_000_000u128,
+			extra: vec![],
+		};
+		<TheaExecutor as TheaIncomingExecutor>::execute_deposits(
+			1,
+			5,
+			vec![deposit(1, 2), deposit(2, 3)].encode(),
+		);
+		// Only recipients or governance can retry
 		assert_noop!(
-			TheaExecutor::do_deposit(1, &vec![deposit].encode()),
+			TheaExecutor::retry_failed_deposits(RuntimeOrigin::signed(4), 1, 5, 10),
+			crate::Error::<Test>::NotDepositRecipient
+		);
+		assert_noop!(
+			TheaExecutor::retry_failed_deposits(RuntimeOrigin::signed(2), 1, 5, 10),
 			crate::Error::<Test>::AssetNotRegistered
 		);
+		assert_noop!(
+			TheaExecutor::retry_failed_deposits(RuntimeOrigin::signed(2), 1, 6, 10),
+			crate::Error::<Test>::FailedDepositNotFound
+		);
+
+		let beneficiary = vec![1; 20];
+		assert_noop!(
+			TheaExecutor::refund_failed_deposit(
+				RuntimeOrigin::signed(3),
+				1,
+				5,
+				vec![1],
+				beneficiary.clone()
+			),
+			crate::Error::<Test>::NotDepositRecipient
+		);
+		assert_ok!(TheaExecutor::refund_failed_deposit(
+			RuntimeOrigin::signed(3),
+			1,
+			5,
+			vec![2],
+			beneficiary.clone()
+		));
+		let pending_withdrawal = <PendingWithdrawals<Test>>::get(1);
+		assert_eq!(pending_withdrawal.len(), 1);
+		assert_eq!(pending_withdrawal[0].id, vec![2]);
+		assert_eq!(pending_withdrawal[0].destination, beneficiary);
+		assert_eq!(pending_withdrawal[0].amount, 1_000_000_000_000_000_000u128);
+		assert_eq!(<FailedDepositsQueue<Test>>::get(1, 5).unwrap().deposits, vec![deposit(1, 2)]);
+
+		assert_ok!(TheaExecutor::refund_failed_deposit(
+			RuntimeOrigin::root(),
+			1,
+			5,
+			vec![1],
+			beneficiary
+		));
+		assert!(<FailedDepositsQueue<Test>>::get(1, 5).is_none());
+	})
+}
+
+#[test]
+fn test_undecodable_deposits_are_stored() {
+	new_test_ext().execute_with(|| {
+		<TheaExecutor as TheaIncomingExecutor>::execute_deposits(1, 5, vec![1, 2, 3]);
+		let failed = <FailedDepositsQueue<Test>>::get(1, 5).unwrap();
+		assert_eq!(failed.payload, vec![1, 2, 3]);
+		assert!(failed.deposits.is_empty());
+		assert_eq!(failed.error, crate::Error::<Test>::FailedToDecode.into());
+		assert_noop!(
+			TheaExecutor::retry_failed_deposits(RuntimeOrigin::root(), 1, 5, 10),
+			crate::Error::<Test>::FailedToDecode
+		);
+	})
+}
+
+#[test]
+fn test_deposits_over_rate_limit_are_put_under_review() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = 2000u128;
+		let admin = 1u64;
+		let recipient = 2u64;
+		Balances::set_balance(&admin, 1_000_000_000_000_000_000);
+		Balances::set_balance(&recipient, 1_000_000_000_000_000_000);
+		assert_ok!(Assets::create(
+			RuntimeOrigin::signed(admin),
+			parity_scale_codec::Compact(asset_id),
+			admin,
+			1u128
+		));
+		assert_ok!(TheaExecutor::update_asset_metadata(RuntimeOrigin::root(), asset_id, 12));
+		let limit = RateLimit { window: 10, deposit_cap: 1_500, withdrawal_cap: 1_500 };
+		assert_noop!(
+			TheaExecutor::set_rate_limit(RuntimeOrigin::signed(admin), 1, asset_id, Some(limit)),
+			BadOrigin
+		);
+		assert_noop!(
+			TheaExecutor::set_rate_limit(
+				RuntimeOrigin::root(),
+				1,
+				asset_id,
+				Some(RateLimit { window: 0, ..limit })
+			),
+			crate::Error::<Test>::InvalidRateLimit
+		);
+		assert_ok!(TheaExecutor::set_rate_limit(RuntimeOrigin::root(), 1, asset_id, Some(limit)));
+
+		let deposit = |id: u8| Deposit {
+			id: vec![id],
+			recipient,
+			asset_id,
+			amount: 1_000u128,
+			extra: vec![],
+		};
+		<TheaExecutor as TheaIncomingExecutor>::execute_deposits(
+			1,
+			1,
+			vec![deposit(1), deposit(2)].encode(),
+		);
+		assert_eq!(Assets::balance(asset_id, &recipient), 1_000u128);
+		assert_eq!(
+			<ReviewQueue<Test>>::get(0),
+			Some(PendingReview::Deposit { network: 1, nonce: 1, deposit: deposit(2) })
+		);
+		assert!(<FailedDepositsQueue<Test>>::get(1, 1).is_none());
+		assert_eq!(TheaExecutor::get_rate_limit_usage(1, asset_id), Some((limit, 1_000, 0)));
+
+		// Usage expires with the rolling window
+		System::set_block_number(16);
+		assert_eq!(TheaExecutor::get_rate_limit_usage(1, asset_id), Some((limit, 500, 0)));
+		System::set_block_number(30);
+		assert_eq!(TheaExecutor::get_rate_limit_usage(1, asset_id), Some((limit, 0, 0)));
+
+		assert_noop!(TheaExecutor::approve_review(RuntimeOrigin::signed(admin), 0), BadOrigin);
+		assert_ok!(TheaExecutor::approve_review(RuntimeOrigin::root(), 0));
+		assert_eq!(Assets::balance(asset_id, &recipient), 2_000u128);
+		assert_noop!(
+			TheaExecutor::approve_review(RuntimeOrigin::root(), 0),
+			crate::Error::<Test>::ReviewNotFound
+		);
+
+		// Rejected deposits can be refunded as failed deposits
+		<TheaExecutor as TheaIncomingExecutor>::execute_deposits(1, 2, vec![deposit(3)].encode());
+		assert_ok!(TheaExecutor::reject_review(RuntimeOrigin::root(), 1));
+		let failed = <FailedDepositsQueue<Test>>::get(1, 2).unwrap();
+		assert_eq!(failed.deposits, vec![deposit(3)]);
+		assert_eq!(failed.error, crate::Error::<Test>::DepositRejected.into());
+		assert_eq!(Assets::balance(asset_id, &recipient), 2_000u128);
+	})
+}
+
+#[test]
+fn test_withdrawals_over_rate_limit_are_put_under_review() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = 1000u128;
+		let admin = 1u64;
+		let user = 2u64;
+		Balances::set_balance(&admin, 1_000_000_000_000_000_000);
+		assert_ok!(Assets::create(
+			RuntimeOrigin::signed(admin),
+			parity_scale_codec::Compact(asset_id),
+			admin,
+			1u128
+		));
+		assert_ok!(TheaExecutor::update_asset_metadata(RuntimeOrigin::root(), asset_id, 12));
+		Balances::set_balance(&user, 1_000_000_000_000_000_000);
+		assert_ok!(Assets::mint_into(asset_id, &user, 1_000_000_000_000_000_000));
+		assert_ok!(TheaExecutor::set_withdrawal_fee(RuntimeOrigin::root(), 1, 0));
+		let limit = RateLimit {
+			window: 10,
+			deposit_cap: u128::MAX,
+			withdrawal_cap: 15_000_000_000_000u128,
+		};
+		assert_ok!(TheaExecutor::set_rate_limit(RuntimeOrigin::root(), 1, asset_id, Some(limit)));
+		for _ in 0..2 {
+			assert_ok!(TheaExecutor::withdraw(
+				RuntimeOrigin::signed(user),
+				asset_id,
+				10_000_000_000_000u128,
+				vec![1; 32],
+				false,
+				1,
+				false
+			));
+		}
+		assert_eq!(<PendingWithdrawals<Test>>::get(1).len(), 1);
+		assert!(matches!(
+			<ReviewQueue<Test>>::get(0),
+			Some(PendingReview::Withdrawal { network: 1, user: 2, amount: 10_000_000_000_000, .. })
+		));
+		assert_eq!(
+			TheaExecutor::get_rate_limit_usage(1, asset_id),
+			Some((limit, 0, 10_000_000_000_000))
+		);
+		assert_eq!(Assets::balance(asset_id, &user), 999_980_000_000_000_000);
+
+		// Rejected withdrawals are returned to the user
+		assert_ok!(TheaExecutor::reject_review(RuntimeOrigin::root(), 0));
+		assert_eq!(Assets::balance(asset_id, &user), 999_990_000_000_000_000);
+		assert_eq!(<PendingWithdrawals<Test>>::get(1).len(), 1);
+		assert!(<ReviewQueue<Test>>::get(0).is_none());
+	})
+}
+
+#[test]
+fn test_withdrawals_are_rejected_while_egress_is_paused() {
+	new_test_ext().execute_with(|| {
+		let asset_id = 1000u128;
+		let admin = 1u64;
+		let user = 2u64;
+		Balances::set_balance(&admin, 1_000_000_000_000_000_000);
+		assert_ok!(Assets::create(
+			RuntimeOrigin::signed(admin),
+			parity_scale_codec::Compact(asset_id),
+			admin,
+			1u128
+		));
+		assert_ok!(TheaExecutor::update_asset_metadata(RuntimeOrigin::root(), asset_id, 12));
+		Balances::set_balance(&user, 1_000_000_000_000_000_000);
+		assert_ok!(Assets::mint_into(asset_id, &user, 1_000_000_000_000_000_000));
+		assert_ok!(TheaExecutor::set_withdrawal_fee(RuntimeOrigin::root(), 1, 0));
+
+		assert_ok!(Thea::set_network_pause(RuntimeOrigin::root(), 1, Some(PauseState::Egress)));
+		assert_noop!(
+			TheaExecutor::withdraw(
+				RuntimeOrigin::signed(user),
+				asset_id,
+				10_000_000_000_000u128,
+				vec![1; 32],
+				false,
+				1,
+				false
+			),
+			crate::Error::<Test>::NetworkEgressPaused
+		);
+		assert_ok!(Thea::set_network_pause(RuntimeOrigin::root(), 1, Some(PauseState::Ingress)));
+		assert_ok!(Thea::set_asset_pause(RuntimeOrigin::root(), asset_id, Some(PauseState::Both)));
+		assert_noop!(
+			TheaExecutor::evm_withdraw(
+				RuntimeOrigin::signed(user),
+				asset_id,
+				10_000_000_000_000u128,
+				H160::zero(),
+				1,
+				false,
+				false
+			),
+			crate::Error::<Test>::AssetEgressPaused
+		);
+	})
+}
+
+#[test]
+fn test_ready_withdrawals_are_held_while_egress_is_paused() {
+	new_test_ext().execute_with(|| {
+		let withdrawal = Withdraw {
+			id: vec![1],
+			asset_id: 1000,
+			amount: 1,
+			destination: vec![1; 32],
+			is_blocked: false,
+			extra: vec![],
+		};
+		<ReadyWithdrawals<Test>>::insert(1, 1, vec![withdrawal.clone()]);
+		assert_ok!(Thea::set_network_pause(RuntimeOrigin::root(), 1, Some(PauseState::Egress)));
+		TheaExecutor::on_initialize(2);
+		assert_eq!(<ReadyWithdrawals<Test>>::get(2, 1), vec![withdrawal.clone()]);
+		// The batch is moved rather than copied each block
+		assert!(!<ReadyWithdrawals<Test>>::contains_key(1, 1));
+		TheaExecutor::on_initialize(3);
+		assert!(!<ReadyWithdrawals<Test>>::contains_key(2, 1));
+		assert_eq!(<ReadyWithdrawals<Test>>::get(3, 1), vec![withdrawal]);
+	})
+}
+
+#[test]
+fn test_deposits_wait_for_asset_ingress_to_resume() {
+	new_test_ext().execute_with(|| {
+		let asset_id = 2000u128;
+		let admin = 1u64;
+		let recipient = 2u64;
+		Balances::set_balance(&admin, 1_000_000_000_000_000_000);
+		Balances::set_balance(&recipient, 1_000_000_000_000_000_000);
+		assert_ok!(Assets::create(
+			RuntimeOrigin::signed(admin),
+			parity_scale_codec::Compact(asset_id),
+			admin,
+			1u128
+		));
+		assert_ok!(TheaExecutor::update_asset_metadata(RuntimeOrigin::root(), asset_id, 12));
+		assert_ok!(Thea::set_asset_pause(
+			RuntimeOrigin::root(),
+			asset_id,
+			Some(PauseState::Ingress)
+		));
+		let deposit = Deposit {
+			id: vec![1],
+			recipient,
+			asset_id,
+			amount: 1_000_000_000_000_000_000u128,
+			extra: vec![],
+		};
+		<TheaExecutor as TheaIncomingExecutor>::execute_deposits(1, 1, vec![deposit].encode());
+		assert_eq!(
+			<FailedDepositsQueue<Test>>::get(1, 1).unwrap().error,
+			crate::Error::<Test>::AssetIngressPaused.into()
+		);
+		assert_eq!(<AwaitingAssetResume<Test>>::get(asset_id), vec![(1, 1)]);
+
+		assert_ok!(Thea::set_asset_pause(RuntimeOrigin::root(), asset_id, None));
+		assert_eq!(<FailedDepositsRetryQueue<Test>>::get(), vec![(1, 1)]);
+		TheaExecutor::on_idle(1, Weight::MAX);
+		assert!(<FailedDepositsQueue<Test>>::get(1, 1).is_none());
+		assert_eq!(Assets::balance(asset_id, &recipient), 1_000_000_000_000_000_000u128);
+	})
+}
+
+#[test]
+fn test_deposits_wait_for_network_ingress_to_resume() {
+	new_test_ext().execute_with(|| {
+		let asset_id = 2000u128;
+		let admin = 1u64;
+		let recipient = 2u64;
+		Balances::set_balance(&admin, 1_000_000_000_000_000_000);
+		Balances::set_balance(&recipient, 1_000_000_000_000_000_000);
+		assert_ok!(Assets::create(
+			RuntimeOrigin::signed(admin),
+			parity_scale_codec::Compact(asset_id),
+			admin,
+			1u128
+		));
+		assert_ok!(TheaExecutor::update_asset_metadata(RuntimeOrigin::root(), asset_id, 12));
+		assert_ok!(Thea::set_network_pause(RuntimeOrigin::root(), 1, Some(PauseState::Ingress)));
+		let deposit = Deposit {
+			id: vec![1],
+			recipient,
+			asset_id,
+			amount: 1_000_000_000_000_000_000u128,
+			extra: vec![],
+		};
+		<TheaExecutor as TheaIncomingExecutor>::execute_deposits(1, 1, vec![deposit].encode());
+		assert_eq!(
+			<FailedDepositsQueue<Test>>::get(1, 1).unwrap().error,
+			crate::Error::<Test>::NetworkIngressPaused.into()
+		);
+		assert_eq!(<AwaitingNetworkResume<Test>>::get(1), vec![1]);
+
+		assert_ok!(Thea::set_network_pause(RuntimeOrigin::root(), 1, None));
+		assert!(<AwaitingNetworkResume<Test>>::get(1).is_empty());
+		assert_eq!(<FailedDepositsRetryQueue<Test>>::get(), vec![(1, 1)]);
+		TheaExecutor::on_idle(1, Weight::MAX);
+		assert!(<FailedDepositsQueue<Test>>::get(1, 1).is_none());
+		assert_eq!(Assets::balance(asset_id, &recipient), 1_000_000_000_000_000_000u128);
+	})
+}
+
+#[test]
+fn test_fund_relayer_rewards_from_withdrawal_fees() {
+	new_test_ext().execute_with(|| {
+		let thea_account = TheaExecutor::thea_account();
+		Balances::set_balance(&thea_account, 1_000_000_000_000_000_000);
+		assert_noop!(
+			TheaExecutor::fund_relayer_rewards(RuntimeOrigin::signed(1), 1_000_000_000_000),
+			BadOrigin
+		);
+		assert_ok!(TheaExecutor::fund_relayer_rewards(RuntimeOrigin::root(), 1_000_000_000_000));
+		assert_eq!(Balances::free_balance(&RelayerRewardsAccount::get()), 1_000_000_000_000);
+		assert_eq!(Balances::free_balance(&thea_account), 999_999_000_000_000_000);
 	})
 }
 
@@ -62,7 +62,7 @@ impl<T: frame_system::Config> crate::TheaExecutorWeightInfo for WeightInfo<T> {
 	/// Proof: `TheaExecutor::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TheaExecutor::WithdrawalFees` (r:1 w:0)
 	/// Proof: `TheaExecutor::WithdrawalFees` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `System::Account` (r:3 w:3)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Asset` (r:1 w:1)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
@@ -78,12 +78,13 @@ impl<T: frame_system::Config> crate::TheaExecutorWeightInfo for WeightInfo<T> {
 		//  Measured:  `826`
 		//  Estimated: `6196`
 		// Minimum execution time: 297_681_000 picoseconds.
+		// Placeholder: DB reads/writes adjusted by hand, regenerate with `benchmark pallet`.
 		Weight::from_parts(304_469_185, 0)
 			.saturating_add(Weight::from_parts(0, 6196))
 			// Standard Error: 51
 			.saturating_add(Weight::from_parts(40, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `TheaExecutor::PendingWithdrawals` (r:1 w:1)
 	/// Proof: `TheaExecutor::PendingWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -91,7 +92,7 @@ impl<T: frame_system::Config> crate::TheaExecutorWeightInfo for WeightInfo<T> {
 	/// Proof: `TheaExecutor::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TheaExecutor::WithdrawalFees` (r:1 w:0)
 	/// Proof: `TheaExecutor::WithdrawalFees` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `System::Account` (r:3 w:3)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Asset` (r:1 w:1)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
@@ -107,10 +108,11 @@ impl<T: frame_system::Config> crate::TheaExecutorWeightInfo for WeightInfo<T> {
 		//  Measured:  `793`
 		//  Estimated: `6196`
 		// Minimum execution time: 291_921_000 picoseconds.
+		// Placeholder: DB reads/writes adjusted by hand, regenerate with `benchmark pallet`.
 		Weight::from_parts(299_089_220, 0)
 			.saturating_add(Weight::from_parts(0, 6196))
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `TheaExecutor::PendingWithdrawals` (r:1 w:1)
 	/// Proof: `TheaExecutor::PendingWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -118,7 +120,7 @@ impl<T: frame_system::Config> crate::TheaExecutorWeightInfo for WeightInfo<T> {
 	/// Proof: `TheaExecutor::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TheaExecutor::WithdrawalFees` (r:1 w:0)
 	/// Proof: `TheaExecutor::WithdrawalFees` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `System::Account` (r:3 w:3)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Asset` (r:1 w:1)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
@@ -134,10 +136,11 @@ impl<T: frame_system::Config> crate::TheaExecutorWeightInfo for WeightInfo<T> {
 		//  Measured:  `793`
 		//  Estimated: `6196`
 		// Minimum execution time: 290_141_000 picoseconds.
+		// Placeholder: DB reads/writes adjusted by hand, regenerate with `benchmark pallet`.
 		Weight::from_parts(297_666_735, 0)
 			.saturating_add(Weight::from_parts(0, 6196))
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `TheaExecutor::ReadyWithdrawals` (r:1 w:0)
 	/// Proof: `TheaExecutor::ReadyWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -189,4 +192,95 @@ impl<T: frame_system::Config> crate::TheaExecutorWeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `TheaExecutor::FailedDepositsQueue` (r:1 w:1)
+	/// Proof: `TheaExecutor::FailedDepositsQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TheaExecutor::Metadata` (r:1 w:0)
+	/// Proof: `TheaExecutor::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 100]`.
+	fn retry_failed_deposits(x: u32, ) -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(61_428_104, 0)
+			.saturating_add(Weight::from_parts(0, 5011))
+			// Standard Error: 10_962
+			.saturating_add(Weight::from_parts(79_830_512, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 91).saturating_mul(x.into()))
+	}
+	/// Storage: `TheaExecutor::FailedDepositsQueue` (r:1 w:1)
+	/// Proof: `TheaExecutor::FailedDepositsQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TheaExecutor::PendingWithdrawals` (r:1 w:1)
+	/// Proof: `TheaExecutor::PendingWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TheaExecutor::ReadyWithdrawals` (r:0 w:1)
+	/// Proof: `TheaExecutor::ReadyWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn refund_failed_deposit() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(49_560_000, 0)
+			.saturating_add(Weight::from_parts(0, 5837))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `TheaExecutor::RateLimits` (r:0 w:1)
+	/// Proof: `TheaExecutor::RateLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TheaExecutor::RateLimitUsages` (r:0 w:1)
+	/// Proof: `TheaExecutor::RateLimitUsages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_rate_limit() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(27_040_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `TheaExecutor::ReviewQueue` (r:1 w:1)
+	/// Proof: `TheaExecutor::ReviewQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TheaExecutor::Metadata` (r:1 w:0)
+	/// Proof: `TheaExecutor::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `TheaExecutor::RateLimits` (r:1 w:0)
+	/// Proof: `TheaExecutor::RateLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TheaExecutor::RateLimitUsages` (r:1 w:1)
+	/// Proof: `TheaExecutor::RateLimitUsages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_review() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(107_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 5152))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `TheaExecutor::ReviewQueue` (r:1 w:1)
+	/// Proof: `TheaExecutor::ReviewQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	fn reject_review() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(108_650_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn fund_relayer_rewards() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(58_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -229,6 +229,7 @@ pub mod pallet {
 					// Normal Thea message
 					T::Executor::execute_deposits(
 						payload.message.network,
+						payload.message.nonce,
 						payload.message.data.clone(),
 					);
 				},
@@ -17,7 +17,10 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use frame_support::{
-	ord_parameter_types, pallet_prelude::*, parameter_types, traits::AsEnsureOriginWithArg,
+	ord_parameter_types,
+	pallet_prelude::*,
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, Nothing},
 	PalletId,
 };
 use frame_system as system;
@@ -25,8 +28,9 @@ use frame_system::{EnsureRoot, EnsureSigned};
 use polkadex_primitives::AssetId;
 use sp_core::H256;
 use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage, Permill,
+	BuildStorage, Perbill, Permill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -102,6 +106,10 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub const LockPeriod: u64 = 201600;
 	pub const MaxRelayers: u32 = 3;
+	pub const RelayerUnbondingPeriod: u32 = 100;
+	pub const RelayerRotationPeriod: u32 = 10;
+	pub const RelayerRewardsAccount: u64 = 100;
+	pub const RelayerRewardsShare: Perbill = Perbill::from_percent(50);
 }
 
 parameter_types! {
@@ -145,6 +153,14 @@ impl thea::Config for Test {
 	type Executor = TheaExecutor;
 	type NativeCurrency = Balances;
 	type TheaGovernanceOrigin = EnsureRoot<Self::AccountId>;
+	type PauseOrigin = EnsureRoot<Self::AccountId>;
+	type MaxRelayers = MaxRelayers;
+	type RelayerUnbondingPeriod = RelayerUnbondingPeriod;
+	type RelayerRotationPeriod = RelayerRotationPeriod;
+	type RelayerRewardsAccount = RelayerRewardsAccount;
+	type ProvenNetworks = Nothing;
+	type FishermanId = TestFishermanId;
+	type ForeignChainSource = thea::fisherman::JsonRpcSource;
 	type WeightInfo = thea::weights::WeightInfo<Test>;
 }
 
@@ -205,6 +221,9 @@ impl thea_executor::Config for Test {
 	type MultiAssetIdAdapter = AssetId;
 	type AssetBalanceAdapter = u128;
 	type GovernanceOrigin = EnsureRoot<u64>;
+	type PauseSwitch = Thea;
+	type RelayerRewardsAccount = RelayerRewardsAccount;
+	type RelayerRewardsShare = RelayerRewardsShare;
 	type ExistentialDeposit = ExistentialDeposit;
 }
 
@@ -225,6 +244,33 @@ where
 	type OverarchingCall = RuntimeCall;
 }
 
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: UintAuthorityId,
+		account: u64,
+		_nonce: u32,
+	) -> Option<(RuntimeCall, (u64, (), ()))> {
+		Some((call, (account, (), ())))
+	}
+}
+
+pub struct TestFishermanId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestFishermanId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
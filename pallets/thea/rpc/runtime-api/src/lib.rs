@@ -0,0 +1,55 @@
+// This file is part of Polkadex.
+//
+// Copyright (c) 2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+use thea_primitives::{
+	types::{
+		BlsSignedMessage, IncomingMessage, MessageCommitment, MessageProof, NetworkConfig,
+		NetworkNonces, OutgoingMessageStatus, SignedMessage,
+	},
+	Network, ValidatorSetId,
+};
+
+sp_api::decl_runtime_apis! {
+	pub trait TheaRuntimeApi<AccountId, AuthorityId, Signature> where AccountId: Codec, AuthorityId: Codec, Signature: Codec {
+		// Returns the outgoing message of the network with its aggregated BLS signature
+		fn get_bls_signed_message(network: Network, nonce: u64) -> Option<BlsSignedMessage>;
+		// Returns the registered BLS public keys of the validator set by authority index
+		fn get_bls_authorities(id: ValidatorSetId) -> Vec<(u32, bls_primitives::Public)>;
+		// Returns the signing progress of the outgoing message, defaults to the next message
+		// awaiting signatures
+		fn get_outgoing_message_status(network: Network, nonce: Option<u64>) -> Option<OutgoingMessageStatus<Signature>>;
+		// Returns the incoming messages waiting for execution by nonce
+		fn get_incoming_queue(network: Network) -> Vec<(u64, IncomingMessage<AccountId, u128>)>;
+		// Returns the incoming, outgoing and signed outgoing nonces of the network
+		fn get_network_nonces(network: Network) -> NetworkNonces;
+		// Returns the current validator set id
+		fn get_validator_set_id() -> ValidatorSetId;
+		// Returns the authorities of the validator set
+		fn get_authorities(id: ValidatorSetId) -> Vec<AuthorityId>;
+		// Returns the config of every active network
+		fn get_network_configs() -> Vec<(Network, NetworkConfig)>;
+		// Returns the outgoing message with its inclusion proof in the message commitment
+		fn get_message_proof(network: Network, nonce: u64) -> Option<MessageProof>;
+		// Returns the message commitment with the signatures collected so far
+		fn get_signed_commitment(network: Network, nonce: u64) -> Option<SignedMessage<Signature, MessageCommitment>>;
+	}
+}
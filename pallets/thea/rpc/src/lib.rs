@@ -0,0 +1,444 @@
+// This file is part of Polkadex.
+//
+// Copyright (c) 2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! This crate provides RPC methods for Thea relayers - signed outgoing messages, the incoming
+//! queue, nonces, validator sets and network configs - and a subscription to newly signed
+//! outgoing messages.
+
+use futures::{stream, FutureExt, StreamExt};
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::{
+		error::{CallError, ErrorObject},
+		SubscriptionResult,
+	},
+	SubscriptionSink,
+};
+use parity_scale_codec::{Codec, Encode};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{traits::SpawnNamed, Bytes, H256};
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+use thea_primitives::{
+	types::{IncomingMessage, NetworkConfig, NetworkNonces, SignatureScheme},
+	Network, ValidatorSetId,
+};
+pub use thea_runtime_api::TheaRuntimeApi;
+
+const RUNTIME_ERROR: i32 = 1;
+
+/// Compact proof of an outgoing message as returned by `thea_blsSignedMessage`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BlsSignedMessageProof {
+	pub validator_set_id: ValidatorSetId,
+	/// SCALE encoded message
+	pub message: Bytes,
+	/// Bitmap of the signers, bit `i % 8` of byte `i / 8` is set if authority `i` signed
+	pub signers: Bytes,
+	/// Aggregated BLS signature of all signers
+	pub signature: Bytes,
+}
+
+/// Signing progress of an outgoing message as returned by `thea_outgoingMessageStatus`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OutgoingMessageStatus {
+	pub network: Network,
+	pub nonce: u64,
+	pub validator_set_id: ValidatorSetId,
+	pub scheme: SignatureScheme,
+	/// SCALE encoded message
+	pub message: Bytes,
+	/// SCALE encoded signatures by authority index, empty for BLS signed networks
+	pub signatures: Vec<(u32, Bytes)>,
+	/// Authority indexes of all signers
+	pub signers: Vec<u32>,
+	pub required_signatures: u32,
+	pub threshold_reached: bool,
+}
+
+impl<Signature: Encode> From<thea_primitives::types::OutgoingMessageStatus<Signature>>
+	for OutgoingMessageStatus
+{
+	fn from(status: thea_primitives::types::OutgoingMessageStatus<Signature>) -> Self {
+		Self {
+			network: status.message.network,
+			nonce: status.message.nonce,
+			validator_set_id: status.validator_set_id,
+			scheme: status.scheme,
+			message: status.message.encode().into(),
+			signatures: status
+				.signatures
+				.into_iter()
+				.map(|(auth_index, signature)| (auth_index, signature.encode().into()))
+				.collect(),
+			signers: status.signers,
+			required_signatures: status.required_signatures,
+			threshold_reached: status.threshold_reached,
+		}
+	}
+}
+
+/// Inclusion proof of an outgoing message in a signed message commitment as returned by
+/// `thea_messageProof`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MessageInclusionProof {
+	pub network: Network,
+	pub commitment_nonce: u64,
+	/// SCALE encoded message commitment, the payload signed by validators
+	pub commitment: Bytes,
+	pub root: H256,
+	/// SCALE encoded message
+	pub message: Bytes,
+	pub leaf_index: u32,
+	pub number_of_leaves: u32,
+	/// Hashes of the sibling nodes from the leaf up to the root
+	pub proof: Vec<H256>,
+	/// Validator set signing the commitment, `None` until the first signature
+	pub validator_set_id: Option<ValidatorSetId>,
+	/// SCALE encoded signatures of the commitment by authority index
+	pub signatures: Vec<(u32, Bytes)>,
+}
+
+/// Validator set as returned by `thea_authorities`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AuthoritySet {
+	pub id: ValidatorSetId,
+	/// SCALE encoded public keys by authority index
+	pub authorities: Vec<Bytes>,
+}
+
+#[rpc(client, server)]
+pub trait TheaRpcApi<BlockHash, AccountId> {
+	/// Returns the outgoing message of the network with its aggregated BLS signature
+	#[method(name = "thea_blsSignedMessage")]
+	fn get_bls_signed_message(
+		&self,
+		network: Network,
+		nonce: u64,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<BlsSignedMessageProof>>;
+
+	/// Returns (authority index, BLS public key) of every registered key of the validator set
+	#[method(name = "thea_blsAuthorities")]
+	fn get_bls_authorities(
+		&self,
+		id: ValidatorSetId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(u32, Bytes)>>;
+
+	/// Returns the signing progress of the outgoing message, defaults to the next message
+	/// awaiting signatures
+	#[method(name = "thea_outgoingMessageStatus")]
+	fn get_outgoing_message_status(
+		&self,
+		network: Network,
+		nonce: Option<u64>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<OutgoingMessageStatus>>;
+
+	/// Returns the incoming messages of the network waiting for execution by nonce
+	#[method(name = "thea_incomingQueue")]
+	fn get_incoming_queue(
+		&self,
+		network: Network,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(u64, IncomingMessage<AccountId, u128>)>>;
+
+	/// Returns the last processed incoming nonce and the outgoing nonces of the network
+	#[method(name = "thea_networkNonces")]
+	fn get_network_nonces(
+		&self,
+		network: Network,
+		at: Option<BlockHash>,
+	) -> RpcResult<NetworkNonces>;
+
+	/// Returns the authorities of the validator set, defaults to the current validator set
+	#[method(name = "thea_authorities")]
+	fn get_authorities(
+		&self,
+		id: Option<ValidatorSetId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<AuthoritySet>;
+
+	/// Returns the config of every active network
+	#[method(name = "thea_networkConfigs")]
+	fn get_network_configs(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(Network, NetworkConfig)>>;
+
+	/// Returns the outgoing message with its inclusion proof and the signatures of the message
+	/// commitment including it
+	#[method(name = "thea_messageProof")]
+	fn get_message_proof(
+		&self,
+		network: Network,
+		nonce: u64,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<MessageInclusionProof>>;
+
+	/// Notifies outgoing messages of the network as they reach the signature threshold in
+	/// finalized blocks
+	#[subscription(
+		name = "thea_subscribeSignedMessages" => "thea_signedMessage",
+		unsubscribe = "thea_unsubscribeSignedMessages",
+		item = OutgoingMessageStatus
+	)]
+	fn subscribe_signed_messages(&self, network: Network);
+}
+
+/// A structure that represents the Thea RPC, which allows relayers to fetch signed outgoing
+/// messages.
+///
+/// # Type Parameters
+///
+/// * `Client`: The client API used to interact with the Substrate runtime.
+/// * `Block`: The block type of the Substrate.
+pub struct TheaRpc<Client, Block> {
+	/// An `Arc` reference to the client API for accessing runtime functionality.
+	client: Arc<Client>,
+
+	/// Executor to drive the signed messages subscriptions.
+	executor: SubscriptionTaskExecutor,
+
+	/// A marker for the `Block` type parameter, used to ensure the struct
+	/// is covariant with respect to the block type.
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<Client, Block> TheaRpc<Client, Block> {
+	pub fn new(client: Arc<Client>, executor: SubscriptionTaskExecutor) -> Self {
+		Self { client, executor, _marker: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<Client, Block, AccountId, AuthorityId, Signature>
+	TheaRpcApiServer<<Block as BlockT>::Hash, AccountId> for TheaRpc<Client, Block>
+where
+	Block: BlockT,
+	Client: Send
+		+ Sync
+		+ 'static
+		+ ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>,
+	Client::Api: TheaRuntimeApi<Block, AccountId, AuthorityId, Signature>,
+	AccountId: Codec + Serialize + Send + Sync + 'static,
+	AuthorityId: Codec,
+	Signature: Codec,
+{
+	fn get_bls_signed_message(
+		&self,
+		network: Network,
+		nonce: u64,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<BlsSignedMessageProof>> {
+		let api = self.client.runtime_api();
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let signed_message = api
+			.get_bls_signed_message(at, network, nonce)
+			.map_err(runtime_error_into_rpc_err)?;
+		Ok(signed_message.map(|signed_message| BlsSignedMessageProof {
+			validator_set_id: signed_message.validator_set_id,
+			message: signed_message.message.encode().into(),
+			signers: signed_message.signers.into(),
+			signature: signed_message.signature.0.to_vec().into(),
+		}))
+	}
+
+	fn get_bls_authorities(
+		&self,
+		id: ValidatorSetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u32, Bytes)>> {
+		let api = self.client.runtime_api();
+		let at = match at {
+			Some(at) => at,
+			None => self.client.info().best_hash,
+		};
+
+		let authorities = api.get_bls_authorities(at, id).map_err(runtime_error_into_rpc_err)?;
+		Ok(authorities
+			.into_iter()
+			.map(|(auth_index, public)| (auth_index, public.0.to_vec().into()))
+			.collect())
+	}
+
+	fn get_outgoing_message_status(
+		&self,
+		network: Network,
+		nonce: Option<u64>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<OutgoingMessageStatus>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let status = api
+			.get_outgoing_message_status(at, network, nonce)
+			.map_err(runtime_error_into_rpc_err)?;
+		Ok(status.map(Into::into))
+	}
+
+	fn get_incoming_queue(
+		&self,
+		network: Network,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u64, IncomingMessage<AccountId, u128>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_incoming_queue(at, network).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_network_nonces(
+		&self,
+		network: Network,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<NetworkNonces> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_network_nonces(at, network).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_authorities(
+		&self,
+		id: Option<ValidatorSetId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<AuthoritySet> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let id = match id {
+			Some(id) => id,
+			None => api.get_validator_set_id(at).map_err(runtime_error_into_rpc_err)?,
+		};
+		let authorities = api.get_authorities(at, id).map_err(runtime_error_into_rpc_err)?;
+		Ok(AuthoritySet {
+			id,
+			authorities: authorities.into_iter().map(|public| public.encode().into()).collect(),
+		})
+	}
+
+	fn get_network_configs(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(Network, NetworkConfig)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_network_configs(at).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_message_proof(
+		&self,
+		network: Network,
+		nonce: u64,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<MessageInclusionProof>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let Some(proof) =
+			api.get_message_proof(at, network, nonce).map_err(runtime_error_into_rpc_err)?
+		else {
+			return Ok(None);
+		};
+		let signed_commitment = api
+			.get_signed_commitment(at, network, proof.commitment.nonce)
+			.map_err(runtime_error_into_rpc_err)?;
+		let commitment = proof.commitment;
+		Ok(Some(MessageInclusionProof {
+			network,
+			commitment_nonce: commitment.nonce,
+			commitment: commitment.encode().into(),
+			root: commitment.root,
+			message: proof.message.encode().into(),
+			leaf_index: proof.leaf_index,
+			number_of_leaves: (commitment.last_nonce - commitment.first_nonce + 1) as u32,
+			proof: proof.proof,
+			validator_set_id: signed_commitment
+				.as_ref()
+				.map(|signed_commitment| signed_commitment.validator_set_id),
+			signatures: signed_commitment
+				.map(|signed_commitment| {
+					signed_commitment
+						.signatures
+						.into_iter()
+						.map(|(auth_index, signature)| (auth_index, signature.encode().into()))
+						.collect()
+				})
+				.unwrap_or_default(),
+		}))
+	}
+
+	fn subscribe_signed_messages(
+		&self,
+		mut sink: SubscriptionSink,
+		network: Network,
+	) -> SubscriptionResult {
+		let client = self.client.clone();
+		let mut last_signed = client
+			.runtime_api()
+			.get_network_nonces(client.info().finalized_hash, network)
+			.map(|nonces| nonces.signed_outgoing)
+			.unwrap_or_default();
+
+		let signed_messages = self
+			.client
+			.finality_notification_stream()
+			.flat_map(move |notification| {
+				let api = client.runtime_api();
+				let mut signed = Vec::new();
+				if let Ok(nonces) = api.get_network_nonces(notification.hash, network) {
+					for nonce in last_signed.saturating_add(1)..=nonces.signed_outgoing {
+						if let Ok(Some(status)) =
+							api.get_outgoing_message_status(notification.hash, network, Some(nonce))
+						{
+							signed.push(OutgoingMessageStatus::from(status));
+						}
+					}
+					last_signed = last_signed.max(nonces.signed_outgoing);
+				}
+				stream::iter(signed)
+			})
+			.boxed();
+
+		let fut = async move {
+			sink.pipe_from_stream(signed_messages).await;
+		};
+		self.executor.spawn("thea-rpc-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, "Runtime error", Some(format!("{err:?}"))))
+		.into()
+}
@@ -29,10 +29,21 @@ use polkadex_primitives::AssetId;
 use polkadex_primitives::UNIT_BALANCE;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use thea_primitives::types::{
-	IncomingMessage, MisbehaviourReport, SignedMessage, THEA_HOLD_REASON,
+	BlsSignedMessage, ForkPeriodTier, IncomingMessage, MessageCommitment, MisbehaviourReport,
+	RelayerInfo, SchemeSignature, SignatureScheme, SignedMessage, SigningKey, THEA_HOLD_REASON,
+	THEA_RELAYER_BOND_REASON,
 };
 use thea_primitives::TheaBenchmarkHelper;
 
+fn fork_period_tiers(count: u32) -> Vec<ForkPeriodTier> {
+	(1..=count)
+		.map(|tier| ForkPeriodTier {
+			min_value: (tier as u128).saturating_mul(1000 * UNIT_BALANCE),
+			fork_period: tier.saturating_add(1).saturating_mul(20),
+		})
+		.collect()
+}
+
 fn generate_deposit_payload<T: Config>() -> Vec<Deposit<T::AccountId>> {
 	sp_std::vec![Deposit {
 		id: H256::zero().0.to_vec(),
@@ -43,6 +54,13 @@ fn generate_deposit_payload<T: Config>() -> Vec<Deposit<T::AccountId>> {
 	}]
 }
 
+fn bond_relayer<T: Config>(network: Network, relayer: &T::AccountId) {
+	let bond = 1000 * UNIT_BALANCE;
+	T::NativeCurrency::hold(&THEA_RELAYER_BOND_REASON, relayer, bond.saturated_into()).unwrap();
+	<Relayers<T>>::insert(network, relayer, RelayerInfo { bond, unbonding_at: None });
+	<ActiveRelayers<T>>::mutate(network, |relayers| relayers.try_push(relayer.clone()).unwrap());
+}
+
 benchmarks! {
 	submit_incoming_message {
 		let b in 0 .. 256; // keep withing u8 range
@@ -54,8 +72,11 @@ benchmarks! {
 			payload_type: PayloadType::L1Deposit
 		};
 		let relayer: T::AccountId = T::AccountId::decode(&mut &[0u8; 32][..]).unwrap();
-		<AllowListTestingRelayers<T>>::insert(0u8, relayer.clone());
 		<T as pallet::Config>::NativeCurrency::mint_into(&relayer, (100000*UNIT_BALANCE).saturated_into()).unwrap();
+		bond_relayer::<T>(0u8, &relayer);
+		// Valuing the deposits for the fork period is the worst case
+		T::TheaBenchmarkHelper::set_metadata(AssetId::Asset(1));
+		<NetworkConfig<T>>::mutate(0u8, |config| config.fork_period_tiers = fork_period_tiers(MAX_FORK_PERIOD_TIERS));
 	}: _(RawOrigin::Signed(relayer), message, 10000*UNIT_BALANCE)
 	verify {
 		// Nonce is updated only after execute_at number of blocks
@@ -192,7 +213,7 @@ benchmarks! {
 		};
 		let incoming_message = IncomingMessage {
 			message: message,
-			relayer: relayer,
+			relayer: relayer.clone(),
 			stake: relayer_stake_amount,
 			execute_at: 1000
 		};
@@ -202,7 +223,211 @@ benchmarks! {
 			stake: fisherman_stake_amount
 		};
 		<MisbehaviourReports<T>>::insert(2, 0, report);
+		bond_relayer::<T>(2u8, &relayer);
 	}: _(RawOrigin::Root, 2, 0, true)
+	verify {
+		assert!(<Relayers<T>>::get(2u8, &relayer).is_none());
+	}
+
+	register_relayer {
+		let network: u8 = 2;
+		let relayer: T::AccountId = T::AccountId::decode(&mut &[0u8; 32][..]).unwrap();
+		<T as pallet::Config>::NativeCurrency::mint_into(&relayer, (100000*UNIT_BALANCE).saturated_into()).unwrap();
+	}: _(RawOrigin::Signed(relayer.clone()), network, 1000*UNIT_BALANCE)
+	verify {
+		assert!(<ActiveRelayers<T>>::get(network).contains(&relayer));
+	}
+
+	unbond_relayer {
+		let network: u8 = 2;
+		let relayer: T::AccountId = T::AccountId::decode(&mut &[0u8; 32][..]).unwrap();
+		<T as pallet::Config>::NativeCurrency::mint_into(&relayer, (100000*UNIT_BALANCE).saturated_into()).unwrap();
+		bond_relayer::<T>(network, &relayer);
+	}: _(RawOrigin::Signed(relayer.clone()), network)
+	verify {
+		assert!(!<ActiveRelayers<T>>::get(network).contains(&relayer));
+	}
+
+	withdraw_unbonded {
+		let network: u8 = 2;
+		let relayer: T::AccountId = T::AccountId::decode(&mut &[0u8; 32][..]).unwrap();
+		<T as pallet::Config>::NativeCurrency::mint_into(&relayer, (100000*UNIT_BALANCE).saturated_into()).unwrap();
+		bond_relayer::<T>(network, &relayer);
+		<Relayers<T>>::insert(network, &relayer, RelayerInfo { bond: 1000*UNIT_BALANCE, unbonding_at: Some(0) });
+		<ActiveRelayers<T>>::remove(network);
+	}: _(RawOrigin::Signed(relayer.clone()), network)
+	verify {
+		assert!(<Relayers<T>>::get(network, &relayer).is_none());
+	}
+
+	set_signature_scheme {
+		let network: u8 = 2;
+	}: _(RawOrigin::Root, network, SignatureScheme::Bls)
+	verify {
+		assert_eq!(<NetworkConfig<T>>::get(network).signature_scheme, SignatureScheme::Bls);
+	}
+
+	submit_bls_public_key {
+		let secret = [1u8; 32];
+		let public = bls_primitives::public_from_secret(&secret).unwrap();
+		let proof_of_possession = bls_primitives::sign_with_secret(&secret, public.as_ref()).unwrap();
+		let signature: T::Signature = sp_core::ecdsa::Signature::default().into();
+	}: _(RawOrigin::None, 0, 0, public, proof_of_possession, signature)
+	verify {
+		assert_eq!(<BlsAuthorities<T>>::get(0, 0), Some(public));
+	}
+
+	submit_signing_key {
+		let key = SigningKey::Ed25519(sp_core::ed25519::Public::from_raw([1u8; 32]));
+		let signature: T::Signature = sp_core::ecdsa::Signature::default().into();
+	}: _(RawOrigin::None, 0, 0, key.clone(), signature)
+	verify {
+		assert_eq!(<SigningKeys<T>>::get(0, (SignatureScheme::Ed25519, 0)), Some(key));
+	}
+
+	submit_scheme_signed_outgoing_messages {
+		let x in 1 .. 255;
+		let signature = SchemeSignature::Ed25519(sp_core::ed25519::Signature::from_raw([1u8; 64]));
+		let mut signatures = Vec::new();
+		for network in 0..x as u8 {
+			let message = Message {
+				block_no: u64::MAX,
+				nonce: 1,
+				data: generate_deposit_payload::<T>().encode(),
+				network,
+				payload_type: PayloadType::L1Deposit
+			};
+			<SchemeSignedOutgoingMessages<T>>::insert(network, 1, SignedMessage::new(message.clone(), 0, 1, signature.clone()));
+			<OutgoingMessages<T>>::insert(network, 1, message);
+			signatures.push((network, 1, signature.clone()));
+		}
+	}: _(RawOrigin::None, 0, 0, signatures)
+	verify {
+		for network in 0..x as u8 {
+			assert_eq!(<SchemeSignedOutgoingMessages<T>>::get(network, 1).unwrap().signatures.len(), 2);
+		}
+	}
+
+	submit_bls_signed_outgoing_messages {
+		let x in 1 .. 255;
+		let mut signatures = Vec::new();
+		for network in 0..x as u8 {
+			let message = Message {
+				block_no: u64::MAX,
+				nonce: 1,
+				data: generate_deposit_payload::<T>().encode(),
+				network,
+				payload_type: PayloadType::L1Deposit
+			};
+			let msg_hash = Pallet::<T>::outgoing_message_hash(&message);
+			// Partial signature of another authority to aggregate with
+			let signature = bls_primitives::sign_with_secret(&[2u8; 32], &msg_hash).unwrap();
+			<BlsSignedOutgoingMessages<T>>::insert(network, 1, BlsSignedMessage::new(message.clone(), 0, 1, signature));
+			<OutgoingMessages<T>>::insert(network, 1, message);
+			signatures.push((network, 1, bls_primitives::sign_with_secret(&[1u8; 32], &msg_hash).unwrap()));
+		}
+	}: _(RawOrigin::None, 0, 0, signatures)
+	verify {
+		for network in 0..x as u8 {
+			assert!(<BlsSignedOutgoingMessages<T>>::get(network, 1).unwrap().contains_signature(&0));
+		}
+	}
+
+	set_network_pause {
+		let network: u8 = 2;
+		<NetworkPauseStates<T>>::insert(network, PauseState::Ingress);
+	}: _(RawOrigin::Root, network, None)
+	verify {
+		assert_eq!(<NetworkPauseStates<T>>::get(network), None);
+	}
+
+	set_asset_pause {
+		let asset_id: u128 = 100;
+		<AssetPauseStates<T>>::insert(asset_id, PauseState::Ingress);
+	}: _(RawOrigin::Root, asset_id, None)
+	verify {
+		assert_eq!(<AssetPauseStates<T>>::get(asset_id), None);
+	}
+
+	set_commitment_period {
+		let network: u8 = 2;
+		<SignedOutgoingNonce<T>>::insert(network, 10);
+	}: _(RawOrigin::Root, network, Some(10))
+	verify {
+		assert_eq!(<CommitmentPeriods<T>>::get(network), Some(10));
+		assert_eq!(<CommittedOutgoingNonce<T>>::get(network), 10);
+	}
+
+	set_fork_period_tiers {
+		let x in 0 .. MAX_FORK_PERIOD_TIERS;
+		let network: u8 = 2;
+		let tiers = fork_period_tiers(x);
+	}: _(RawOrigin::Root, network, tiers.clone())
+	verify {
+		assert_eq!(<NetworkConfig<T>>::get(network).fork_period_tiers, tiers);
+	}
+
+	set_relayer_reward {
+		let network: u8 = 2;
+	}: _(RawOrigin::Root, network, UNIT_BALANCE)
+	verify {
+		assert_eq!(<RelayerRewardPerMessage<T>>::get(network), UNIT_BALANCE);
+	}
+
+	claim_relayer_rewards {
+		let relayer: T::AccountId = T::AccountId::decode(&mut &[0u8; 32][..]).unwrap();
+		let rewards = 10 * UNIT_BALANCE;
+		<T as pallet::Config>::NativeCurrency::mint_into(&T::RelayerRewardsAccount::get(), (1000 * UNIT_BALANCE).saturated_into()).unwrap();
+		<T as pallet::Config>::NativeCurrency::mint_into(&relayer, UNIT_BALANCE.saturated_into()).unwrap();
+		<RelayerRewards<T>>::insert(&relayer, rewards);
+		<UnclaimedRelayerRewards<T>>::put(rewards);
+	}: _(RawOrigin::Signed(relayer.clone()))
+	verify {
+		assert_eq!(<RelayerRewards<T>>::get(&relayer), 0);
+	}
+
+	submit_signed_commitments {
+		let x in 1 .. 255;
+		let signature: T::Signature = sp_core::ecdsa::Signature::default().into();
+		let mut signatures = Vec::new();
+		for network in 0..x {
+			let network = network as u8;
+			let commitment = MessageCommitment {
+				block_no: 10,
+				nonce: 1,
+				network,
+				first_nonce: 1,
+				last_nonce: MAX_COMMITTED_MESSAGES,
+				root: H256::zero(),
+			};
+			<MessageCommitments<T>>::insert(network, 1, commitment);
+			signatures.push((network, 1, signature.clone()));
+		}
+	}: _(RawOrigin::None, 1, 0, signatures)
+	verify {
+		assert!(<SignedMessageCommitments<T>>::get(0, 1).is_some());
+	}
+
+	commit_outgoing_messages {
+		let x in 0 .. MAX_COMMITTED_MESSAGES as u32;
+		let network: u8 = 2;
+		<CommitmentPeriods<T>>::insert(network, 1);
+		for nonce in 1..=x as u64 {
+			let message = Message {
+				block_no: 1,
+				nonce,
+				data: generate_deposit_payload::<T>().encode(),
+				network,
+				payload_type: PayloadType::L1Deposit
+			};
+			<OutgoingMessages<T>>::insert(network, nonce, message);
+		}
+		<OutgoingNonce<T>>::insert(network, x as u64);
+	}: {
+		<Thea<T>>::commit_outgoing_messages(network, 1u32.into());
+	} verify {
+		assert_eq!(<CommittedOutgoingNonce<T>>::get(network), x as u64);
+	}
 
 	on_initialize {
 		let x in 1 .. 1_000;
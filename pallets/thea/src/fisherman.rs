@@ -0,0 +1,181 @@
+// This file is part of Polkadex.
+//
+// Copyright (c) 2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Fisherman offchain worker.
+//!
+//! Watches the incoming messages queue and reports every message that doesn't match the
+//! foreign chain before it is executed. The worker is opt-in: it only runs on nodes that have a
+//! fisherman key (`thfm`) in their keystore.
+
+use crate::{
+	pallet::{ActiveNetworks, IncomingMessagesQueue},
+	Call, Config, Pallet,
+};
+use frame_system::{
+	offchain::{SendSignedTransaction, Signer},
+	pallet_prelude::BlockNumberFor,
+};
+use parity_scale_codec::Encode;
+use serde::{de::IgnoredAny, Deserialize, Serialize};
+use sp_core::offchain::{Duration, StorageKind};
+use sp_runtime::{
+	offchain::{http, storage::StorageValueRef},
+	SaturatedConversion,
+};
+use sp_std::{vec, vec::Vec};
+use thea_primitives::{types::Message, Network};
+
+/// Offchain local storage key of the JSON-RPC endpoint used by [`JsonRpcSource`]
+pub const FISHERMAN_RPC_URL: &[u8] = b"thea::fisherman::rpc_url";
+/// Offchain local storage prefix of the messages already reported by this node
+const FISHERMAN_REPORTED: &[u8] = b"thea::fisherman::reported";
+/// Number of blocks to wait before reporting the same message again
+const REPORT_RETRY_BLOCKS: u32 = 10;
+
+pub mod crypto {
+	use super::super::FISHERMAN;
+	use sp_application_crypto::{app_crypto, sr25519};
+	use sp_runtime::{MultiSignature, MultiSigner};
+
+	app_crypto!(sr25519, FISHERMAN);
+
+	/// Fisherman key used to sign misbehaviour reports
+	pub struct FishermanAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for FishermanAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericPublic = sp_core::sr25519::Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+	}
+}
+
+/// Independent source of truth for the messages emitted by foreign chains
+pub trait ForeignChainSource {
+	/// Returns the message emitted by the foreign chain for the given network and nonce or
+	/// `None` if the foreign chain never emitted it.
+	fn get_message(network: Network, nonce: u64) -> Result<Option<Message>, &'static str>;
+}
+
+/// Fetches messages over JSON-RPC (`thea_getMessage`) from the endpoint stored under
+/// [`FISHERMAN_RPC_URL`] in the persistent offchain local storage.
+pub struct JsonRpcSource;
+
+#[derive(Serialize)]
+struct JsonRpcRequest<'a> {
+	jsonrpc: &'a str,
+	id: u64,
+	method: &'a str,
+	params: (Network, u64),
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse {
+	#[serde(default)]
+	result: Option<Message>,
+	#[serde(default)]
+	error: Option<IgnoredAny>,
+}
+
+impl ForeignChainSource for JsonRpcSource {
+	fn get_message(network: Network, nonce: u64) -> Result<Option<Message>, &'static str> {
+		let url = sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, FISHERMAN_RPC_URL)
+			.ok_or("Fisherman rpc url not set")?;
+		let url = sp_std::str::from_utf8(&url).map_err(|_| "Invalid fisherman rpc url")?;
+		let body = serde_json::to_vec(&JsonRpcRequest {
+			jsonrpc: "2.0",
+			id: 1,
+			method: "thea_getMessage",
+			params: (network, nonce),
+		})
+		.map_err(|_| "Unable to serialize request")?;
+
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(5_000));
+		let pending = http::Request::post(url, vec![body])
+			.add_header("Content-Type", "application/json")
+			.deadline(deadline)
+			.send()
+			.map_err(|_| "Unable to send request")?;
+		let response = pending
+			.try_wait(deadline)
+			.map_err(|_| "Deadline reached")?
+			.map_err(|_| "Request failed")?;
+		if response.code != 200u16 {
+			log::warn!(target:"thea","Unexpected status code from fisherman rpc: {:?}",response.code);
+			return Err("Unexpected status code");
+		}
+
+		let body = response.body().collect::<Vec<u8>>();
+		let response: JsonRpcResponse =
+			serde_json::from_slice(&body).map_err(|_| "Response failed deserialize")?;
+		if response.error.is_some() {
+			return Err("Fisherman rpc returned an error");
+		}
+		Ok(response.result)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Checks every queued incoming message against `T::ForeignChainSource` and reports the
+	/// ones that don't match while they can still be reported.
+	pub fn run_fisherman(blk: BlockNumberFor<T>) -> Result<(), &'static str> {
+		let signer = Signer::<T, T::FishermanId>::any_account();
+		if !signer.can_sign() {
+			return Ok(());
+		}
+		let blk = blk.saturated_into::<u32>();
+
+		for network in <ActiveNetworks<T>>::get() {
+			for (nonce, queued) in <IncomingMessagesQueue<T>>::iter_prefix(network) {
+				// A report submitted now is included in the next block at the earliest, after
+				// its on_initialize has executed the message.
+				if queued.execute_at <= blk.saturating_add(1) {
+					continue;
+				}
+				let mut key = FISHERMAN_REPORTED.to_vec();
+				key.append(
+					&mut (network, nonce, sp_io::hashing::blake2_256(&queued.message.encode()))
+						.encode(),
+				);
+				let reported = StorageValueRef::persistent(&key);
+				if let Ok(Some(reported_at)) = reported.get::<u32>() {
+					if blk < reported_at.saturating_add(REPORT_RETRY_BLOCKS) {
+						continue;
+					}
+				}
+
+				match T::ForeignChainSource::get_message(network, nonce) {
+					Err(err) => {
+						log::warn!(target:"thea","Fisherman unable to fetch message for network {:?} nonce {:?}: {:?}",network, nonce, err);
+						continue;
+					},
+					Ok(Some(expected)) if expected == queued.message => continue,
+					Ok(_) => {},
+				}
+
+				log::info!(target:"thea","Fisherman reporting message for network {:?} nonce {:?}",network, nonce);
+				match signer
+					.send_signed_transaction(|_| Call::<T>::report_misbehaviour { network, nonce })
+				{
+					Some((_, Ok(()))) => reported.set(&blk),
+					_ => log::error!(target:"thea","Error submitting misbehaviour report"),
+				}
+			}
+		}
+		Ok(())
+	}
+}
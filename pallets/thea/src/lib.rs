@@ -27,24 +27,36 @@
 //! * keep track of egress messages;
 //! * handle validator session changes;
 
-use frame_support::{pallet_prelude::*, traits::Get, BoundedVec, Parameter};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Contains, Get},
+	BoundedVec, Parameter,
+};
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use parity_scale_codec::Encode;
 use sp_core::crypto::KeyTypeId;
+use sp_core::H256;
 use sp_runtime::{
-	traits::{BlockNumberProvider, Member},
+	traits::{BlockNumberProvider, Keccak256, Member},
 	transaction_validity::{InvalidTransaction, TransactionValidity, ValidTransaction},
 	RuntimeAppPublic, SaturatedConversion,
 };
-use sp_std::prelude::*;
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 use thea_primitives::{
-	types::{Message, NetworkType, PayloadType},
+	types::{
+		signature_threshold, BlsSignedMessage, ForkPeriodTier, IncomingMessage, Message,
+		MessageCommitment, MessageProof, NetworkNonces, NetworkType, OutgoingMessageStatus,
+		PauseState, PayloadType, SchemeSignature, SignatureScheme, SignedMessage, SigningKey,
+		Withdraw,
+	},
 	Network, ValidatorSet, GENESIS_AUTHORITY_SET_ID,
 };
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod fisherman;
+pub mod migration;
 #[cfg(test)]
 mod mock;
 mod session;
@@ -55,6 +67,12 @@ pub mod validation;
 pub mod weights;
 
 pub const THEA: KeyTypeId = KeyTypeId(*b"thea");
+pub const FISHERMAN: KeyTypeId = KeyTypeId(*b"thfm");
+
+/// Maximum number of outgoing messages included in a single message commitment
+pub const MAX_COMMITTED_MESSAGES: u64 = 512;
+/// Maximum number of fork period tiers of a network
+pub const MAX_FORK_PERIOD_TIERS: u32 = 16;
 
 pub mod ecdsa {
 	mod app_ecdsa {
@@ -88,6 +106,22 @@ pub trait TheaWeightInfo {
 	fn report_misbehaviour() -> Weight;
 	fn handle_misbehaviour() -> Weight;
 	fn on_initialize(x: u32) -> Weight;
+	fn register_relayer() -> Weight;
+	fn unbond_relayer() -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn set_signature_scheme() -> Weight;
+	fn submit_bls_public_key() -> Weight;
+	fn submit_bls_signed_outgoing_messages(x: u32) -> Weight;
+	fn set_network_pause() -> Weight;
+	fn set_asset_pause() -> Weight;
+	fn set_commitment_period() -> Weight;
+	fn submit_signed_commitments(x: u32) -> Weight;
+	fn commit_outgoing_messages(x: u32) -> Weight;
+	fn submit_signing_key() -> Weight;
+	fn submit_scheme_signed_outgoing_messages(x: u32) -> Weight;
+	fn set_fork_period_tiers(x: u32) -> Weight;
+	fn set_relayer_reward() -> Weight;
+	fn claim_relayer_rewards() -> Weight;
 }
 
 #[frame_support::pallet]
@@ -100,16 +134,23 @@ pub mod pallet {
 		},
 		transactional,
 	};
-	use frame_system::offchain::SendTransactionTypes;
+	use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendTransactionTypes};
 	use polkadex_primitives::Balance;
 	use sp_std::collections::btree_set::BTreeSet;
 	use thea_primitives::{
-		types::{IncomingMessage, Message, MisbehaviourReport, SignedMessage, THEA_HOLD_REASON},
+		types::{
+			BlsSignedMessage, IncomingMessage, Message, MisbehaviourReport, PauseState,
+			RelayerInfo, SignedMessage, THEA_HOLD_REASON, THEA_RELAYER_BOND_REASON,
+		},
 		TheaIncomingExecutor, TheaOutgoingExecutor,
 	};
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
+	pub trait Config:
+		frame_system::Config
+		+ SendTransactionTypes<Call<Self>>
+		+ CreateSignedTransaction<Call<Self>>
+	{
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Authority identifier type
@@ -142,6 +183,36 @@ pub mod pallet {
 		/// Governance Origin
 		type TheaGovernanceOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+		/// Origin that can pause networks and assets without governance (e.g. a security
+		/// council), lifting a pause still requires `TheaGovernanceOrigin`
+		type PauseOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// Maximum number of active relayers per network
+		#[pallet::constant]
+		type MaxRelayers: Get<u32>;
+
+		/// Number of blocks a relayer has to wait after unbonding before the bond is released
+		#[pallet::constant]
+		type RelayerUnbondingPeriod: Get<u32>;
+
+		/// Number of blocks each relayer is the leader for submitting new messages
+		#[pallet::constant]
+		type RelayerRotationPeriod: Get<u32>;
+
+		/// Account paying relayer rewards, funded with withdrawal fees by Thea Executor
+		#[pallet::constant]
+		type RelayerRewardsAccount: Get<Self::AccountId>;
+
+		/// Networks whose incoming messages are only accepted with proofs of inclusion, e.g.
+		/// verified by a light client, instead of being relayed with a stake
+		type ProvenNetworks: Contains<Network>;
+
+		/// Key used by the fisherman worker to sign misbehaviour reports
+		type FishermanId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// Source of truth for foreign chain messages used by the fisherman worker
+		type ForeignChainSource: fisherman::ForeignChainSource;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		type TheaBenchmarkHelper: thea_primitives::TheaBenchmarkHelper;
 
@@ -149,7 +220,11 @@ pub mod pallet {
 		type WeightInfo: TheaWeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -198,6 +273,58 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Outgoing messages signed with an aggregated BLS signature
+	/// first key: Network
+	/// second key: Message nonce
+	#[pallet::storage]
+	#[pallet::getter(fn bls_signed_outgoing_messages)]
+	pub(super) type BlsSignedOutgoingMessages<T: Config> =
+		StorageDoubleMap<_, Identity, Network, Identity, u64, BlsSignedMessage, OptionQuery>;
+
+	/// Outgoing messages signed with ed25519 or sr25519
+	/// first key: Network
+	/// second key: Message nonce
+	#[pallet::storage]
+	pub(super) type SchemeSignedOutgoingMessages<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		Network,
+		Identity,
+		u64,
+		SignedMessage<SchemeSignature>,
+		OptionQuery,
+	>;
+
+	/// Ed25519 and sr25519 public keys of the authorities
+	/// first key: Validator set id
+	/// second key: (Signature scheme, Authority index)
+	#[pallet::storage]
+	#[pallet::getter(fn signing_keys)]
+	pub(super) type SigningKeys<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		thea_primitives::ValidatorSetId,
+		Identity,
+		(SignatureScheme, u32),
+		SigningKey,
+		OptionQuery,
+	>;
+
+	/// BLS public keys of the authorities
+	/// first key: Validator set id
+	/// second key: Authority index
+	#[pallet::storage]
+	#[pallet::getter(fn bls_authorities)]
+	pub(super) type BlsAuthorities<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		thea_primitives::ValidatorSetId,
+		Identity,
+		u32,
+		bls_primitives::Public,
+		OptionQuery,
+	>;
+
 	/// Incoming messages queue
 	/// first key: origin network
 	/// second key: blocknumber at which it will execute
@@ -259,10 +386,99 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
-	/// Temporary allowlist for relayer
+	/// Registered relayers and their bonds
+	/// first key: network
+	/// second key: relayer
+	#[pallet::storage]
+	#[pallet::getter(fn relayers)]
+	pub(super) type Relayers<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		Network,
+		Blake2_128Concat,
+		T::AccountId,
+		RelayerInfo<Balance>,
+		OptionQuery,
+	>;
+
+	/// Relayers allowed to submit messages, in leader rotation order
+	#[pallet::storage]
+	#[pallet::getter(fn active_relayers)]
+	pub(super) type ActiveRelayers<T: Config> =
+		StorageMap<_, Identity, Network, BoundedVec<T::AccountId, T::MaxRelayers>, ValueQuery>;
+
+	/// Reward paid to the relayer of every executed incoming message of the network
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_reward_per_message)]
+	pub(super) type RelayerRewardPerMessage<T: Config> =
+		StorageMap<_, Identity, Network, Balance, ValueQuery>;
+
+	/// Rewards accumulated by relayers and not claimed yet
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_rewards)]
+	pub(super) type RelayerRewards<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+	/// Sum of the rewards accumulated by all relayers and not claimed yet
+	#[pallet::storage]
+	#[pallet::getter(fn unclaimed_relayer_rewards)]
+	pub(super) type UnclaimedRelayerRewards<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Pause state of networks
+	#[pallet::storage]
+	#[pallet::getter(fn network_pause_state)]
+	pub(super) type NetworkPauseStates<T: Config> =
+		StorageMap<_, Identity, Network, PauseState, OptionQuery>;
+
+	/// Pause state of assets on every network
+	#[pallet::storage]
+	#[pallet::getter(fn asset_pause_state)]
+	pub(super) type AssetPauseStates<T: Config> =
+		StorageMap<_, Identity, u128, PauseState, OptionQuery>;
+
+	/// Number of blocks between two commitments of the outgoing messages of a network, validators
+	/// sign every outgoing message of networks without a commitment period
 	#[pallet::storage]
-	pub(super) type AllowListTestingRelayers<T: Config> =
-		StorageMap<_, Identity, Network, T::AccountId, OptionQuery>;
+	#[pallet::getter(fn commitment_period)]
+	pub(super) type CommitmentPeriods<T: Config> =
+		StorageMap<_, Identity, Network, u32, OptionQuery>;
+
+	/// Nonce of the last outgoing message included in a commitment grouped by network
+	#[pallet::storage]
+	pub(super) type CommittedOutgoingNonce<T: Config> =
+		StorageMap<_, Identity, Network, u64, ValueQuery>;
+
+	/// Nonce of the last message commitment grouped by network
+	#[pallet::storage]
+	#[pallet::getter(fn commitment_nonce)]
+	pub(super) type CommitmentNonce<T: Config> = StorageMap<_, Identity, Network, u64, ValueQuery>;
+
+	/// Message commitments
+	/// first key: Network
+	/// second key: Commitment nonce
+	#[pallet::storage]
+	#[pallet::getter(fn message_commitments)]
+	pub(super) type MessageCommitments<T: Config> =
+		StorageDoubleMap<_, Identity, Network, Identity, u64, MessageCommitment, OptionQuery>;
+
+	/// Signed message commitments
+	/// first key: Network
+	/// second key: Commitment nonce
+	#[pallet::storage]
+	pub(super) type SignedMessageCommitments<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		Network,
+		Identity,
+		u64,
+		SignedMessage<T::Signature, MessageCommitment>,
+		OptionQuery,
+	>;
+
+	/// Nonce of the last message commitment that reached the signature threshold
+	#[pallet::storage]
+	pub(super) type SignedCommitmentNonce<T: Config> =
+		StorageMap<_, Identity, Network, u64, ValueQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
@@ -281,6 +497,38 @@ pub mod pallet {
 		UnableToSlicePublicKeyHash(T::TheaId),
 		/// Unable to generate rotate validators payload for this network
 		UnableToGenerateValidatorSet(Network),
+		/// Relayer registered (relayer, network, bond)
+		RelayerRegistered(T::AccountId, Network, Balance),
+		/// Relayer started unbonding (relayer, network, unlock block)
+		RelayerUnbonding(T::AccountId, Network, u32),
+		/// Relayer bond withdrawn (relayer, network, bond)
+		RelayerBondWithdrawn(T::AccountId, Network, Balance),
+		/// Relayer bond slashed (relayer, network, amount)
+		RelayerSlashed(T::AccountId, Network, Balance),
+		/// Signature scheme of network updated
+		SignatureSchemeUpdated(Network, SignatureScheme),
+		/// BLS public key registered (validator set id, authority index)
+		BlsPublicKeyRegistered(thea_primitives::ValidatorSetId, u32),
+		/// Ed25519 or sr25519 public key registered (validator set id, authority index, scheme)
+		SigningKeyRegistered(thea_primitives::ValidatorSetId, u32, SignatureScheme),
+		/// Pause state of network updated (network, pause state)
+		NetworkPauseUpdated(Network, Option<PauseState>),
+		/// Pause state of asset updated (asset id, pause state)
+		AssetPauseUpdated(u128, Option<PauseState>),
+		/// Commitment period of network updated (network, period)
+		CommitmentPeriodUpdated(Network, Option<u32>),
+		/// Outgoing messages committed (network, commitment nonce, merkle root)
+		OutgoingMessagesCommitted(Network, u64, H256),
+		/// Signing of message commitment completed (network, commitment nonce)
+		CommitmentSignatureFinalized(Network, u64),
+		/// Fork period tiers of the network updated
+		ForkPeriodTiersUpdated(Network),
+		/// Relayer reward per message of the network updated
+		RelayerRewardUpdated(Network, Balance),
+		/// Relayer rewarded for delivering a message (relayer, network, nonce, reward)
+		RelayerRewarded(T::AccountId, Network, u64, Balance),
+		/// Relayer claimed rewards (relayer, amount)
+		RelayerRewardsClaimed(T::AccountId, Balance),
 	}
 
 	#[pallet::error]
@@ -295,12 +543,44 @@ pub mod pallet {
 		NotEnoughStake,
 		/// MessageNotFound
 		MessageNotFound,
+		/// Withdrawals to EVM networks could not be decoded
+		InvalidWithdrawalPayload,
 		/// No Relayer found
 		NoRelayersFound,
-		/// Not expected relayer origin
-		NotAnAllowlistedRelayer,
 		/// Nonce Error
 		NonceError,
+		/// Relayer is already registered for this network
+		RelayerAlreadyRegistered,
+		/// Relayer is not registered for this network
+		RelayerNotRegistered,
+		/// Maximum number of relayers reached for this network
+		TooManyRelayers,
+		/// Relayer is unbonding
+		RelayerUnbonding,
+		/// Relayer is not unbonding
+		RelayerNotUnbonding,
+		/// Unbonding period is not over yet
+		UnbondingPeriodNotOver,
+		/// Only the scheduled relayer can submit new messages
+		NotScheduledRelayer,
+		/// Unable to aggregate BLS signature
+		InvalidBlsSignature,
+		/// Only governance can lift a pause
+		PauseLiftNotAllowed,
+		/// Commitment period must be greater than zero
+		InvalidCommitmentPeriod,
+		/// Message commitment not found
+		CommitmentNotFound,
+		/// Tier values are not strictly increasing or there are too many tiers
+		InvalidForkPeriodTiers,
+		/// No relayer rewards to claim
+		NoRewardsToClaim,
+		/// Incoming messages of the network must be submitted with proofs
+		ProofRequired,
+		/// Call is deprecated and kept only to reserve its index
+		CallDeprecated,
+		/// Withdrawals to EVM networks must be paid out to a 20 byte address
+		InvalidEvmDestination,
 	}
 
 	#[pallet::hooks]
@@ -309,14 +589,20 @@ pub mod pallet {
 			// Every block check the next incoming nonce and if fork period is over, execute them
 			let active_networks = <ActiveNetworks<T>>::get();
 			for network in active_networks.clone() {
+				// Messages stay queued while the ingress of the network is paused
+				if <NetworkPauseStates<T>>::get(network).map_or(false, |state| state.ingress()) {
+					continue;
+				}
 				let last_processed_nonce = <IncomingNonce<T>>::get(network);
 				let next_nonce = last_processed_nonce.saturating_add(1);
-				match <IncomingMessagesQueue<T>>::take(network, next_nonce) {
+				match <IncomingMessagesQueue<T>>::get(network, next_nonce) {
 					None => continue,
 					Some(msg) => {
 						if msg.execute_at <= blk.saturated_into::<u32>() {
+							<IncomingMessagesQueue<T>>::remove(network, next_nonce);
 							<T as pallet::Config>::Executor::execute_deposits(
 								msg.message.network,
+								msg.message.nonce,
 								msg.message.data.clone(),
 							);
 							<IncomingNonce<T>>::insert(msg.message.network, next_nonce);
@@ -324,6 +610,7 @@ pub mod pallet {
 								msg.message.network,
 								msg.message.nonce,
 							));
+							Self::reward_relayer(&msg.relayer, network, next_nonce);
 							// Save the incoming message for some time
 							<IncomingMessages<T>>::insert(
 								msg.message.network,
@@ -346,13 +633,24 @@ pub mod pallet {
 					},
 				}
 			}
+			let mut committed_messages = 0u32;
+			for network in active_networks.iter() {
+				committed_messages = committed_messages
+					.saturating_add(Self::commit_outgoing_messages(*network, blk));
+			}
 			<T as pallet::Config>::WeightInfo::on_initialize(active_networks.len() as u32)
+				.saturating_add(<T as pallet::Config>::WeightInfo::commit_outgoing_messages(
+					committed_messages,
+				))
 		}
 		fn offchain_worker(blk: BlockNumberFor<T>) {
 			log::debug!(target:"thea","Thea offchain worker started");
 			if let Err(err) = Self::run_thea_validation(blk) {
 				log::error!(target:"thea","Error while running thea: {:?}",err);
 			}
+			if let Err(err) = Self::run_fisherman(blk) {
+				log::error!(target:"thea","Error while running fisherman: {:?}",err);
+			}
 		}
 	}
 
@@ -365,6 +663,31 @@ pub mod pallet {
 				Call::submit_signed_outgoing_messages { auth_index, signatures, id } => {
 					Self::validate_signed_outgoing_message(auth_index, id, signatures)
 				},
+				Call::submit_bls_public_key {
+					auth_index,
+					id,
+					public,
+					proof_of_possession,
+					signature,
+				} => Self::validate_bls_public_key(
+					auth_index,
+					id,
+					public,
+					proof_of_possession,
+					signature,
+				),
+				Call::submit_bls_signed_outgoing_messages { auth_index, id, signatures } => {
+					Self::validate_bls_signed_outgoing_message(auth_index, id, signatures)
+				},
+				Call::submit_signed_commitments { auth_index, id, signatures } => {
+					Self::validate_signed_commitments(auth_index, id, signatures)
+				},
+				Call::submit_signing_key { auth_index, id, key, signature } => {
+					Self::validate_signing_key(auth_index, id, key, signature)
+				},
+				Call::submit_scheme_signed_outgoing_messages { auth_index, id, signatures } => {
+					Self::validate_scheme_signed_outgoing_message(auth_index, id, signatures)
+				},
 				_ => InvalidTransaction::Call.into(),
 			}
 		}
@@ -382,9 +705,10 @@ pub mod pallet {
 			stake: Balance,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
-			let expected_signer = <AllowListTestingRelayers<T>>::get(payload.network)
-				.ok_or(Error::<T>::NoRelayersFound)?;
-			ensure!(signer == expected_signer, Error::<T>::NotAnAllowlistedRelayer);
+			ensure!(!T::ProvenNetworks::contains(&payload.network), Error::<T>::ProofRequired);
+			let relayers = <ActiveRelayers<T>>::get(payload.network);
+			ensure!(!relayers.is_empty(), Error::<T>::NoRelayersFound);
+			ensure!(relayers.contains(&signer), Error::<T>::RelayerNotRegistered);
 
 			let config = <NetworkConfig<T>>::get(payload.network);
 
@@ -397,8 +721,15 @@ pub mod pallet {
 
 			match <IncomingMessagesQueue<T>>::get(payload.network, payload.nonce) {
 				None => {
+					// Only the leader of the current rotation slot can propose a new message,
+					// others can still override it with a higher stake.
+					ensure!(
+						Self::scheduled_relayer(payload.network).as_ref() == Some(&signer),
+						Error::<T>::NotScheduledRelayer
+					);
 					// Lock balance
 					T::NativeCurrency::hold(&THEA_HOLD_REASON, &signer, stake.saturated_into())?;
+					let fork_period = Self::fork_period(&config, &payload);
 					// Put it in a queue
 					<IncomingMessagesQueue<T>>::insert(
 						payload.network,
@@ -409,7 +740,7 @@ pub mod pallet {
 							stake,
 							execute_at: frame_system::Pallet::<T>::current_block_number()
 								.saturated_into::<u32>()
-								.saturating_add(config.fork_period),
+								.saturating_add(fork_period),
 						},
 					);
 				},
@@ -427,13 +758,14 @@ pub mod pallet {
 							&signer,
 							stake.saturated_into(),
 						)?;
-						existing_payload.message = payload;
-						existing_payload.relayer = signer;
-						existing_payload.stake = stake;
+						// The overriding message can move a different value
 						existing_payload.execute_at =
 							frame_system::Pallet::<T>::current_block_number()
 								.saturated_into::<u32>()
-								.saturating_add(config.fork_period);
+								.saturating_add(Self::fork_period(&config, &payload));
+						existing_payload.message = payload;
+						existing_payload.relayer = signer;
+						existing_payload.stake = stake;
 						<IncomingMessagesQueue<T>>::insert(
 							existing_payload.message.network,
 							existing_payload.message.nonce,
@@ -456,7 +788,7 @@ pub mod pallet {
 			network: Network,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			Self::execute_withdrawals(network, data)?;
+			Self::queue_outgoing_message(network, data);
 			Ok(())
 		}
 
@@ -651,6 +983,8 @@ pub mod pallet {
 							report.stake.saturated_into(),
 							Precision::BestEffort,
 						)?;
+						// Slash relayer bond and remove it from the relayer set
+						Self::slash_relayer(network, &report.reported_msg.relayer)?;
 					} else {
 						// Insert back the message to queue
 						<IncomingMessagesQueue<T>>::insert(
@@ -671,27 +1005,455 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Adds a relayer origin for deposits - will be removed after mainnet testing
+		/// Deprecated: relayers register themselves with `register_relayer`. Kept to reserve the
+		/// call index.
 		#[pallet::call_index(9)]
 		#[pallet::weight(< T as Config >::WeightInfo::add_thea_network())]
 		pub fn add_relayer_origin_for_network(
+			origin: OriginFor<T>,
+			_network: Network,
+			_relayer: T::AccountId,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Err(Error::<T>::CallDeprecated.into())
+		}
+
+		/// Register as a relayer for the given network by bonding at least `min_stake`
+		#[pallet::call_index(10)]
+		#[pallet::weight(< T as Config >::WeightInfo::register_relayer())]
+		#[transactional]
+		pub fn register_relayer(
+			origin: OriginFor<T>,
+			network: Network,
+			bond: Balance,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			ensure!(
+				!<Relayers<T>>::contains_key(network, &relayer),
+				Error::<T>::RelayerAlreadyRegistered
+			);
+			let config = <NetworkConfig<T>>::get(network);
+			ensure!(bond >= config.min_stake, Error::<T>::NotEnoughStake);
+			T::NativeCurrency::hold(&THEA_RELAYER_BOND_REASON, &relayer, bond.saturated_into())?;
+			<ActiveRelayers<T>>::try_mutate(network, |relayers| {
+				relayers.try_push(relayer.clone()).map_err(|_| Error::<T>::TooManyRelayers)
+			})?;
+			<Relayers<T>>::insert(network, &relayer, RelayerInfo { bond, unbonding_at: None });
+			Self::deposit_event(Event::<T>::RelayerRegistered(relayer, network, bond));
+			Ok(())
+		}
+
+		/// Leave the relayer set of the given network, bond is released after the unbonding
+		/// period
+		#[pallet::call_index(11)]
+		#[pallet::weight(< T as Config >::WeightInfo::unbond_relayer())]
+		#[transactional]
+		pub fn unbond_relayer(origin: OriginFor<T>, network: Network) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			let mut info =
+				<Relayers<T>>::get(network, &relayer).ok_or(Error::<T>::RelayerNotRegistered)?;
+			ensure!(info.unbonding_at.is_none(), Error::<T>::RelayerUnbonding);
+			<ActiveRelayers<T>>::mutate(network, |relayers| relayers.retain(|r| *r != relayer));
+			let unbonding_at = frame_system::Pallet::<T>::current_block_number()
+				.saturated_into::<u32>()
+				.saturating_add(T::RelayerUnbondingPeriod::get());
+			info.unbonding_at = Some(unbonding_at);
+			<Relayers<T>>::insert(network, &relayer, info);
+			Self::deposit_event(Event::<T>::RelayerUnbonding(relayer, network, unbonding_at));
+			Ok(())
+		}
+
+		/// Release the bond of a relayer once the unbonding period is over
+		#[pallet::call_index(12)]
+		#[pallet::weight(< T as Config >::WeightInfo::withdraw_unbonded())]
+		#[transactional]
+		pub fn withdraw_unbonded(origin: OriginFor<T>, network: Network) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			let info =
+				<Relayers<T>>::get(network, &relayer).ok_or(Error::<T>::RelayerNotRegistered)?;
+			let unbonding_at = info.unbonding_at.ok_or(Error::<T>::RelayerNotUnbonding)?;
+			ensure!(
+				frame_system::Pallet::<T>::current_block_number().saturated_into::<u32>()
+					>= unbonding_at,
+				Error::<T>::UnbondingPeriodNotOver
+			);
+			T::NativeCurrency::release(
+				&THEA_RELAYER_BOND_REASON,
+				&relayer,
+				info.bond.saturated_into(),
+				Precision::BestEffort,
+			)?;
+			<Relayers<T>>::remove(network, &relayer);
+			Self::deposit_event(Event::<T>::RelayerBondWithdrawn(relayer, network, info.bond));
+			Ok(())
+		}
+
+		/// Set the signature scheme used to sign outgoing messages of the network
+		#[pallet::call_index(13)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_signature_scheme())]
+		pub fn set_signature_scheme(
+			origin: OriginFor<T>,
+			network: Network,
+			scheme: SignatureScheme,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			<NetworkConfig<T>>::mutate(network, |config| config.signature_scheme = scheme);
+			Self::deposit_event(Event::<T>::SignatureSchemeUpdated(network, scheme));
+			Ok(())
+		}
+
+		/// Register the BLS public key of an authority for the given validator set
+		#[pallet::call_index(14)]
+		#[pallet::weight(< T as Config >::WeightInfo::submit_bls_public_key())]
+		pub fn submit_bls_public_key(
+			origin: OriginFor<T>,
+			auth_index: u32,
+			id: thea_primitives::ValidatorSetId,
+			public: bls_primitives::Public,
+			proof_of_possession: bls_primitives::Signature,
+			signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			// Both signatures are already verified in `validate_unsigned`
+			let _ = (proof_of_possession, signature);
+			<BlsAuthorities<T>>::insert(id, auth_index, public);
+			Self::deposit_event(Event::<T>::BlsPublicKeyRegistered(id, auth_index));
+			Ok(())
+		}
+
+		/// BLS signed outgoing messages, aggregated into one signature per message
+		#[pallet::call_index(15)]
+		#[pallet::weight(< T as Config >::WeightInfo::submit_bls_signed_outgoing_messages(signatures.len() as u32))]
+		pub fn submit_bls_signed_outgoing_messages(
+			origin: OriginFor<T>,
+			auth_index: u32,
+			id: thea_primitives::ValidatorSetId,
+			signatures: Vec<(Network, u64, bls_primitives::Signature)>,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			for (network, nonce, signature) in signatures {
+				let message = match <OutgoingMessages<T>>::get(network, nonce) {
+					None => return Err(Error::<T>::MessageNotFound.into()),
+					Some(msg) => msg,
+				};
+				let signed_msg = match <BlsSignedOutgoingMessages<T>>::get(network, nonce) {
+					None => BlsSignedMessage::new(message, id, auth_index, signature),
+					Some(mut signed_msg) => {
+						signed_msg
+							.add_signature(message, id, auth_index, signature)
+							.map_err(|_| Error::<T>::InvalidBlsSignature)?;
+						signed_msg
+					},
+				};
+				let auth_len = <Authorities<T>>::get(signed_msg.validator_set_id).len();
+				if signed_msg.threshold_reached(auth_len)
+					&& <SignedOutgoingNonce<T>>::get(network) < nonce
+				{
+					<SignedOutgoingNonce<T>>::insert(network, nonce);
+					// Emit an event
+					Self::deposit_event(Event::<T>::TheaSignatureFinalized(network, nonce));
+				}
+				let total_signatures = signed_msg.signer_indexes().len();
+				<BlsSignedOutgoingMessages<T>>::insert(network, nonce, signed_msg);
+				// Emit an event
+				Self::deposit_event(Event::<T>::TheaSignatureUpdated(
+					network,
+					nonce,
+					total_signatures as u16,
+				));
+			}
+
+			Ok(())
+		}
+
+		/// Pause ingress, egress or both directions of a network, `None` resumes it
+		#[pallet::call_index(16)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_network_pause())]
+		pub fn set_network_pause(
 			origin: OriginFor<T>,
 			network: Network,
-			relayer: T::AccountId,
+			state: Option<PauseState>,
+		) -> DispatchResult {
+			let current = <NetworkPauseStates<T>>::get(network);
+			Self::ensure_pause_origin(origin, current, state)?;
+			<NetworkPauseStates<T>>::set(network, state);
+			if current.map_or(false, |current| current.ingress())
+				&& !state.map_or(false, |state| state.ingress())
+			{
+				<T as pallet::Config>::Executor::on_network_ingress_resumed(network);
+			}
+			Self::deposit_event(Event::<T>::NetworkPauseUpdated(network, state));
+			Ok(())
+		}
+
+		/// Pause ingress, egress or both directions of an asset on every network, `None`
+		/// resumes it
+		#[pallet::call_index(17)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_asset_pause())]
+		pub fn set_asset_pause(
+			origin: OriginFor<T>,
+			asset_id: u128,
+			state: Option<PauseState>,
+		) -> DispatchResult {
+			let current = <AssetPauseStates<T>>::get(asset_id);
+			Self::ensure_pause_origin(origin, current, state)?;
+			<AssetPauseStates<T>>::set(asset_id, state);
+			if current.map_or(false, |current| current.ingress())
+				&& !state.map_or(false, |state| state.ingress())
+			{
+				<T as pallet::Config>::Executor::on_asset_ingress_resumed(asset_id);
+			}
+			Self::deposit_event(Event::<T>::AssetPauseUpdated(asset_id, state));
+			Ok(())
+		}
+
+		/// Commit the outgoing messages of the network into a Merkle root every `period` blocks,
+		/// validators then sign the root instead of every message. `None` goes back to signing
+		/// every message
+		#[pallet::call_index(18)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_commitment_period())]
+		pub fn set_commitment_period(
+			origin: OriginFor<T>,
+			network: Network,
+			period: Option<u32>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			<AllowListTestingRelayers<T>>::insert(network, relayer);
+			ensure!(period != Some(0), Error::<T>::InvalidCommitmentPeriod);
+			if period.is_some() {
+				// Messages signed individually don't need to be committed
+				let signed_nonce = <SignedOutgoingNonce<T>>::get(network);
+				<CommittedOutgoingNonce<T>>::mutate(network, |nonce| {
+					*nonce = (*nonce).max(signed_nonce)
+				});
+			} else {
+				// Committed messages are signed through their commitments
+				let committed_nonce = <CommittedOutgoingNonce<T>>::get(network);
+				<SignedOutgoingNonce<T>>::mutate(network, |nonce| {
+					*nonce = (*nonce).max(committed_nonce)
+				});
+			}
+			<CommitmentPeriods<T>>::set(network, period);
+			Self::deposit_event(Event::<T>::CommitmentPeriodUpdated(network, period));
+			Ok(())
+		}
+
+		/// Submit signatures of message commitments
+		#[pallet::call_index(19)]
+		#[pallet::weight(< T as Config >::WeightInfo::submit_signed_commitments(signatures.len() as u32))]
+		pub fn submit_signed_commitments(
+			origin: OriginFor<T>,
+			auth_index: u32,
+			id: thea_primitives::ValidatorSetId,
+			signatures: Vec<(Network, u64, T::Signature)>,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			for (network, nonce, signature) in signatures {
+				let commitment = <MessageCommitments<T>>::get(network, nonce)
+					.ok_or(Error::<T>::CommitmentNotFound)?;
+				let signed_commitment = match <SignedMessageCommitments<T>>::get(network, nonce) {
+					None => SignedMessage::new(commitment, id, auth_index, signature),
+					Some(mut signed_commitment) => {
+						signed_commitment.add_signature(commitment, id, auth_index, signature);
+						signed_commitment
+					},
+				};
+				let auth_len = <Authorities<T>>::get(signed_commitment.validator_set_id).len();
+				if signed_commitment.threshold_reached(auth_len)
+					&& <SignedCommitmentNonce<T>>::get(network) < nonce
+				{
+					<SignedCommitmentNonce<T>>::insert(network, nonce);
+					Self::deposit_event(Event::<T>::CommitmentSignatureFinalized(network, nonce));
+				}
+				<SignedMessageCommitments<T>>::insert(network, nonce, signed_commitment);
+			}
+
+			Ok(())
+		}
+
+		/// Register the ed25519 or sr25519 public key of an authority for the given validator set
+		#[pallet::call_index(20)]
+		#[pallet::weight(< T as Config >::WeightInfo::submit_signing_key())]
+		pub fn submit_signing_key(
+			origin: OriginFor<T>,
+			auth_index: u32,
+			id: thea_primitives::ValidatorSetId,
+			key: SigningKey,
+			signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			// The signature is already verified in `validate_unsigned`
+			let _ = signature;
+			let scheme = key.scheme();
+			<SigningKeys<T>>::insert(id, (scheme, auth_index), key);
+			Self::deposit_event(Event::<T>::SigningKeyRegistered(id, auth_index, scheme));
+			Ok(())
+		}
+
+		/// Submit ed25519 or sr25519 signatures of outgoing messages
+		#[pallet::call_index(21)]
+		#[pallet::weight(< T as Config >::WeightInfo::submit_scheme_signed_outgoing_messages(signatures.len() as u32))]
+		pub fn submit_scheme_signed_outgoing_messages(
+			origin: OriginFor<T>,
+			auth_index: u32,
+			id: thea_primitives::ValidatorSetId,
+			signatures: Vec<(Network, u64, SchemeSignature)>,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			for (network, nonce, signature) in signatures {
+				let message = <OutgoingMessages<T>>::get(network, nonce)
+					.ok_or(Error::<T>::MessageNotFound)?;
+				let signed_msg = match <SchemeSignedOutgoingMessages<T>>::get(network, nonce) {
+					None => SignedMessage::new(message, id, auth_index, signature),
+					Some(mut signed_msg) => {
+						signed_msg.add_signature(message, id, auth_index, signature);
+						signed_msg
+					},
+				};
+				let auth_len = <Authorities<T>>::get(signed_msg.validator_set_id).len();
+				if signed_msg.threshold_reached(auth_len)
+					&& <SignedOutgoingNonce<T>>::get(network) < nonce
+				{
+					<SignedOutgoingNonce<T>>::insert(network, nonce);
+					// Emit an event
+					Self::deposit_event(Event::<T>::TheaSignatureFinalized(network, nonce));
+				}
+				let total_signatures = signed_msg.signatures.len();
+				<SchemeSignedOutgoingMessages<T>>::insert(network, nonce, signed_msg);
+				// Emit an event
+				Self::deposit_event(Event::<T>::TheaSignatureUpdated(
+					network,
+					nonce,
+					total_signatures as u16,
+				));
+			}
+
+			Ok(())
+		}
+
+		/// Set the fork periods of incoming messages by the value of their deposits. Messages
+		/// below the first tier wait the fork period of the network.
+		#[pallet::call_index(22)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_fork_period_tiers(tiers.len() as u32))]
+		pub fn set_fork_period_tiers(
+			origin: OriginFor<T>,
+			network: Network,
+			tiers: Vec<ForkPeriodTier>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				tiers.len() <= MAX_FORK_PERIOD_TIERS as usize
+					&& <NetworkConfig<T>>::get(network).valid_fork_period_tiers(&tiers),
+				Error::<T>::InvalidForkPeriodTiers
+			);
+			<NetworkConfig<T>>::mutate(network, |config| config.fork_period_tiers = tiers);
+			Self::deposit_event(Event::<T>::ForkPeriodTiersUpdated(network));
+			Ok(())
+		}
+
+		/// Set the reward paid to relayers for every executed incoming message of the network
+		#[pallet::call_index(23)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_relayer_reward())]
+		pub fn set_relayer_reward(
+			origin: OriginFor<T>,
+			network: Network,
+			reward: Balance,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			<RelayerRewardPerMessage<T>>::insert(network, reward);
+			Self::deposit_event(Event::<T>::RelayerRewardUpdated(network, reward));
+			Ok(())
+		}
+
+		/// Claim the rewards accumulated by the relayer, paid from `RelayerRewardsAccount`
+		#[pallet::call_index(24)]
+		#[pallet::weight(< T as Config >::WeightInfo::claim_relayer_rewards())]
+		#[transactional]
+		pub fn claim_relayer_rewards(origin: OriginFor<T>) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			let rewards = <RelayerRewards<T>>::take(&relayer);
+			ensure!(rewards > 0, Error::<T>::NoRewardsToClaim);
+			<UnclaimedRelayerRewards<T>>::mutate(|unclaimed| {
+				*unclaimed = unclaimed.saturating_sub(rewards)
+			});
+			T::NativeCurrency::transfer(
+				&T::RelayerRewardsAccount::get(),
+				&relayer,
+				rewards.saturated_into(),
+				Preservation::Preserve,
+			)?;
+			Self::deposit_event(Event::<T>::RelayerRewardsClaimed(relayer, rewards));
 			Ok(())
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Governance can set any pause state, `PauseOrigin` can only make it stricter
+	fn ensure_pause_origin(
+		origin: OriginFor<T>,
+		current: Option<PauseState>,
+		new: Option<PauseState>,
+	) -> DispatchResult {
+		let origin = match T::TheaGovernanceOrigin::try_origin(origin) {
+			Ok(_) => return Ok(()),
+			Err(origin) => origin,
+		};
+		T::PauseOrigin::ensure_origin(origin)?;
+		let lifted = |paused: fn(&PauseState) -> bool| {
+			current.as_ref().map_or(false, paused) && !new.as_ref().map_or(false, paused)
+		};
+		ensure!(
+			!lifted(PauseState::ingress) && !lifted(PauseState::egress),
+			Error::<T>::PauseLiftNotAllowed
+		);
+		Ok(())
+	}
+
 	pub fn active_validators() -> Vec<T::TheaId> {
 		let id = Self::validator_set_id();
 		<Authorities<T>>::get(id).to_vec()
 	}
 
+	/// Returns the relayer allowed to propose new messages for the given network in the
+	/// current block. Leadership rotates over the active relayers every
+	/// `RelayerRotationPeriod` blocks.
+	pub fn scheduled_relayer(network: Network) -> Option<T::AccountId> {
+		let relayers = <ActiveRelayers<T>>::get(network);
+		if relayers.is_empty() {
+			return None;
+		}
+		let blk = frame_system::Pallet::<T>::current_block_number().saturated_into::<u32>();
+		let slot = blk / T::RelayerRotationPeriod::get().max(1);
+		relayers.get((slot as usize) % relayers.len()).cloned()
+	}
+
+	/// Burns the bond of the given relayer and removes it from the relayer set
+	fn slash_relayer(network: Network, relayer: &T::AccountId) -> DispatchResult {
+		use frame_support::traits::{
+			fungible::hold::Mutate,
+			tokens::{Fortitude, Precision},
+		};
+		if let Some(info) = <Relayers<T>>::take(network, relayer) {
+			<ActiveRelayers<T>>::mutate(network, |relayers| relayers.retain(|r| r != relayer));
+			let slashed = T::NativeCurrency::burn_held(
+				&thea_primitives::types::THEA_RELAYER_BOND_REASON,
+				relayer,
+				info.bond.saturated_into(),
+				Precision::BestEffort,
+				Fortitude::Force,
+			)?;
+			Self::deposit_event(Event::<T>::RelayerSlashed(
+				relayer.clone(),
+				network,
+				slashed.saturated_into(),
+			));
+		}
+		Ok(())
+	}
+
 	fn validate_signed_outgoing_message(
 		auth_index: &u32,
 		id: &thea_primitives::ValidatorSetId,
@@ -708,6 +1470,16 @@ impl<T: Config> Pallet<T> {
 				return InvalidTransaction::Custom(2).into();
 			}
 
+			// Reject if the network is not signed with ECDSA
+			if Self::signature_scheme(*network) != SignatureScheme::Ecdsa {
+				return InvalidTransaction::Custom(7).into();
+			}
+
+			// Reject if validators sign the message commitments of the network
+			if <CommitmentPeriods<T>>::contains_key(network) {
+				return InvalidTransaction::Custom(10).into();
+			}
+
 			// Reject if it contains already submitted message signatures
 			match <SignedOutgoingMessages<T>>::get(network, nonce) {
 				None => {},
@@ -722,7 +1494,7 @@ impl<T: Config> Pallet<T> {
 				None => return InvalidTransaction::Custom(3).into(),
 				Some(msg) => msg,
 			};
-			let msg_hash = sp_io::hashing::sha2_256(message.encode().as_slice());
+			let msg_hash = Self::outgoing_message_hash(&message);
 
 			if !sp_io::crypto::ecdsa_verify_prehashed(
 				&signature.clone().into(),
@@ -741,6 +1513,357 @@ impl<T: Config> Pallet<T> {
 			.build()
 	}
 
+	fn validate_bls_public_key(
+		auth_index: &u32,
+		id: &thea_primitives::ValidatorSetId,
+		public: &bls_primitives::Public,
+		proof_of_possession: &bls_primitives::Signature,
+		signature: &T::Signature,
+	) -> TransactionValidity {
+		let authorities = <Authorities<T>>::get(id).to_vec();
+		let signer: &T::TheaId = match authorities.get(*auth_index as usize) {
+			None => return InvalidTransaction::Custom(1).into(),
+			Some(signer) => signer,
+		};
+		// Reject if the key is already registered
+		if <BlsAuthorities<T>>::contains_key(id, auth_index) {
+			return InvalidTransaction::Custom(4).into();
+		}
+		// The BLS key must be authorized by the Thea key of the authority
+		let payload_hash = sp_io::hashing::sha2_256((id, auth_index, public).encode().as_slice());
+		if !sp_io::crypto::ecdsa_verify_prehashed(
+			&signature.clone().into(),
+			&payload_hash,
+			&signer.clone().into(),
+		) {
+			return InvalidTransaction::Custom(6).into();
+		}
+		// Proof of possession of the BLS key, protects the aggregate against rogue keys
+		if !proof_of_possession.verify(&[*public], public.as_ref()) {
+			return InvalidTransaction::Custom(8).into();
+		}
+
+		ValidTransaction::with_tag_prefix("thea-bls-key")
+			.priority(TransactionPriority::MAX / 3)
+			.and_provides((id, auth_index))
+			.longevity(10)
+			.propagate(true)
+			.build()
+	}
+
+	fn validate_bls_signed_outgoing_message(
+		auth_index: &u32,
+		id: &thea_primitives::ValidatorSetId,
+		signatures: &Vec<(Network, u64, bls_primitives::Signature)>,
+	) -> TransactionValidity {
+		if <Authorities<T>>::get(id).get(*auth_index as usize).is_none() {
+			return InvalidTransaction::Custom(1).into();
+		}
+		let public = match <BlsAuthorities<T>>::get(id, auth_index) {
+			None => return InvalidTransaction::Custom(9).into(),
+			Some(public) => public,
+		};
+		for (network, nonce, signature) in signatures {
+			let next_outgoing_nonce = <SignedOutgoingNonce<T>>::get(network).saturating_add(1);
+			if *nonce != next_outgoing_nonce {
+				return InvalidTransaction::Custom(2).into();
+			}
+
+			// Reject if the network is not signed with BLS
+			if Self::signature_scheme(*network) != SignatureScheme::Bls {
+				return InvalidTransaction::Custom(7).into();
+			}
+
+			// Reject if validators sign the message commitments of the network
+			if <CommitmentPeriods<T>>::contains_key(network) {
+				return InvalidTransaction::Custom(10).into();
+			}
+
+			if let Some(signed_msg) = <BlsSignedOutgoingMessages<T>>::get(network, nonce) {
+				// Reject signatures of an older validator set, they can't be aggregated
+				if *id < signed_msg.validator_set_id {
+					return InvalidTransaction::Custom(11).into();
+				}
+				// Reject if it contains already submitted message signatures
+				if *id == signed_msg.validator_set_id && signed_msg.contains_signature(auth_index) {
+					return InvalidTransaction::Custom(4).into();
+				}
+			}
+
+			let message = match <OutgoingMessages<T>>::get(network, nonce) {
+				None => return InvalidTransaction::Custom(3).into(),
+				Some(msg) => msg,
+			};
+			let msg_hash = Self::outgoing_message_hash(&message);
+
+			if !signature.verify(&[public], &msg_hash) {
+				return InvalidTransaction::Custom(6).into();
+			}
+		}
+
+		ValidTransaction::with_tag_prefix("thea-bls")
+			.priority(TransactionPriority::MAX / 3)
+			.and_provides((id, auth_index))
+			.longevity(10)
+			.propagate(true)
+			.build()
+	}
+
+	fn validate_signing_key(
+		auth_index: &u32,
+		id: &thea_primitives::ValidatorSetId,
+		key: &SigningKey,
+		signature: &T::Signature,
+	) -> TransactionValidity {
+		let authorities = <Authorities<T>>::get(id).to_vec();
+		let signer: &T::TheaId = match authorities.get(*auth_index as usize) {
+			None => return InvalidTransaction::Custom(1).into(),
+			Some(signer) => signer,
+		};
+		// Reject if a key of the scheme is already registered
+		if <SigningKeys<T>>::contains_key(id, (key.scheme(), auth_index)) {
+			return InvalidTransaction::Custom(4).into();
+		}
+		// The key must be authorized by the Thea key of the authority
+		let payload_hash = sp_io::hashing::sha2_256((id, auth_index, key).encode().as_slice());
+		if !sp_io::crypto::ecdsa_verify_prehashed(
+			&signature.clone().into(),
+			&payload_hash,
+			&signer.clone().into(),
+		) {
+			return InvalidTransaction::Custom(6).into();
+		}
+
+		ValidTransaction::with_tag_prefix("thea-signing-key")
+			.priority(TransactionPriority::MAX / 3)
+			.and_provides((id, auth_index, key.scheme()))
+			.longevity(10)
+			.propagate(true)
+			.build()
+	}
+
+	fn validate_scheme_signed_outgoing_message(
+		auth_index: &u32,
+		id: &thea_primitives::ValidatorSetId,
+		signatures: &Vec<(Network, u64, SchemeSignature)>,
+	) -> TransactionValidity {
+		if <Authorities<T>>::get(id).get(*auth_index as usize).is_none() {
+			return InvalidTransaction::Custom(1).into();
+		}
+		for (network, nonce, signature) in signatures {
+			let next_outgoing_nonce = <SignedOutgoingNonce<T>>::get(network).saturating_add(1);
+			if *nonce != next_outgoing_nonce {
+				return InvalidTransaction::Custom(2).into();
+			}
+
+			// Reject if the network is not signed with the scheme of the signature
+			let scheme = Self::signature_scheme(*network);
+			if scheme != signature.scheme() {
+				return InvalidTransaction::Custom(7).into();
+			}
+
+			// Reject if validators sign the message commitments of the network
+			if <CommitmentPeriods<T>>::contains_key(network) {
+				return InvalidTransaction::Custom(10).into();
+			}
+
+			let key = match <SigningKeys<T>>::get(id, (scheme, auth_index)) {
+				None => return InvalidTransaction::Custom(9).into(),
+				Some(key) => key,
+			};
+
+			// Reject if it contains already submitted message signatures
+			if let Some(signed_msg) = <SchemeSignedOutgoingMessages<T>>::get(network, nonce) {
+				if signed_msg.validator_set_id == *id && signed_msg.contains_signature(auth_index) {
+					return InvalidTransaction::Custom(4).into();
+				}
+			}
+
+			let message = match <OutgoingMessages<T>>::get(network, nonce) {
+				None => return InvalidTransaction::Custom(3).into(),
+				Some(msg) => msg,
+			};
+			let msg_hash = Self::outgoing_message_hash(&message);
+
+			if !signature.verify(&key, &msg_hash) {
+				return InvalidTransaction::Custom(6).into();
+			}
+		}
+
+		ValidTransaction::with_tag_prefix("thea-scheme")
+			.priority(TransactionPriority::MAX / 3)
+			.and_provides((id, auth_index))
+			.longevity(10)
+			.propagate(true)
+			.build()
+	}
+
+	fn validate_signed_commitments(
+		auth_index: &u32,
+		id: &thea_primitives::ValidatorSetId,
+		signatures: &Vec<(Network, u64, T::Signature)>,
+	) -> TransactionValidity {
+		let authorities = <Authorities<T>>::get(id).to_vec();
+		let signer: &T::TheaId = match authorities.get(*auth_index as usize) {
+			None => return InvalidTransaction::Custom(1).into(),
+			Some(signer) => signer,
+		};
+		for (network, nonce, signature) in signatures {
+			let next_commitment_nonce = <SignedCommitmentNonce<T>>::get(network).saturating_add(1);
+			if *nonce != next_commitment_nonce {
+				return InvalidTransaction::Custom(2).into();
+			}
+
+			// Reject if it contains already submitted commitment signatures
+			if let Some(signed_commitment) = <SignedMessageCommitments<T>>::get(network, nonce) {
+				if signed_commitment.validator_set_id == *id
+					&& signed_commitment.contains_signature(auth_index)
+				{
+					return InvalidTransaction::Custom(4).into();
+				}
+			}
+
+			let commitment = match <MessageCommitments<T>>::get(network, nonce) {
+				None => return InvalidTransaction::Custom(3).into(),
+				Some(commitment) => commitment,
+			};
+			let commitment_hash = Self::commitment_hash(&commitment);
+
+			if !sp_io::crypto::ecdsa_verify_prehashed(
+				&signature.clone().into(),
+				&commitment_hash,
+				&signer.clone().into(),
+			) {
+				return InvalidTransaction::Custom(6).into();
+			}
+		}
+
+		ValidTransaction::with_tag_prefix("thea-commitment")
+			.priority(TransactionPriority::MAX / 3)
+			.and_provides((id, auth_index))
+			.longevity(10)
+			.propagate(true)
+			.build()
+	}
+
+	/// Commits the outgoing messages of the network created since the last commitment if the
+	/// commitment period of the network is over, returns the number of committed messages
+	fn commit_outgoing_messages(network: Network, blk: BlockNumberFor<T>) -> u32 {
+		let Some(period) = <CommitmentPeriods<T>>::get(network) else { return 0 };
+		if blk.saturated_into::<u32>() % period != 0 {
+			return 0;
+		}
+		let committed_nonce = <CommittedOutgoingNonce<T>>::get(network);
+		let outgoing_nonce = <OutgoingNonce<T>>::get(network);
+		if outgoing_nonce <= committed_nonce {
+			return 0;
+		}
+		let first_nonce = committed_nonce.saturating_add(1);
+		let last_nonce = outgoing_nonce.min(committed_nonce.saturating_add(MAX_COMMITTED_MESSAGES));
+		let Some(messages) = Self::committed_messages(network, first_nonce, last_nonce) else {
+			log::error!(target:"thea","Outgoing messages {:?}..={:?} of network {:?} not found",first_nonce,last_nonce,network);
+			return 0;
+		};
+		let leaves = Self::commitment_leaves(network, &messages);
+		let root = binary_merkle_tree::merkle_root::<Keccak256, _>(leaves);
+		let nonce = <CommitmentNonce<T>>::get(network).saturating_add(1);
+		let commitment = MessageCommitment {
+			block_no: blk.saturated_into(),
+			nonce,
+			network,
+			first_nonce,
+			last_nonce,
+			root,
+		};
+		<MessageCommitments<T>>::insert(network, nonce, commitment);
+		<CommitmentNonce<T>>::insert(network, nonce);
+		<CommittedOutgoingNonce<T>>::insert(network, last_nonce);
+		Self::deposit_event(Event::<T>::OutgoingMessagesCommitted(network, nonce, root));
+		(last_nonce - committed_nonce).saturated_into()
+	}
+
+	/// Returns the outgoing messages of the network between the given nonces, both inclusive,
+	/// `None` if any of them is missing
+	fn committed_messages(
+		network: Network,
+		first_nonce: u64,
+		last_nonce: u64,
+	) -> Option<Vec<Message>> {
+		(first_nonce..=last_nonce)
+			.map(|nonce| <OutgoingMessages<T>>::get(network, nonce))
+			.collect()
+	}
+
+	/// Returns the Merkle leaves of the committed messages, ABI encoded for EVM networks
+	fn commitment_leaves(network: Network, messages: &[Message]) -> Vec<Vec<u8>> {
+		match <NetworkConfig<T>>::get(network).network_type {
+			NetworkType::Evm => messages.iter().map(thea_primitives::abi::encode_message).collect(),
+			NetworkType::Parachain => messages.iter().map(Encode::encode).collect(),
+		}
+	}
+
+	/// Returns the hash of the message commitment signed by validators. Commitments of EVM
+	/// networks are ABI encoded and signed over their EIP-191 digest.
+	pub fn commitment_hash(commitment: &MessageCommitment) -> [u8; 32] {
+		match <NetworkConfig<T>>::get(commitment.network).network_type {
+			NetworkType::Evm => thea_primitives::abi::commitment_digest(commitment),
+			NetworkType::Parachain => sp_io::hashing::sha2_256(commitment.encode().as_slice()),
+		}
+	}
+
+	/// Accumulates the reward of the relayer for the executed incoming message, capped by the
+	/// funds of `RelayerRewardsAccount` not owed to other relayers yet
+	fn reward_relayer(relayer: &T::AccountId, network: Network, nonce: u64) {
+		use frame_support::traits::{
+			fungible::Inspect,
+			tokens::{Fortitude, Preservation},
+		};
+		let funded: Balance = T::NativeCurrency::reducible_balance(
+			&T::RelayerRewardsAccount::get(),
+			Preservation::Preserve,
+			Fortitude::Polite,
+		)
+		.saturated_into();
+		let unclaimed = <UnclaimedRelayerRewards<T>>::get();
+		let reward =
+			<RelayerRewardPerMessage<T>>::get(network).min(funded.saturating_sub(unclaimed));
+		if reward == 0 {
+			return;
+		}
+		<UnclaimedRelayerRewards<T>>::put(unclaimed.saturating_add(reward));
+		<RelayerRewards<T>>::mutate(relayer, |rewards| *rewards = rewards.saturating_add(reward));
+		Self::deposit_event(Event::<T>::RelayerRewarded(relayer.clone(), network, nonce, reward));
+	}
+
+	/// Returns the fork period of the incoming message, scaled by the value of its deposits
+	pub fn fork_period(config: &thea_primitives::types::NetworkConfig, message: &Message) -> u32 {
+		if config.fork_period_tiers.is_empty() {
+			return config.fork_period;
+		}
+		let value = <T::Executor as thea_primitives::TheaIncomingExecutor>::deposits_value(
+			message.network,
+			&message.data,
+		);
+		config.fork_period_for_value(value)
+	}
+
+	/// Queues the data as the next outgoing message of the network
+	fn queue_outgoing_message(network: Network, data: Vec<u8>) {
+		let payload = Self::generate_payload(PayloadType::L1Deposit, network, data);
+		// Update nonce
+		<OutgoingNonce<T>>::insert(network, payload.nonce);
+		<OutgoingMessages<T>>::insert(network, payload.nonce, payload);
+	}
+
+	/// Returns the hash of the outgoing message signed by validators. Messages to EVM networks
+	/// are ABI encoded and signed over their EIP-191 digest.
+	pub fn outgoing_message_hash(message: &Message) -> [u8; 32] {
+		match <NetworkConfig<T>>::get(message.network).network_type {
+			NetworkType::Evm => thea_primitives::abi::message_digest(message),
+			NetworkType::Parachain => sp_io::hashing::sha2_256(message.encode().as_slice()),
+		}
+	}
+
 	/// Generates the next payload based on saved nonce,
 	///
 	/// NOTE: It will not change the nonce on storage.
@@ -870,14 +1993,193 @@ impl<T: Config> Pallet<T> {
 	pub fn get_last_processed_nonce(network: Network) -> u64 {
 		<IncomingNonce<T>>::get(network)
 	}
+
+	pub fn get_bls_signed_message(network: Network, nonce: u64) -> Option<BlsSignedMessage> {
+		<BlsSignedOutgoingMessages<T>>::get(network, nonce)
+	}
+
+	pub fn get_bls_authorities(
+		id: thea_primitives::ValidatorSetId,
+	) -> Vec<(u32, bls_primitives::Public)> {
+		let mut authorities = <BlsAuthorities<T>>::iter_prefix(id).collect::<Vec<_>>();
+		authorities.sort_by_key(|(auth_index, _)| *auth_index);
+		authorities
+	}
+
+	/// Returns the signature scheme used to sign outgoing messages of the network
+	pub fn signature_scheme(network: Network) -> SignatureScheme {
+		<NetworkConfig<T>>::get(network).signature_scheme
+	}
+
+	/// Returns the registered key of every authority of the validator set for the scheme
+	pub fn get_signing_keys(
+		id: thea_primitives::ValidatorSetId,
+		scheme: SignatureScheme,
+	) -> Vec<(u32, SigningKey)> {
+		let mut keys = <SigningKeys<T>>::iter_prefix(id)
+			.filter(|((key_scheme, _), _)| *key_scheme == scheme)
+			.map(|((_, auth_index), key)| (auth_index, key))
+			.collect::<Vec<_>>();
+		keys.sort_by_key(|(auth_index, _)| *auth_index);
+		keys
+	}
+
+	/// Returns the outgoing message with its ed25519 or sr25519 signatures
+	pub fn get_scheme_signed_message(
+		network: Network,
+		nonce: u64,
+	) -> Option<SignedMessage<SchemeSignature>> {
+		<SchemeSignedOutgoingMessages<T>>::get(network, nonce)
+	}
+
+	/// Returns the signing progress of the outgoing message, or of the next message awaiting
+	/// signatures if `nonce` is not given
+	pub fn get_outgoing_message_status(
+		network: Network,
+		nonce: Option<u64>,
+	) -> Option<OutgoingMessageStatus<T::Signature>> {
+		let signed_nonce = <SignedOutgoingNonce<T>>::get(network);
+		let nonce = nonce.unwrap_or(signed_nonce.saturating_add(1));
+		let message = <OutgoingMessages<T>>::get(network, nonce)?;
+		let scheme = Self::signature_scheme(network);
+		let (validator_set_id, signatures, signers) = match scheme {
+			SignatureScheme::Ecdsa => match <SignedOutgoingMessages<T>>::get(network, nonce) {
+				Some(signed_msg) => {
+					let signers = signed_msg.signatures.keys().copied().collect();
+					(signed_msg.validator_set_id, signed_msg.signatures, signers)
+				},
+				None => (<ValidatorSetId<T>>::get(), BTreeMap::new(), Vec::new()),
+			},
+			SignatureScheme::Bls => match <BlsSignedOutgoingMessages<T>>::get(network, nonce) {
+				Some(signed_msg) => {
+					(signed_msg.validator_set_id, BTreeMap::new(), signed_msg.signer_indexes())
+				},
+				None => (<ValidatorSetId<T>>::get(), BTreeMap::new(), Vec::new()),
+			},
+			SignatureScheme::Ed25519 | SignatureScheme::Sr25519 => {
+				match <SchemeSignedOutgoingMessages<T>>::get(network, nonce) {
+					Some(signed_msg) => {
+						let signers = signed_msg.signatures.keys().copied().collect();
+						(signed_msg.validator_set_id, BTreeMap::new(), signers)
+					},
+					None => (<ValidatorSetId<T>>::get(), BTreeMap::new(), Vec::new()),
+				}
+			},
+		};
+		let required_signatures =
+			signature_threshold(<Authorities<T>>::get(validator_set_id).len());
+		Some(OutgoingMessageStatus {
+			message,
+			validator_set_id,
+			scheme,
+			signatures,
+			signers,
+			required_signatures: required_signatures.saturated_into(),
+			threshold_reached: nonce <= signed_nonce,
+		})
+	}
+
+	/// Returns the outgoing message with its inclusion proof in the commitment that includes it
+	pub fn get_message_proof(network: Network, nonce: u64) -> Option<MessageProof> {
+		// Commitments cover contiguous ranges of nonces in order
+		let (mut low, mut high) = (1, <CommitmentNonce<T>>::get(network));
+		while low <= high {
+			let mid = low + (high - low) / 2;
+			let commitment = <MessageCommitments<T>>::get(network, mid)?;
+			if nonce < commitment.first_nonce {
+				high = mid - 1;
+			} else if nonce > commitment.last_nonce {
+				low = mid + 1;
+			} else {
+				let messages = Self::committed_messages(
+					network,
+					commitment.first_nonce,
+					commitment.last_nonce,
+				)?;
+				let leaf_index = (nonce - commitment.first_nonce) as usize;
+				let message = messages.get(leaf_index)?.clone();
+				let leaves = Self::commitment_leaves(network, &messages);
+				let proof = binary_merkle_tree::merkle_proof::<Keccak256, _, _>(leaves, leaf_index);
+				return Some(MessageProof {
+					commitment,
+					message,
+					leaf_index: leaf_index.saturated_into(),
+					proof: proof.proof,
+				});
+			}
+		}
+		None
+	}
+
+	pub fn get_signed_commitment(
+		network: Network,
+		nonce: u64,
+	) -> Option<SignedMessage<T::Signature, MessageCommitment>> {
+		<SignedMessageCommitments<T>>::get(network, nonce)
+	}
+
+	/// Returns the incoming messages of the network waiting for execution, ordered by nonce
+	pub fn get_incoming_queue(
+		network: Network,
+	) -> Vec<(u64, IncomingMessage<T::AccountId, polkadex_primitives::Balance>)> {
+		let mut queue = <IncomingMessagesQueue<T>>::iter_prefix(network).collect::<Vec<_>>();
+		queue.sort_by_key(|(nonce, _)| *nonce);
+		queue
+	}
+
+	pub fn get_network_nonces(network: Network) -> NetworkNonces {
+		NetworkNonces {
+			incoming: <IncomingNonce<T>>::get(network),
+			outgoing: <OutgoingNonce<T>>::get(network),
+			signed_outgoing: <SignedOutgoingNonce<T>>::get(network),
+		}
+	}
+
+	pub fn get_authorities(id: thea_primitives::ValidatorSetId) -> Vec<T::TheaId> {
+		<Authorities<T>>::get(id).into_inner()
+	}
+
+	/// Returns the config of every active network
+	pub fn get_network_configs() -> Vec<(Network, thea_primitives::types::NetworkConfig)> {
+		<ActiveNetworks<T>>::get()
+			.into_iter()
+			.map(|network| (network, <NetworkConfig<T>>::get(network)))
+			.collect()
+	}
+}
+
+impl<T: Config> thea_primitives::TheaPauseSwitch for Pallet<T> {
+	fn network_pause(network: Network) -> Option<PauseState> {
+		<NetworkPauseStates<T>>::get(network)
+	}
+
+	fn asset_pause(asset_id: u128) -> Option<PauseState> {
+		<AssetPauseStates<T>>::get(asset_id)
+	}
 }
 
 impl<T: Config> thea_primitives::TheaOutgoingExecutor for Pallet<T> {
 	fn execute_withdrawals(network: Network, data: Vec<u8>) -> DispatchResult {
-		let payload = Self::generate_payload(PayloadType::L1Deposit, network, data);
-		// Update nonce
-		<OutgoingNonce<T>>::insert(network, payload.nonce);
-		<OutgoingMessages<T>>::insert(network, payload.nonce, payload);
+		let data = match <NetworkConfig<T>>::get(network).network_type {
+			// EVM contracts decode the withdrawals natively
+			NetworkType::Evm => {
+				let withdrawals = Vec::<Withdraw>::decode(&mut &data[..])
+					.map_err(|_| Error::<T>::InvalidWithdrawalPayload)?;
+				for withdrawal in withdrawals.iter() {
+					Self::validate_withdrawal(network, withdrawal)?;
+				}
+				thea_primitives::abi::encode_withdrawals(&withdrawals)
+			},
+			NetworkType::Parachain => data,
+		};
+		Self::queue_outgoing_message(network, data);
+		Ok(())
+	}
+
+	fn validate_withdrawal(network: Network, withdrawal: &Withdraw) -> DispatchResult {
+		if <NetworkConfig<T>>::get(network).network_type == NetworkType::Evm {
+			ensure!(withdrawal.destination.len() == 20, Error::<T>::InvalidEvmDestination);
+		}
 		Ok(())
 	}
 
@@ -886,3 +2188,44 @@ impl<T: Config> thea_primitives::TheaOutgoingExecutor for Pallet<T> {
 		nonce.saturating_add(1)
 	}
 }
+
+impl<T: Config> thea_primitives::TheaIncomingProofHandler<T::AccountId> for Pallet<T> {
+	fn on_proven_message(relayer: T::AccountId, message: Message) -> DispatchResult {
+		use frame_support::traits::{
+			fungible::{hold::Mutate, Mutate as FungibleMutate},
+			tokens::{Precision, Preservation},
+		};
+		ensure!(message.nonce > <IncomingNonce<T>>::get(message.network), Error::<T>::NonceError);
+		// A proven message replaces the one relayed before proofs were required
+		if let Some(queued) = <IncomingMessagesQueue<T>>::get(message.network, message.nonce) {
+			T::NativeCurrency::release(
+				&thea_primitives::types::THEA_HOLD_REASON,
+				&queued.relayer,
+				queued.stake.saturated_into(),
+				Precision::BestEffort,
+			)?;
+			// The proof shows the relayed message was forged, slash it like a fisherman report
+			if queued.message != message {
+				T::NativeCurrency::transfer(
+					&queued.relayer,
+					&relayer,
+					queued.stake.saturated_into(),
+					Preservation::Expendable,
+				)?;
+				Self::slash_relayer(message.network, &queued.relayer)?;
+			}
+		}
+		// Proven messages can not be forked, so they are executed without a fork period
+		<IncomingMessagesQueue<T>>::insert(
+			message.network,
+			message.nonce,
+			IncomingMessage {
+				message,
+				relayer,
+				stake: 0,
+				execute_at: frame_system::Pallet::<T>::current_block_number().saturated_into(),
+			},
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,137 @@
+// This file is part of Polkadex.
+//
+// Copyright (c) 2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the Thea pallet.
+
+use crate::{
+	pallet::{ActiveRelayers, NetworkConfig, Relayers},
+	Config, Pallet,
+};
+use frame_support::{pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade};
+use sp_std::{marker::PhantomData, vec::Vec};
+use thea_primitives::{
+	types::{NetworkType, RelayerInfo, SignatureScheme},
+	Network,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// `NetworkConfig` before the signature scheme and the fork period tiers
+	#[derive(Decode)]
+	struct OldNetworkConfig {
+		fork_period: u32,
+		min_stake: u128,
+		fisherman_stake: u128,
+		network_type: NetworkType,
+	}
+
+	#[storage_alias]
+	type AllowListTestingRelayers<T: Config> = StorageMap<
+		Pallet<T>,
+		Identity,
+		Network,
+		<T as frame_system::Config>::AccountId,
+		OptionQuery,
+	>;
+
+	/// Adds the ECDSA signature scheme and empty fork period tiers to every `NetworkConfig` and
+	/// moves the allowlisted testing relayer of every network into the active relayer set,
+	/// without a bond
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() >= 1 {
+				log::info!(target: "thea", "Skipping migration to v1, already applied");
+				return T::DbWeight::get().reads(1);
+			}
+			let mut translated = 0u64;
+			<NetworkConfig<T>>::translate::<OldNetworkConfig, _>(|_, old| {
+				translated += 1;
+				Some(thea_primitives::types::NetworkConfig {
+					fork_period: old.fork_period,
+					min_stake: old.min_stake,
+					fisherman_stake: old.fisherman_stake,
+					network_type: old.network_type,
+					signature_scheme: SignatureScheme::Ecdsa,
+					fork_period_tiers: Vec::new(),
+				})
+			});
+			let mut relayers = 0u64;
+			for (network, relayer) in <AllowListTestingRelayers<T>>::drain() {
+				relayers += 1;
+				let pushed = <ActiveRelayers<T>>::mutate(network, |active| {
+					active.contains(&relayer) || active.try_push(relayer.clone()).is_ok()
+				});
+				if !pushed {
+					log::error!(target: "thea", "Relayer set of network {:?} is full", network);
+					continue;
+				}
+				if !<Relayers<T>>::contains_key(network, &relayer) {
+					<Relayers<T>>::insert(
+						network,
+						&relayer,
+						RelayerInfo { bond: 0, unbonding_at: None },
+					);
+				}
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(
+				target: "thea",
+				"Migrated {:?} network configs and {:?} allowlisted relayers to v1",
+				translated,
+				relayers
+			);
+			let weight = translated.saturating_add(relayers.saturating_mul(3)).saturating_add(1);
+			T::DbWeight::get().reads_writes(weight, weight)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let configs = <NetworkConfig<T>>::iter_keys().count() as u32;
+			let relayers: Vec<(Network, T::AccountId)> =
+				<AllowListTestingRelayers<T>>::iter().collect();
+			Ok((configs, relayers).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (configs, relayers): (u32, Vec<(Network, T::AccountId)>) =
+				Decode::decode(&mut &state[..])
+					.map_err(|_| "Unable to decode the pre upgrade state")?;
+			ensure!(StorageVersion::get::<Pallet<T>>() == 1, "Storage version not updated");
+			// Decoding fails for configs that were not translated
+			ensure!(
+				<NetworkConfig<T>>::iter().count() as u32 == configs,
+				"Network configs lost in migration"
+			);
+			ensure!(
+				<AllowListTestingRelayers<T>>::iter().next().is_none(),
+				"Allowlisted relayers not removed"
+			);
+			for (network, relayer) in relayers {
+				ensure!(
+					<ActiveRelayers<T>>::get(network).contains(&relayer),
+					"Allowlisted relayer not active"
+				);
+			}
+			Ok(())
+		}
+	}
+}
@@ -17,18 +17,23 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{pallet as thea, *};
-use frame_support::{parameter_types, traits::AsEnsureOriginWithArg, PalletId};
+use frame_support::{
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, Equals},
+	PalletId,
+};
 use frame_system as system;
-use frame_system::{EnsureRoot, EnsureSigned};
+use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy};
 use polkadex_primitives::AssetId;
 use sp_core::{Pair, H256};
 use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
 use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage, Permill,
+	BuildStorage, Perbill, Permill,
 };
 
-type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 type Balance = u128;
 
@@ -133,6 +138,11 @@ impl pallet_assets::Config for Test {
 
 parameter_types! {
 	pub const MaxAuthorities: u32 = 200;
+	pub const RelayerUnbondingPeriod: u32 = 100;
+	pub const RelayerRotationPeriod: u32 = 10;
+	pub const RelayerRewardsAccount: u64 = 100;
+	pub const RelayerRewardsShare: Perbill = Perbill::from_percent(50);
+	pub const ProvenNetwork: thea_primitives::Network = 3;
 }
 
 impl crate::Config for Test {
@@ -143,6 +153,14 @@ impl crate::Config for Test {
 	type Executor = TheaExecutor;
 	type NativeCurrency = Balances;
 	type TheaGovernanceOrigin = EnsureRoot<u64>;
+	type PauseOrigin = EnsureSignedBy<SecurityCouncil, u64>;
+	type MaxRelayers = MaxRelayers;
+	type RelayerUnbondingPeriod = RelayerUnbondingPeriod;
+	type RelayerRotationPeriod = RelayerRotationPeriod;
+	type RelayerRewardsAccount = RelayerRewardsAccount;
+	type ProvenNetworks = Equals<ProvenNetwork>;
+	type FishermanId = TestFishermanId;
+	type ForeignChainSource = crate::fisherman::JsonRpcSource;
 	type WeightInfo = crate::weights::WeightInfo<Test>;
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -151,6 +169,7 @@ impl crate::Config for Test {
 
 frame_support::ord_parameter_types! {
 	pub const AssetConversionOrigin: u32 = 1;
+	pub const SecurityCouncil: u64 = 99;
 }
 
 parameter_types! {
@@ -208,6 +227,9 @@ impl thea_executor::Config for Test {
 	type MultiAssetIdAdapter = AssetId;
 	type AssetBalanceAdapter = u128;
 	type GovernanceOrigin = EnsureRoot<Self::AccountId>;
+	type PauseSwitch = Thea;
+	type RelayerRewardsAccount = RelayerRewardsAccount;
+	type RelayerRewardsShare = RelayerRewardsShare;
 	type ExistentialDeposit = ExistentialDeposit;
 }
 
@@ -219,6 +241,33 @@ where
 	type OverarchingCall = RuntimeCall;
 }
 
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: UintAuthorityId,
+		account: u64,
+		_nonce: u32,
+	) -> Option<(RuntimeCall, (u64, (), ()))> {
+		Some((call, (account, (), ())))
+	}
+}
+
+pub struct TestFishermanId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestFishermanId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
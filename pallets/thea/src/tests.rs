@@ -305,6 +305,8 @@ fn test_report_misbehaviour_happy_path() {
 			min_stake: 1_000_000,
 			fisherman_stake: 1_000_000,
 			network_type: NetworkType::Parachain,
+			signature_scheme: SignatureScheme::Ecdsa,
+			fork_period_tiers: vec![],
 		};
 		<NetworkConfig<Test>>::insert(network, config);
 		let relayer = 1u64;
@@ -325,7 +327,10 @@ use frame_support::{
 	assert_noop,
 	traits::{fungible::MutateHold, tokens::Precision},
 };
-use thea_primitives::types::{AssetMetadata, IncomingMessage, SignedMessage, THEA_HOLD_REASON};
+use thea_primitives::{
+	types::{AssetMetadata, IncomingMessage, PauseState, SignedMessage, THEA_HOLD_REASON},
+	TheaIncomingProofHandler, TheaPauseSwitch,
+};
 
 #[test]
 fn test_report_misbehaviour_not_enough_stake() {
@@ -344,6 +349,8 @@ fn test_report_misbehaviour_not_enough_stake() {
 			min_stake: 1_000_000_000_000_000_000_000_000_000,
 			fisherman_stake: 1_000_000_000_000_000_000_000_000,
 			network_type: NetworkType::Parachain,
+			signature_scheme: SignatureScheme::Ecdsa,
+			fork_period_tiers: vec![],
 		};
 		<NetworkConfig<Test>>::insert(network, config);
 		let relayer = 1u64;
@@ -379,6 +386,8 @@ fn test_handle_misbehaviour_happy_path_valid_proposal() {
 			min_stake: 1_000_000,
 			fisherman_stake: 1_000_000,
 			network_type: NetworkType::Parachain,
+			signature_scheme: SignatureScheme::Ecdsa,
+			fork_period_tiers: vec![],
 		};
 		<NetworkConfig<Test>>::insert(network, config);
 		let relayer = 1u64;
@@ -415,6 +424,8 @@ fn test_handle_misbehaviour_happy_path_invalid_proposal() {
 			min_stake: 1_000_000,
 			fisherman_stake: 1_000_000,
 			network_type: NetworkType::Parachain,
+			signature_scheme: SignatureScheme::Ecdsa,
+			fork_period_tiers: vec![],
 		};
 		<NetworkConfig<Test>>::insert(network, config);
 		let relayer = 1u64;
@@ -536,6 +547,162 @@ fn test_on_initialize_happy_path() {
 	})
 }
 
+#[test]
+fn test_relayer_is_rewarded_for_executed_messages() {
+	new_test_ext().execute_with(|| {
+		let network = 1;
+		<ActiveNetworks<Test>>::put(BTreeSet::from([network]));
+		let relayer = 1u64;
+		let _ = Balances::deposit_creating(&relayer, 100 * UNIT_BALANCE);
+		let _ = Balances::deposit_creating(&RelayerRewardsAccount::get(), 100 * UNIT_BALANCE);
+		assert_noop!(
+			Thea::set_relayer_reward(RuntimeOrigin::signed(relayer), network, UNIT_BALANCE),
+			BadOrigin
+		);
+		assert_ok!(Thea::set_relayer_reward(RuntimeOrigin::root(), network, UNIT_BALANCE));
+		assert_noop!(
+			Thea::claim_relayer_rewards(RuntimeOrigin::signed(relayer)),
+			Error::<Test>::NoRewardsToClaim
+		);
+		let stake = UNIT_BALANCE;
+		for nonce in 1..=2 {
+			Balances::hold(&THEA_HOLD_REASON, &relayer, stake).unwrap();
+			let message = Message {
+				block_no: 0,
+				nonce,
+				network,
+				payload_type: PayloadType::L1Deposit,
+				data: vec![],
+			};
+			<IncomingMessagesQueue<Test>>::insert(
+				network,
+				nonce,
+				IncomingMessage { message, relayer, stake, execute_at: 0 },
+			);
+			Thea::on_initialize(nonce);
+		}
+		assert_eq!(<IncomingNonce<Test>>::get(network), 2);
+		assert_eq!(Thea::relayer_rewards(relayer), 2 * UNIT_BALANCE);
+
+		assert_ok!(Thea::claim_relayer_rewards(RuntimeOrigin::signed(relayer)));
+		assert_eq!(Thea::relayer_rewards(relayer), 0);
+		assert_eq!(Balances::free_balance(&relayer), 102 * UNIT_BALANCE);
+		assert_eq!(Balances::free_balance(&RelayerRewardsAccount::get()), 98 * UNIT_BALANCE);
+		assert_eq!(Thea::unclaimed_relayer_rewards(), 0);
+	})
+}
+
+#[test]
+fn test_relayer_rewards_are_capped_by_the_funded_balance() {
+	new_test_ext().execute_with(|| {
+		let network = 1;
+		<ActiveNetworks<Test>>::put(BTreeSet::from([network]));
+		let relayer = 1u64;
+		let _ = Balances::deposit_creating(&relayer, 100 * UNIT_BALANCE);
+		// Existential deposit of the rewards account is not paid out
+		let funded = UNIT_BALANCE + UNIT_BALANCE / 2;
+		let _ = Balances::deposit_creating(
+			&RelayerRewardsAccount::get(),
+			funded + ExistentialDeposit::get() as u128,
+		);
+		assert_ok!(Thea::set_relayer_reward(RuntimeOrigin::root(), network, UNIT_BALANCE));
+		let stake = UNIT_BALANCE;
+		for nonce in 1..=3 {
+			Balances::hold(&THEA_HOLD_REASON, &relayer, stake).unwrap();
+			let message = Message {
+				block_no: 0,
+				nonce,
+				network,
+				payload_type: PayloadType::L1Deposit,
+				data: vec![],
+			};
+			<IncomingMessagesQueue<Test>>::insert(
+				network,
+				nonce,
+				IncomingMessage { message, relayer, stake, execute_at: 0 },
+			);
+			Thea::on_initialize(nonce);
+		}
+		assert_eq!(<IncomingNonce<Test>>::get(network), 3);
+		assert_eq!(Thea::relayer_rewards(relayer), funded);
+		assert_eq!(Thea::unclaimed_relayer_rewards(), funded);
+		assert_ok!(Thea::claim_relayer_rewards(RuntimeOrigin::signed(relayer)));
+		assert_eq!(Balances::free_balance(&relayer), 100 * UNIT_BALANCE + funded);
+	})
+}
+
+#[test]
+fn test_proven_messages_skip_fork_period() {
+	new_test_ext().execute_with(|| {
+		let network = ProvenNetwork::get();
+		<ActiveNetworks<Test>>::put(BTreeSet::from([network]));
+		set_relayer_network_config(network);
+		<NetworkConfig<Test>>::mutate(network, |config| config.fork_period = 100);
+		let relayer = 1u64;
+		let _ = Balances::deposit_creating(&relayer, 100 * UNIT_BALANCE);
+		let message = Message {
+			block_no: 0,
+			nonce: 1,
+			network,
+			payload_type: PayloadType::L1Deposit,
+			data: vec![],
+		};
+		assert_noop!(
+			Thea::submit_incoming_message(
+				RuntimeOrigin::signed(relayer),
+				message.clone(),
+				UNIT_BALANCE
+			),
+			Error::<Test>::ProofRequired
+		);
+
+		assert_ok!(Thea::register_relayer(RuntimeOrigin::signed(relayer), network, UNIT_BALANCE));
+
+		// Message forged by a relayer before proofs were required
+		let stake = UNIT_BALANCE;
+		Balances::hold(&THEA_HOLD_REASON, &relayer, stake).unwrap();
+		let forged = Message { data: vec![1, 2, 3], ..message.clone() };
+		<IncomingMessagesQueue<Test>>::insert(
+			network,
+			1,
+			IncomingMessage { message: forged, relayer, stake, execute_at: 100 },
+		);
+
+		let prover = 2u64;
+		let _ = Balances::deposit_creating(&prover, 100 * UNIT_BALANCE);
+		assert_ok!(Thea::on_proven_message(prover, message.clone()));
+		// The stake of the relayer goes to the prover and its bond is slashed
+		assert_eq!(Balances::free_balance(&relayer), 98 * UNIT_BALANCE);
+		assert_eq!(Balances::free_balance(&prover), 101 * UNIT_BALANCE);
+		assert!(<Relayers<Test>>::get(network, relayer).is_none());
+		assert!(!<ActiveRelayers<Test>>::get(network).contains(&relayer));
+		let queued = <IncomingMessagesQueue<Test>>::get(network, 1).unwrap();
+		assert_eq!(queued.message, message);
+		assert_eq!(queued.relayer, prover);
+		assert_eq!(queued.stake, 0);
+
+		let now = System::block_number();
+		assert_eq!(queued.execute_at, now as u32);
+		Thea::on_initialize(now);
+		assert_eq!(<IncomingNonce<Test>>::get(network), 1);
+		assert_eq!(Thea::incoming_messages(network, 1), Some(message.clone()));
+		assert_noop!(Thea::on_proven_message(prover, message), Error::<Test>::NonceError);
+
+		// Message relayed honestly before proofs were required
+		let message = Message { nonce: 2, data: vec![4, 5, 6], ..message };
+		Balances::hold(&THEA_HOLD_REASON, &relayer, stake).unwrap();
+		<IncomingMessagesQueue<Test>>::insert(
+			network,
+			2,
+			IncomingMessage { message: message.clone(), relayer, stake, execute_at: 100 },
+		);
+		assert_ok!(Thea::on_proven_message(prover, message.clone()));
+		assert_eq!(Balances::free_balance(&relayer), 98 * UNIT_BALANCE);
+		assert_eq!(Balances::free_balance(&prover), 101 * UNIT_BALANCE);
+		assert_eq!(<IncomingMessagesQueue<Test>>::get(network, 2).unwrap().relayer, prover);
+	})
+}
+
 #[test]
 fn test_validate_signed_outgoing_message_happy_path() {
 	new_test_ext().execute_with(|| {
@@ -563,6 +730,64 @@ fn test_validate_signed_outgoing_message_happy_path() {
 	})
 }
 
+#[test]
+fn test_evm_withdrawals_are_abi_encoded_and_signed_over_eip191_digest() {
+	new_test_ext().execute_with(|| {
+		let validator = sp_core::ecdsa::Pair::from_seed(b"12345678901234567890123456789012");
+		let validator_set_id = 1;
+		let mut auths = <Authorities<Test>>::get(validator_set_id);
+		auths.try_push(validator.public().into()).unwrap();
+		<Authorities<Test>>::insert(validator_set_id, auths);
+		let network = 2;
+		<NetworkConfig<Test>>::insert(
+			network,
+			thea_primitives::types::NetworkConfig {
+				network_type: NetworkType::Evm,
+				..Default::default()
+			},
+		);
+		let withdrawals = vec![Withdraw {
+			id: vec![1, 2, 3, 4],
+			asset_id: 1,
+			amount: 1_000_000_000_000_000_000,
+			destination: vec![0x11; 20],
+			is_blocked: false,
+			extra: vec![],
+		}];
+		assert_err!(
+			<Thea as thea_primitives::TheaOutgoingExecutor>::execute_withdrawals(
+				network,
+				vec![1, 2, 3]
+			),
+			Error::<Test>::InvalidWithdrawalPayload
+		);
+		assert_ok!(<Thea as thea_primitives::TheaOutgoingExecutor>::execute_withdrawals(
+			network,
+			withdrawals.encode()
+		));
+		let message = <OutgoingMessages<Test>>::get(network, 1).unwrap();
+		assert_eq!(thea_primitives::abi::decode_withdrawals(&message.data), Ok(withdrawals));
+		assert_eq!(
+			thea_primitives::abi::decode_message(&thea_primitives::abi::encode_message(&message)),
+			Ok(message.clone())
+		);
+
+		// SCALE encoded messages are not accepted for EVM networks
+		let scale_hash = sp_io::hashing::sha2_256(message.encode().as_slice());
+		let signatures = vec![(network, 1, validator.sign_prehashed(&scale_hash).into())];
+		assert_noop!(
+			Thea::validate_signed_outgoing_message(&0, &validator_set_id, &signatures),
+			InvalidTransaction::Custom(6)
+		);
+		let digest = thea_primitives::abi::eth_signed_message_hash(&sp_io::hashing::keccak_256(
+			&thea_primitives::abi::encode_message(&message),
+		));
+		assert_eq!(Thea::outgoing_message_hash(&message), digest);
+		let signatures = vec![(network, 1, validator.sign_prehashed(&digest).into())];
+		assert_ok!(Thea::validate_signed_outgoing_message(&0, &validator_set_id, &signatures));
+	})
+}
+
 #[test]
 fn test_validate_signed_outgoing_message_custom_error_1() {
 	new_test_ext().execute_with(|| {
@@ -676,17 +901,21 @@ fn test_submit_incoming_message_happy_path_first_message() {
 			min_stake: 1 * UNIT_BALANCE,
 			fisherman_stake: 1 * UNIT_BALANCE,
 			network_type: NetworkType::Parachain,
+			signature_scheme: SignatureScheme::Ecdsa,
+			fork_period_tiers: vec![],
 		};
 		<NetworkConfig<Test>>::insert(network_id, network_config);
-		<AllowListTestingRelayers<Test>>::insert(network_id, relayer);
+		let relayer_2 = 2u64;
+		let _ = Balances::deposit_creating(&relayer_2, 100 * UNIT_BALANCE);
+		assert_ok!(Thea::register_relayer(RuntimeOrigin::signed(relayer), network_id, stake));
+		assert_ok!(Thea::register_relayer(RuntimeOrigin::signed(relayer_2), network_id, stake));
 		assert_ok!(Thea::submit_incoming_message(
 			RuntimeOrigin::signed(relayer),
 			message.clone(),
 			stake
 		));
-		assert_eq!(Balances::reserved_balance(&relayer), 1 * UNIT_BALANCE);
-		let relayer_2 = 2u64;
-		let _ = Balances::deposit_creating(&relayer_2, 100 * UNIT_BALANCE);
+		// Bond + message stake
+		assert_eq!(Balances::reserved_balance(&relayer), 2 * UNIT_BALANCE);
 		let message_two = Message {
 			block_no: 0,
 			nonce: 1,
@@ -695,14 +924,281 @@ fn test_submit_incoming_message_happy_path_first_message() {
 			data: vec![1u8; 10],
 		};
 		let new_stake = 2 * UNIT_BALANCE;
-		<AllowListTestingRelayers<Test>>::insert(network_id, relayer_2);
 		assert_ok!(Thea::submit_incoming_message(
 			RuntimeOrigin::signed(relayer_2),
 			message_two.clone(),
 			new_stake
 		));
-		assert_eq!(Balances::reserved_balance(&relayer_2), 2 * UNIT_BALANCE);
+		assert_eq!(Balances::reserved_balance(&relayer_2), 3 * UNIT_BALANCE);
+		assert_eq!(Balances::reserved_balance(&relayer), 1 * UNIT_BALANCE);
+	})
+}
+
+fn set_relayer_network_config(network: Network) {
+	let network_config = thea_primitives::types::NetworkConfig {
+		fork_period: 0,
+		min_stake: 1 * UNIT_BALANCE,
+		fisherman_stake: 1 * UNIT_BALANCE,
+		network_type: NetworkType::Parachain,
+		signature_scheme: SignatureScheme::Ecdsa,
+		fork_period_tiers: vec![],
+	};
+	<NetworkConfig<Test>>::insert(network, network_config);
+}
+
+#[test]
+fn test_register_and_unbond_relayer() {
+	new_test_ext().execute_with(|| {
+		let relayer = 1u64;
+		let network = 2;
+		let _ = Balances::deposit_creating(&relayer, 100 * UNIT_BALANCE);
+		set_relayer_network_config(network);
+		assert_noop!(
+			Thea::register_relayer(RuntimeOrigin::signed(relayer), network, UNIT_BALANCE / 2),
+			Error::<Test>::NotEnoughStake
+		);
+		assert_ok!(Thea::register_relayer(RuntimeOrigin::signed(relayer), network, UNIT_BALANCE));
+		assert_noop!(
+			Thea::register_relayer(RuntimeOrigin::signed(relayer), network, UNIT_BALANCE),
+			Error::<Test>::RelayerAlreadyRegistered
+		);
+		assert_eq!(Balances::reserved_balance(&relayer), UNIT_BALANCE);
+		assert_eq!(Thea::active_relayers(network).to_vec(), vec![relayer]);
+		assert_noop!(
+			Thea::withdraw_unbonded(RuntimeOrigin::signed(relayer), network),
+			Error::<Test>::RelayerNotUnbonding
+		);
+		assert_ok!(Thea::unbond_relayer(RuntimeOrigin::signed(relayer), network));
+		assert!(Thea::active_relayers(network).is_empty());
+		assert_eq!(
+			Thea::relayers(network, relayer).unwrap().unbonding_at,
+			Some(RelayerUnbondingPeriod::get())
+		);
+		let message = Message {
+			block_no: 0,
+			nonce: 1,
+			network,
+			payload_type: PayloadType::L1Deposit,
+			data: vec![],
+		};
+		assert_noop!(
+			Thea::submit_incoming_message(RuntimeOrigin::signed(relayer), message, UNIT_BALANCE),
+			Error::<Test>::NoRelayersFound
+		);
+		assert_noop!(
+			Thea::unbond_relayer(RuntimeOrigin::signed(relayer), network),
+			Error::<Test>::RelayerUnbonding
+		);
+		assert_noop!(
+			Thea::withdraw_unbonded(RuntimeOrigin::signed(relayer), network),
+			Error::<Test>::UnbondingPeriodNotOver
+		);
+		System::set_block_number(RelayerUnbondingPeriod::get().into());
+		assert_ok!(Thea::withdraw_unbonded(RuntimeOrigin::signed(relayer), network));
 		assert_eq!(Balances::reserved_balance(&relayer), 0);
+		assert!(Thea::relayers(network, relayer).is_none());
+	})
+}
+
+#[test]
+fn test_register_relayer_max_relayers() {
+	new_test_ext().execute_with(|| {
+		let network = 2;
+		set_relayer_network_config(network);
+		for relayer in 1..=MaxRelayers::get() as u64 {
+			let _ = Balances::deposit_creating(&relayer, 100 * UNIT_BALANCE);
+			assert_ok!(Thea::register_relayer(
+				RuntimeOrigin::signed(relayer),
+				network,
+				UNIT_BALANCE
+			));
+		}
+		let relayer = MaxRelayers::get() as u64 + 1;
+		let _ = Balances::deposit_creating(&relayer, 100 * UNIT_BALANCE);
+		assert_noop!(
+			Thea::register_relayer(RuntimeOrigin::signed(relayer), network, UNIT_BALANCE),
+			Error::<Test>::TooManyRelayers
+		);
+	})
+}
+
+#[test]
+fn test_submit_incoming_message_follows_leader_schedule() {
+	new_test_ext().execute_with(|| {
+		let network = 2;
+		set_relayer_network_config(network);
+		let outsider = 3u64;
+		for relayer in 1..=3u64 {
+			let _ = Balances::deposit_creating(&relayer, 100 * UNIT_BALANCE);
+		}
+		for relayer in 1..=2u64 {
+			assert_ok!(Thea::register_relayer(
+				RuntimeOrigin::signed(relayer),
+				network,
+				UNIT_BALANCE
+			));
+		}
+		let message = Message {
+			block_no: 0,
+			nonce: 1,
+			network,
+			payload_type: PayloadType::L1Deposit,
+			data: vec![],
+		};
+		assert_noop!(
+			Thea::submit_incoming_message(
+				RuntimeOrigin::signed(outsider),
+				message.clone(),
+				UNIT_BALANCE
+			),
+			Error::<Test>::RelayerNotRegistered
+		);
+		// First slot belongs to relayer 1
+		assert_eq!(Thea::scheduled_relayer(network), Some(1));
+		assert_noop!(
+			Thea::submit_incoming_message(RuntimeOrigin::signed(2), message.clone(), UNIT_BALANCE),
+			Error::<Test>::NotScheduledRelayer
+		);
+		// Next slot belongs to relayer 2
+		System::set_block_number(RelayerRotationPeriod::get().into());
+		assert_eq!(Thea::scheduled_relayer(network), Some(2));
+		assert_ok!(Thea::submit_incoming_message(
+			RuntimeOrigin::signed(2),
+			message.clone(),
+			UNIT_BALANCE
+		));
+		// Relayer 1 can still compete with a higher stake
+		let mut competing_message = message;
+		competing_message.data = vec![1u8; 10];
+		assert_ok!(Thea::submit_incoming_message(
+			RuntimeOrigin::signed(1),
+			competing_message.clone(),
+			2 * UNIT_BALANCE
+		));
+		assert_eq!(
+			<IncomingMessagesQueue<Test>>::get(network, 1).unwrap().message,
+			competing_message
+		);
+	})
+}
+
+#[test]
+fn test_fork_period_scales_with_deposit_value() {
+	new_test_ext().execute_with(|| {
+		let network = 2;
+		set_relayer_network_config(network);
+		for relayer in 1..=2u64 {
+			let _ = Balances::deposit_creating(&relayer, 100 * UNIT_BALANCE);
+			assert_ok!(Thea::register_relayer(
+				RuntimeOrigin::signed(relayer),
+				network,
+				UNIT_BALANCE
+			));
+		}
+		let tiers = vec![
+			ForkPeriodTier { min_value: 100 * UNIT_BALANCE, fork_period: 50 },
+			ForkPeriodTier { min_value: 10_000 * UNIT_BALANCE, fork_period: 500 },
+		];
+		assert_noop!(
+			Thea::set_fork_period_tiers(RuntimeOrigin::signed(1), network, tiers.clone()),
+			BadOrigin
+		);
+		assert_noop!(
+			Thea::set_fork_period_tiers(
+				RuntimeOrigin::root(),
+				network,
+				tiers.iter().rev().copied().collect()
+			),
+			Error::<Test>::InvalidForkPeriodTiers
+		);
+		assert_noop!(
+			Thea::set_fork_period_tiers(
+				RuntimeOrigin::root(),
+				network,
+				vec![
+					ForkPeriodTier { min_value: 100 * UNIT_BALANCE, fork_period: 500 },
+					ForkPeriodTier { min_value: 10_000 * UNIT_BALANCE, fork_period: 50 },
+				]
+			),
+			Error::<Test>::InvalidForkPeriodTiers
+		);
+		assert_ok!(Thea::set_fork_period_tiers(RuntimeOrigin::root(), network, tiers.clone()));
+		assert_eq!(<NetworkConfig<Test>>::get(network).fork_period_tiers, tiers);
+		thea_executor::Metadata::<Test>::insert(1, AssetMetadata::new(12).unwrap());
+
+		let deposit_message = |amount: u128| Message {
+			block_no: 0,
+			nonce: 1,
+			network,
+			payload_type: PayloadType::L1Deposit,
+			data: vec![thea_primitives::types::Deposit {
+				id: vec![],
+				recipient: 1u64,
+				asset_id: 1,
+				amount,
+				extra: vec![],
+			}]
+			.encode(),
+		};
+		let config = <NetworkConfig<Test>>::get(network);
+		assert_eq!(Thea::fork_period(&config, &deposit_message(UNIT_BALANCE)), 0);
+		assert_eq!(Thea::fork_period(&config, &deposit_message(100 * UNIT_BALANCE)), 50);
+		// Deposits that can not be valued wait the longest
+		let mut undecodable = deposit_message(UNIT_BALANCE);
+		undecodable.data = vec![1, 2, 3];
+		assert_eq!(Thea::fork_period(&config, &undecodable), 500);
+
+		let now = System::block_number() as u32;
+		assert_ok!(Thea::submit_incoming_message(
+			RuntimeOrigin::signed(1),
+			deposit_message(UNIT_BALANCE),
+			UNIT_BALANCE
+		));
+		assert_eq!(<IncomingMessagesQueue<Test>>::get(network, 1).unwrap().execute_at, now);
+		// Overriding message is delayed by the value it moves
+		assert_ok!(Thea::submit_incoming_message(
+			RuntimeOrigin::signed(2),
+			deposit_message(20_000 * UNIT_BALANCE),
+			2 * UNIT_BALANCE
+		));
+		assert_eq!(<IncomingMessagesQueue<Test>>::get(network, 1).unwrap().execute_at, now + 500);
+	})
+}
+
+#[test]
+fn test_handle_misbehaviour_slashes_relayer_bond() {
+	new_test_ext().execute_with(|| {
+		let network = 2;
+		set_relayer_network_config(network);
+		let relayer = 1u64;
+		let fisherman = 2u64;
+		let _ = Balances::deposit_creating(&relayer, 100 * UNIT_BALANCE);
+		let _ = Balances::deposit_creating(&fisherman, 100 * UNIT_BALANCE);
+		assert_ok!(Thea::register_relayer(
+			RuntimeOrigin::signed(relayer),
+			network,
+			10 * UNIT_BALANCE
+		));
+		let message = Message {
+			block_no: 0,
+			nonce: 1,
+			network,
+			payload_type: PayloadType::L1Deposit,
+			data: vec![],
+		};
+		assert_ok!(Thea::submit_incoming_message(
+			RuntimeOrigin::signed(relayer),
+			message,
+			UNIT_BALANCE
+		));
+		assert_ok!(Thea::report_misbehaviour(RuntimeOrigin::signed(fisherman), network, 1));
+		assert_ok!(Thea::handle_misbehaviour(RuntimeOrigin::root(), network, 1, true));
+		// Message stake goes to the fisherman, bond is burned
+		assert_eq!(Balances::reserved_balance(&relayer), 0);
+		assert_eq!(Balances::free_balance(&relayer), 89 * UNIT_BALANCE);
+		assert_eq!(Balances::free_balance(&fisherman), 101 * UNIT_BALANCE);
+		assert!(Thea::relayers(network, relayer).is_none());
+		assert!(Thea::active_relayers(network).is_empty());
 	})
 }
 
@@ -764,3 +1260,759 @@ fn test_locks() {
 		assert_eq!(Balances::reserved_balance(&relayer), 0);
 	})
 }
+
+#[test]
+fn test_network_ingress_pause_keeps_messages_queued() {
+	new_test_ext().execute_with(|| {
+		let mut networks: BTreeSet<Network> = BTreeSet::new();
+		let network = 1;
+		networks.insert(network);
+		<ActiveNetworks<Test>>::put(networks);
+		let relayer = 1u64;
+		let _ = Balances::deposit_creating(&relayer, 100 * UNIT_BALANCE);
+		let stake = 1 * UNIT_BALANCE;
+		Balances::hold(&THEA_HOLD_REASON, &relayer, stake).unwrap();
+		let message = Message {
+			block_no: 0,
+			nonce: 1,
+			network,
+			payload_type: PayloadType::L1Deposit,
+			data: vec![],
+		};
+		let incoming_message = IncomingMessage { message, relayer, stake, execute_at: 5 };
+		<IncomingMessagesQueue<Test>>::insert(network, 1, incoming_message);
+		// Messages that are not due yet stay queued
+		Thea::on_initialize(1);
+		assert!(<IncomingMessagesQueue<Test>>::get(network, 1).is_some());
+
+		assert_ok!(Thea::set_network_pause(
+			RuntimeOrigin::root(),
+			network,
+			Some(PauseState::Ingress)
+		));
+		Thea::on_initialize(6);
+		assert!(<IncomingMessagesQueue<Test>>::get(network, 1).is_some());
+		assert_eq!(<IncomingNonce<Test>>::get(network), 0);
+
+		assert_ok!(Thea::set_network_pause(RuntimeOrigin::root(), network, None));
+		Thea::on_initialize(7);
+		assert!(<IncomingMessagesQueue<Test>>::get(network, 1).is_none());
+		assert_eq!(<IncomingNonce<Test>>::get(network), 1);
+	})
+}
+
+#[test]
+fn test_pause_origin_can_only_tighten_pauses() {
+	new_test_ext().execute_with(|| {
+		let council = SecurityCouncil::get();
+		assert_err!(
+			Thea::set_asset_pause(RuntimeOrigin::signed(1), 100, Some(PauseState::Both)),
+			BadOrigin
+		);
+		assert_ok!(Thea::set_asset_pause(
+			RuntimeOrigin::signed(council),
+			100,
+			Some(PauseState::Egress)
+		));
+		assert_ok!(Thea::set_asset_pause(
+			RuntimeOrigin::signed(council),
+			100,
+			Some(PauseState::Both)
+		));
+		assert_eq!(Thea::asset_pause_state(100), Some(PauseState::Both));
+		assert_err!(
+			Thea::set_asset_pause(RuntimeOrigin::signed(council), 100, Some(PauseState::Ingress)),
+			Error::<Test>::PauseLiftNotAllowed
+		);
+		assert_err!(
+			Thea::set_asset_pause(RuntimeOrigin::signed(council), 100, None),
+			Error::<Test>::PauseLiftNotAllowed
+		);
+		assert_ok!(Thea::set_asset_pause(RuntimeOrigin::root(), 100, None));
+		assert_eq!(Thea::asset_pause_state(100), None);
+
+		assert_ok!(Thea::set_network_pause(
+			RuntimeOrigin::signed(council),
+			2,
+			Some(PauseState::Both)
+		));
+		assert_eq!(<Thea as TheaPauseSwitch>::network_pause(2), Some(PauseState::Both));
+	})
+}
+
+#[test]
+fn test_outgoing_message_status_tracks_signatures() {
+	new_test_ext().execute_with(|| {
+		let network = 2;
+		let validator_set_id = Thea::validator_set_id();
+		let authorities = (1..=3u8)
+			.map(|i| sp_core::ecdsa::Public::from_raw([i; 33]).into())
+			.collect::<Vec<_>>();
+		<Authorities<Test>>::insert(
+			validator_set_id,
+			BoundedVec::truncate_from(authorities.clone()),
+		);
+		assert_eq!(Thea::get_authorities(validator_set_id), authorities);
+		assert!(Thea::get_outgoing_message_status(network, None).is_none());
+
+		let message = Message {
+			block_no: 0,
+			nonce: 1,
+			network,
+			payload_type: PayloadType::L1Deposit,
+			data: vec![],
+		};
+		<OutgoingNonce<Test>>::insert(network, 1);
+		<OutgoingMessages<Test>>::insert(network, 1, message.clone());
+		let status = Thea::get_outgoing_message_status(network, None).unwrap();
+		assert_eq!(status.message, message);
+		assert!(status.signers.is_empty());
+		assert_eq!(status.required_signatures, 2);
+		assert!(!status.threshold_reached);
+
+		let signature: <Test as Config>::Signature = sp_core::ecdsa::Signature::default().into();
+		for auth_index in 0..2 {
+			assert_ok!(Thea::submit_signed_outgoing_messages(
+				RuntimeOrigin::none(),
+				auth_index,
+				validator_set_id,
+				vec![(network, 1, signature.clone())]
+			));
+		}
+		// The next message is not created yet
+		assert!(Thea::get_outgoing_message_status(network, None).is_none());
+		let status = Thea::get_outgoing_message_status(network, Some(1)).unwrap();
+		assert_eq!(status.signers, vec![0, 1]);
+		assert_eq!(status.signatures.len(), 2);
+		assert!(status.threshold_reached);
+		assert_eq!(
+			Thea::get_network_nonces(network),
+			NetworkNonces { incoming: 0, outgoing: 1, signed_outgoing: 1 }
+		);
+	})
+}
+
+#[test]
+fn test_outgoing_messages_are_committed_and_signed_as_merkle_root() {
+	new_test_ext().execute_with(|| {
+		let network = 2;
+		<ActiveNetworks<Test>>::put(BTreeSet::from([network]));
+		assert_err!(
+			Thea::set_commitment_period(RuntimeOrigin::root(), network, Some(0)),
+			Error::<Test>::InvalidCommitmentPeriod
+		);
+		assert_ok!(Thea::set_commitment_period(RuntimeOrigin::root(), network, Some(10)));
+		let messages = (1..=5u64)
+			.map(|nonce| Message {
+				block_no: 0,
+				nonce,
+				network,
+				payload_type: PayloadType::L1Deposit,
+				data: vec![nonce as u8],
+			})
+			.collect::<Vec<_>>();
+		for message in messages.iter() {
+			<OutgoingNonce<Test>>::insert(network, message.nonce);
+			<OutgoingMessages<Test>>::insert(network, message.nonce, message.clone());
+		}
+		// Messages are only committed at the end of the period
+		Thea::on_initialize(5);
+		assert_eq!(Thea::commitment_nonce(network), 0);
+		Thea::on_initialize(10);
+		let commitment = Thea::message_commitments(network, 1).unwrap();
+		assert_eq!((commitment.first_nonce, commitment.last_nonce), (1, 5));
+		for message in messages.iter() {
+			let proof = Thea::get_message_proof(network, message.nonce).unwrap();
+			assert_eq!(&proof.message, message);
+			let leaf = message.encode();
+			assert!(binary_merkle_tree::verify_proof::<Keccak256, _, _>(
+				&commitment.root,
+				proof.proof,
+				messages.len(),
+				proof.leaf_index as usize,
+				&leaf[..]
+			));
+		}
+		assert!(Thea::get_message_proof(network, 6).is_none());
+		// Nothing new to commit
+		Thea::on_initialize(20);
+		assert_eq!(Thea::commitment_nonce(network), 1);
+
+		// Validators sign the root instead of the messages
+		let validator = sp_core::ecdsa::Pair::from_seed(b"12345678901234567890123456789012");
+		let validator_set_id = Thea::validator_set_id();
+		<Authorities<Test>>::insert(
+			validator_set_id,
+			BoundedVec::truncate_from(vec![validator.public().into()]),
+		);
+		let msg_hash = sp_io::hashing::sha2_256(messages[0].encode().as_slice());
+		let signatures = vec![(network, 1, validator.sign_prehashed(&msg_hash).into())];
+		assert_err!(
+			Thea::validate_signed_outgoing_message(&0, &validator_set_id, &signatures),
+			InvalidTransaction::Custom(10)
+		);
+		let commitment_hash = Thea::commitment_hash(&commitment);
+		let signatures = vec![(network, 1, validator.sign_prehashed(&commitment_hash).into())];
+		assert_ok!(Thea::validate_signed_commitments(&0, &validator_set_id, &signatures));
+		assert_ok!(Thea::submit_signed_commitments(
+			RuntimeOrigin::none(),
+			0,
+			validator_set_id,
+			signatures
+		));
+		assert_eq!(<SignedCommitmentNonce<Test>>::get(network), 1);
+		assert!(Thea::get_signed_commitment(network, 1).unwrap().contains_signature(&0));
+
+		// Committed messages are not signed again once the commitment period is removed
+		assert_ok!(Thea::set_commitment_period(RuntimeOrigin::root(), network, None));
+		assert_eq!(<SignedOutgoingNonce<Test>>::get(network), 5);
+	})
+}
+
+#[test]
+fn test_outgoing_messages_of_evm_networks_are_committed_as_abi() {
+	new_test_ext().execute_with(|| {
+		let network = 2;
+		<ActiveNetworks<Test>>::put(BTreeSet::from([network]));
+		<NetworkConfig<Test>>::insert(
+			network,
+			thea_primitives::types::NetworkConfig {
+				network_type: NetworkType::Evm,
+				..Default::default()
+			},
+		);
+		assert_ok!(Thea::set_commitment_period(RuntimeOrigin::root(), network, Some(10)));
+		let messages = (1..=3u64)
+			.map(|nonce| Message {
+				block_no: 0,
+				nonce,
+				network,
+				payload_type: PayloadType::L1Deposit,
+				data: vec![nonce as u8],
+			})
+			.collect::<Vec<_>>();
+		for message in messages.iter() {
+			<OutgoingNonce<Test>>::insert(network, message.nonce);
+			<OutgoingMessages<Test>>::insert(network, message.nonce, message.clone());
+		}
+		Thea::on_initialize(10);
+		let commitment = Thea::message_commitments(network, 1).unwrap();
+		for message in messages.iter() {
+			let proof = Thea::get_message_proof(network, message.nonce).unwrap();
+			let leaf = thea_primitives::abi::encode_message(message);
+			assert!(binary_merkle_tree::verify_proof::<Keccak256, _, _>(
+				&commitment.root,
+				proof.proof,
+				messages.len(),
+				proof.leaf_index as usize,
+				&leaf[..]
+			));
+		}
+		assert_eq!(
+			Thea::commitment_hash(&commitment),
+			thea_primitives::abi::commitment_digest(&commitment)
+		);
+	})
+}
+
+#[test]
+fn test_commitment_fails_on_missing_outgoing_message() {
+	new_test_ext().execute_with(|| {
+		let network = 2;
+		<ActiveNetworks<Test>>::put(BTreeSet::from([network]));
+		assert_ok!(Thea::set_commitment_period(RuntimeOrigin::root(), network, Some(10)));
+		<OutgoingNonce<Test>>::insert(network, 2);
+		<OutgoingMessages<Test>>::insert(
+			network,
+			2,
+			Message {
+				block_no: 0,
+				nonce: 2,
+				network,
+				payload_type: PayloadType::L1Deposit,
+				data: vec![],
+			},
+		);
+		Thea::on_initialize(10);
+		assert_eq!(Thea::commitment_nonce(network), 0);
+		assert_eq!(<CommittedOutgoingNonce<Test>>::get(network), 0);
+	})
+}
+
+mod fisherman {
+	use super::*;
+	use crate::fisherman::FISHERMAN_RPC_URL;
+	use parity_scale_codec::Decode;
+	use sp_core::offchain::{
+		testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt,
+	};
+	use sp_runtime::testing::UintAuthorityId;
+
+	const RPC_URL: &str = "http://localhost:9933";
+
+	fn queued_message(network: Network, execute_at: u32) -> Message {
+		let message = Message {
+			block_no: 0,
+			nonce: 1,
+			network,
+			payload_type: PayloadType::L1Deposit,
+			data: vec![],
+		};
+		<ActiveNetworks<Test>>::put(BTreeSet::from([network]));
+		<IncomingMessagesQueue<Test>>::insert(
+			network,
+			1,
+			IncomingMessage { message: message.clone(), relayer: 1, stake: 0, execute_at },
+		);
+		message
+	}
+
+	fn rpc_request(network: Network, foreign_message: Message) -> PendingRequest {
+		PendingRequest {
+			method: "POST".into(),
+			uri: RPC_URL.into(),
+			headers: vec![("Content-Type".into(), "application/json".into())],
+			body: format!(
+				r#"{{"jsonrpc":"2.0","id":1,"method":"thea_getMessage","params":[{},1]}}"#,
+				network
+			)
+			.into_bytes(),
+			response: Some(
+				serde_json::to_vec(&serde_json::json!({
+					"jsonrpc": "2.0",
+					"id": 1,
+					"result": foreign_message,
+				}))
+				.unwrap(),
+			),
+			sent: true,
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn test_fisherman_reports_mismatching_message() {
+		let fisherman = 5u64;
+		let network = 2;
+		let (offchain, offchain_state) = TestOffchainExt::new();
+		let (pool, pool_state) = TestTransactionPoolExt::new();
+		let mut ext = new_test_ext();
+		ext.register_extension(OffchainDbExt::new(offchain.clone()));
+		ext.register_extension(OffchainWorkerExt::new(offchain));
+		ext.register_extension(TransactionPoolExt::new(pool));
+		ext.execute_with(|| {
+			UintAuthorityId::set_all_keys(vec![fisherman]);
+			sp_io::offchain::local_storage_set(
+				StorageKind::PERSISTENT,
+				FISHERMAN_RPC_URL,
+				RPC_URL.as_bytes(),
+			);
+			let mut foreign_message = queued_message(network, 10);
+			foreign_message.data = vec![1u8; 10];
+			offchain_state.write().expect_request(rpc_request(network, foreign_message));
+
+			assert_ok!(Thea::run_fisherman(1));
+			let tx = pool_state.write().transactions.pop().unwrap();
+			let tx = UncheckedExtrinsic::decode(&mut &*tx).unwrap();
+			assert_eq!(tx.signature.unwrap().0, fisherman);
+			assert_eq!(
+				tx.function,
+				RuntimeCall::Thea(crate::Call::report_misbehaviour { network, nonce: 1 })
+			);
+			// Same message is not reported twice in a row
+			assert_ok!(Thea::run_fisherman(2));
+			assert!(pool_state.read().transactions.is_empty());
+		})
+	}
+
+	#[test]
+	fn test_fisherman_ignores_matching_and_expiring_messages() {
+		let network = 2;
+		let (offchain, offchain_state) = TestOffchainExt::new();
+		let (pool, pool_state) = TestTransactionPoolExt::new();
+		let mut ext = new_test_ext();
+		ext.register_extension(OffchainDbExt::new(offchain.clone()));
+		ext.register_extension(OffchainWorkerExt::new(offchain));
+		ext.register_extension(TransactionPoolExt::new(pool));
+		ext.execute_with(|| {
+			UintAuthorityId::set_all_keys(vec![5u64]);
+			sp_io::offchain::local_storage_set(
+				StorageKind::PERSISTENT,
+				FISHERMAN_RPC_URL,
+				RPC_URL.as_bytes(),
+			);
+			let message = queued_message(network, 10);
+			offchain_state.write().expect_request(rpc_request(network, message));
+			assert_ok!(Thea::run_fisherman(1));
+			assert!(pool_state.read().transactions.is_empty());
+			// Too late to report, no request is sent
+			assert_ok!(Thea::run_fisherman(9));
+			assert!(pool_state.read().transactions.is_empty());
+		})
+	}
+}
+
+mod bls {
+	use super::*;
+	use thea_primitives::types::SignatureScheme;
+
+	fn setup_bls_authorities(validator_set_id: u64, count: u8) -> Vec<[u8; 32]> {
+		let mut auths = <Authorities<Test>>::get(validator_set_id);
+		let mut secrets = Vec::new();
+		for auth_index in 0..count {
+			let validator = sp_core::ecdsa::Pair::from_seed(&[auth_index + 1; 32]);
+			auths.try_push(validator.public().into()).unwrap();
+			<Authorities<Test>>::insert(validator_set_id, auths.clone());
+			let secret = [auth_index + 1; 32];
+			let public = bls_primitives::public_from_secret(&secret).unwrap();
+			let proof_of_possession =
+				bls_primitives::sign_with_secret(&secret, public.as_ref()).unwrap();
+			let payload_hash = sp_io::hashing::sha2_256(
+				(validator_set_id, auth_index as u32, public).encode().as_slice(),
+			);
+			let signature: <Test as Config>::Signature =
+				validator.sign_prehashed(&payload_hash).into();
+			assert_ok!(Thea::validate_bls_public_key(
+				&(auth_index as u32),
+				&validator_set_id,
+				&public,
+				&proof_of_possession,
+				&signature
+			));
+			assert_ok!(Thea::submit_bls_public_key(
+				RuntimeOrigin::none(),
+				auth_index as u32,
+				validator_set_id,
+				public,
+				proof_of_possession,
+				signature
+			));
+			secrets.push(secret);
+		}
+		secrets
+	}
+
+	#[test]
+	fn test_validate_bls_public_key_rejects_invalid_proofs() {
+		new_test_ext().execute_with(|| {
+			let validator_set_id = 1;
+			let validator = sp_core::ecdsa::Pair::from_seed(&[1u8; 32]);
+			let mut auths = <Authorities<Test>>::get(validator_set_id);
+			auths.try_push(validator.public().into()).unwrap();
+			<Authorities<Test>>::insert(validator_set_id, auths);
+			let secret = [1u8; 32];
+			let public = bls_primitives::public_from_secret(&secret).unwrap();
+			let payload_hash =
+				sp_io::hashing::sha2_256((validator_set_id, 0u32, public).encode().as_slice());
+			let signature: <Test as Config>::Signature =
+				validator.sign_prehashed(&payload_hash).into();
+			// Proof of possession from another key
+			let proof_of_possession =
+				bls_primitives::sign_with_secret(&[2u8; 32], public.as_ref()).unwrap();
+			assert_noop!(
+				Thea::validate_bls_public_key(
+					&0,
+					&validator_set_id,
+					&public,
+					&proof_of_possession,
+					&signature
+				),
+				InvalidTransaction::Custom(8)
+			);
+			// Not authorized by the Thea key
+			let proof_of_possession =
+				bls_primitives::sign_with_secret(&secret, public.as_ref()).unwrap();
+			assert_noop!(
+				Thea::validate_bls_public_key(
+					&0,
+					&validator_set_id,
+					&public,
+					&proof_of_possession,
+					&sp_core::ecdsa::Signature::default().into()
+				),
+				InvalidTransaction::Custom(6)
+			);
+		})
+	}
+
+	#[test]
+	fn test_bls_signed_outgoing_messages_are_aggregated() {
+		new_test_ext().execute_with(|| {
+			let validator_set_id = 1;
+			let network = 2;
+			let nonce = 1;
+			let secrets = setup_bls_authorities(validator_set_id, 3);
+			assert_ok!(Thea::set_signature_scheme(
+				RuntimeOrigin::root(),
+				network,
+				SignatureScheme::Bls
+			));
+			let message = Message {
+				block_no: 0,
+				nonce,
+				network,
+				payload_type: PayloadType::L1Deposit,
+				data: vec![],
+			};
+			<OutgoingMessages<Test>>::insert(network, nonce, message.clone());
+			let msg_hash = sp_io::hashing::sha2_256(message.encode().as_slice());
+			// ECDSA signatures are rejected for BLS networks
+			let ecdsa_signature =
+				sp_core::ecdsa::Pair::from_seed(&[1u8; 32]).sign_prehashed(&msg_hash).into();
+			assert_noop!(
+				Thea::validate_signed_outgoing_message(
+					&0,
+					&validator_set_id,
+					&vec![(network, nonce, ecdsa_signature)]
+				),
+				InvalidTransaction::Custom(7)
+			);
+
+			let first = vec![(
+				network,
+				nonce,
+				bls_primitives::sign_with_secret(&secrets[0], &msg_hash).unwrap(),
+			)];
+			assert_ok!(Thea::validate_bls_signed_outgoing_message(&0, &validator_set_id, &first));
+			assert_ok!(Thea::submit_bls_signed_outgoing_messages(
+				RuntimeOrigin::none(),
+				0,
+				validator_set_id,
+				first.clone()
+			));
+			assert_eq!(<SignedOutgoingNonce<Test>>::get(network), 0);
+			assert_noop!(
+				Thea::validate_bls_signed_outgoing_message(&0, &validator_set_id, &first),
+				InvalidTransaction::Custom(4)
+			);
+			// Signatures of an older validator set are rejected
+			let stale_secrets = setup_bls_authorities(validator_set_id - 1, 2);
+			let stale = vec![(
+				network,
+				nonce,
+				bls_primitives::sign_with_secret(&stale_secrets[1], &msg_hash).unwrap(),
+			)];
+			assert_noop!(
+				Thea::validate_bls_signed_outgoing_message(&1, &(validator_set_id - 1), &stale),
+				InvalidTransaction::Custom(11)
+			);
+			// Signature of another key is rejected
+			let wrong = vec![(
+				network,
+				nonce,
+				bls_primitives::sign_with_secret(&secrets[2], &msg_hash).unwrap(),
+			)];
+			assert_noop!(
+				Thea::validate_bls_signed_outgoing_message(&1, &validator_set_id, &wrong),
+				InvalidTransaction::Custom(6)
+			);
+
+			let second = vec![(
+				network,
+				nonce,
+				bls_primitives::sign_with_secret(&secrets[1], &msg_hash).unwrap(),
+			)];
+			assert_ok!(Thea::validate_bls_signed_outgoing_message(&1, &validator_set_id, &second));
+			assert_ok!(Thea::submit_bls_signed_outgoing_messages(
+				RuntimeOrigin::none(),
+				1,
+				validator_set_id,
+				second
+			));
+			assert_eq!(<SignedOutgoingNonce<Test>>::get(network), nonce);
+
+			let signed_message = Thea::bls_signed_outgoing_messages(network, nonce).unwrap();
+			assert_eq!(signed_message.signers, vec![0b011]);
+			assert_eq!(signed_message.signer_indexes(), vec![0, 1]);
+			let publics = signed_message
+				.signer_indexes()
+				.iter()
+				.map(|auth_index| Thea::bls_authorities(validator_set_id, auth_index).unwrap())
+				.collect::<Vec<_>>();
+			assert!(signed_message.signature.verify(&publics, &msg_hash));
+		})
+	}
+}
+
+mod signing_keys {
+	use super::*;
+	use thea_primitives::types::{SchemeSignature, SignatureScheme, SigningKey};
+
+	fn register_signing_key(
+		validator_set_id: u64,
+		auth_index: u32,
+		validator: &sp_core::ecdsa::Pair,
+		key: SigningKey,
+	) {
+		let payload_hash = sp_io::hashing::sha2_256(
+			(validator_set_id, auth_index, key.clone()).encode().as_slice(),
+		);
+		let signature: <Test as Config>::Signature = validator.sign_prehashed(&payload_hash).into();
+		assert_ok!(Thea::validate_signing_key(&auth_index, &validator_set_id, &key, &signature));
+		assert_ok!(Thea::submit_signing_key(
+			RuntimeOrigin::none(),
+			auth_index,
+			validator_set_id,
+			key.clone(),
+			signature.clone()
+		));
+		assert_noop!(
+			Thea::validate_signing_key(&auth_index, &validator_set_id, &key, &signature),
+			InvalidTransaction::Custom(4)
+		);
+	}
+
+	#[test]
+	fn test_ed25519_and_sr25519_signed_outgoing_messages() {
+		new_test_ext().execute_with(|| {
+			let validator_set_id = 1;
+			let network = 2;
+			let nonce = 1;
+			let validators =
+				(1..=2u8).map(|i| sp_core::ecdsa::Pair::from_seed(&[i; 32])).collect::<Vec<_>>();
+			<Authorities<Test>>::insert(
+				validator_set_id,
+				BoundedVec::truncate_from(
+					validators.iter().map(|v| v.public().into()).collect::<Vec<_>>(),
+				),
+			);
+			let ed25519_keys = (1..=2u8)
+				.map(|i| sp_core::ed25519::Pair::from_seed(&[i; 32]))
+				.collect::<Vec<_>>();
+			let sr25519_keys = (1..=2u8)
+				.map(|i| sp_core::sr25519::Pair::from_seed(&[i; 32]))
+				.collect::<Vec<_>>();
+			// Keys must be authorized by the Thea key of the authority
+			assert_noop!(
+				Thea::validate_signing_key(
+					&0,
+					&validator_set_id,
+					&SigningKey::Ed25519(ed25519_keys[0].public()),
+					&sp_core::ecdsa::Signature::default().into()
+				),
+				InvalidTransaction::Custom(6)
+			);
+			for (auth_index, validator) in validators.iter().enumerate() {
+				register_signing_key(
+					validator_set_id,
+					auth_index as u32,
+					validator,
+					SigningKey::Ed25519(ed25519_keys[auth_index].public()),
+				);
+				register_signing_key(
+					validator_set_id,
+					auth_index as u32,
+					validator,
+					SigningKey::Sr25519(sr25519_keys[auth_index].public()),
+				);
+			}
+			assert_eq!(
+				Thea::get_signing_keys(validator_set_id, SignatureScheme::Ed25519),
+				vec![
+					(0, SigningKey::Ed25519(ed25519_keys[0].public())),
+					(1, SigningKey::Ed25519(ed25519_keys[1].public()))
+				]
+			);
+
+			assert_ok!(Thea::set_signature_scheme(
+				RuntimeOrigin::root(),
+				network,
+				SignatureScheme::Sr25519
+			));
+			assert_eq!(Thea::signature_scheme(network), SignatureScheme::Sr25519);
+			let message = Message {
+				block_no: 0,
+				nonce,
+				network,
+				payload_type: PayloadType::L1Deposit,
+				data: vec![],
+			};
+			<OutgoingMessages<Test>>::insert(network, nonce, message.clone());
+			let msg_hash = sp_io::hashing::sha2_256(message.encode().as_slice());
+
+			// Signatures of another scheme are rejected
+			let ed25519_signature =
+				vec![(network, nonce, SchemeSignature::Ed25519(ed25519_keys[0].sign(&msg_hash)))];
+			assert_noop!(
+				Thea::validate_scheme_signed_outgoing_message(
+					&0,
+					&validator_set_id,
+					&ed25519_signature
+				),
+				InvalidTransaction::Custom(7)
+			);
+			// Signature of another key is rejected
+			let wrong =
+				vec![(network, nonce, SchemeSignature::Sr25519(sr25519_keys[1].sign(&msg_hash)))];
+			assert_noop!(
+				Thea::validate_scheme_signed_outgoing_message(&0, &validator_set_id, &wrong),
+				InvalidTransaction::Custom(6)
+			);
+
+			for (auth_index, key) in sr25519_keys.iter().enumerate() {
+				let signatures =
+					vec![(network, nonce, SchemeSignature::Sr25519(key.sign(&msg_hash)))];
+				assert_ok!(Thea::validate_scheme_signed_outgoing_message(
+					&(auth_index as u32),
+					&validator_set_id,
+					&signatures
+				));
+				assert_ok!(Thea::submit_scheme_signed_outgoing_messages(
+					RuntimeOrigin::none(),
+					auth_index as u32,
+					validator_set_id,
+					signatures
+				));
+			}
+			assert_eq!(<SignedOutgoingNonce<Test>>::get(network), nonce);
+			let signed_message = Thea::get_scheme_signed_message(network, nonce).unwrap();
+			assert_eq!(signed_message.signatures.len(), 2);
+			let status = Thea::get_outgoing_message_status(network, Some(nonce)).unwrap();
+			assert_eq!(status.scheme, SignatureScheme::Sr25519);
+			assert_eq!(status.signers, vec![0, 1]);
+			assert!(status.threshold_reached);
+		})
+	}
+}
+
+#[test]
+fn test_migration_to_v1() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Thea>();
+		let network = 2u8;
+		let relayer = 7u64;
+		let mut config_key =
+			frame_support::storage::storage_prefix(b"Thea", b"NetworkConfig").to_vec();
+		config_key.extend(network.encode());
+		frame_support::storage::unhashed::put(
+			&config_key,
+			&(30u32, 10u128, 20u128, NetworkType::Evm),
+		);
+		let mut relayer_key =
+			frame_support::storage::storage_prefix(b"Thea", b"AllowListTestingRelayers").to_vec();
+		relayer_key.extend(network.encode());
+		frame_support::storage::unhashed::put(&relayer_key, &relayer);
+		crate::migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Thea>(), 1);
+		assert_eq!(
+			<NetworkConfig<Test>>::get(network),
+			thea_primitives::types::NetworkConfig {
+				fork_period: 30,
+				min_stake: 10,
+				fisherman_stake: 20,
+				network_type: NetworkType::Evm,
+				signature_scheme: SignatureScheme::Ecdsa,
+				fork_period_tiers: vec![],
+			}
+		);
+		assert!(frame_support::storage::unhashed::get_raw(&relayer_key).is_none());
+		assert_eq!(<ActiveRelayers<Test>>::get(network).to_vec(), vec![relayer]);
+		assert_eq!(<Relayers<Test>>::get(network, relayer).unwrap().bond, 0);
+		assert_err!(
+			Thea::add_relayer_origin_for_network(RuntimeOrigin::root(), network, relayer),
+			Error::<Test>::CallDeprecated
+		);
+	})
+}
@@ -18,8 +18,10 @@
 
 use crate::{
 	pallet::{
-		ActiveNetworks, Authorities, OutgoingMessages, SignedOutgoingMessages, SignedOutgoingNonce,
-		ValidatorSetId,
+		ActiveNetworks, Authorities, BlsAuthorities, BlsSignedOutgoingMessages, CommitmentNonce,
+		CommitmentPeriods, MessageCommitments, OutgoingMessages, SchemeSignedOutgoingMessages,
+		SignedCommitmentNonce, SignedMessageCommitments, SignedOutgoingMessages,
+		SignedOutgoingNonce, SigningKeys, ValidatorSetId,
 	},
 	Call, Config, Pallet, THEA,
 };
@@ -27,7 +29,14 @@ use frame_system::{offchain::SubmitTransaction, pallet_prelude::BlockNumberFor};
 use parity_scale_codec::Encode;
 use sp_application_crypto::RuntimeAppPublic;
 use sp_std::vec::Vec;
-use thea_primitives::Network;
+use thea_primitives::{
+	types::{SchemeSignature, SignatureScheme, SigningKey},
+	Network,
+};
+
+/// Domain separation tag of the BLS secret key used to sign outgoing messages of networks using
+/// the BLS signature scheme, derived from the Thea authority key
+pub const BLS_SECRET_DERIVATION_TAG: &[u8] = b"thea::bls_secret";
 
 impl<T: Config> Pallet<T> {
 	/// Starts the offchain worker instance that checks for finalized next incoming messages
@@ -60,33 +69,113 @@ impl<T: Config> Pallet<T> {
 		let active_networks = <ActiveNetworks<T>>::get();
 		log::info!(target:"thea","List of active networks: {:?}",active_networks);
 
+		let bls_secret = Self::bls_secret(signer);
+		if let Some(secret) = bls_secret.as_ref() {
+			Self::register_bls_public_key(*auth_index as u32, id, signer, secret)?;
+		}
+
 		let mut signed_messages: Vec<(Network, u64, T::Signature)> = Vec::new();
+		let mut bls_signed_messages: Vec<(Network, u64, bls_primitives::Signature)> = Vec::new();
+		let mut signed_commitments: Vec<(Network, u64, T::Signature)> = Vec::new();
+		let mut scheme_signed_messages: Vec<(Network, u64, SchemeSignature)> = Vec::new();
 		// 2. Check for new nonce to process for all networks
 		for network in active_networks {
+			// Commitments made before the commitment period was removed are still signed
+			let commitment_period = <CommitmentPeriods<T>>::contains_key(network);
+			if commitment_period
+				|| <SignedCommitmentNonce<T>>::get(network) < <CommitmentNonce<T>>::get(network)
+			{
+				if let Some(signature) =
+					Self::sign_next_commitment(network, id, *auth_index as u32, signer)?
+				{
+					signed_commitments.push(signature);
+				}
+			}
+			// Only the message commitments are signed for networks with a commitment period
+			if commitment_period {
+				continue;
+			}
 			// Sign message for each network
 			let next_outgoing_nonce = <SignedOutgoingNonce<T>>::get(network).saturating_add(1);
 			log::info!(target:"thea","Next outgoing nonce for network {:?} is: {:?} ",network, next_outgoing_nonce);
+			let scheme = Self::signature_scheme(network);
 			// Check if we already signed it, then continue
-			match <SignedOutgoingMessages<T>>::get(network, next_outgoing_nonce) {
-				None => {},
-				Some(signed_msg) => {
-					// Don't sign again if we already signed it
-					if signed_msg.contains_signature(&(*auth_index as u32)) {
-						log::warn!(target:"thea","Next outgoing nonce for network {:?} is: {:?} is already signed ",network, next_outgoing_nonce);
-						continue;
-					}
+			let already_signed = match scheme {
+				SignatureScheme::Ecdsa => {
+					<SignedOutgoingMessages<T>>::get(network, next_outgoing_nonce)
+						.map(|signed_msg| signed_msg.contains_signature(&(*auth_index as u32)))
+				},
+				SignatureScheme::Bls => {
+					<BlsSignedOutgoingMessages<T>>::get(network, next_outgoing_nonce).map(
+						|signed_msg| {
+							signed_msg.validator_set_id == id
+								&& signed_msg.contains_signature(&(*auth_index as u32))
+						},
+					)
 				},
+				SignatureScheme::Ed25519 | SignatureScheme::Sr25519 => {
+					<SchemeSignedOutgoingMessages<T>>::get(network, next_outgoing_nonce).map(
+						|signed_msg| {
+							signed_msg.validator_set_id == id
+								&& signed_msg.contains_signature(&(*auth_index as u32))
+						},
+					)
+				},
+			};
+			// Don't sign again if we already signed it
+			if already_signed.unwrap_or(false) {
+				log::warn!(target:"thea","Next outgoing nonce for network {:?} is: {:?} is already signed ",network, next_outgoing_nonce);
+				continue;
 			}
 			let message = match <OutgoingMessages<T>>::get(network, next_outgoing_nonce) {
 				None => continue,
 				Some(msg) => msg,
 			};
-			let msg_hash = sp_io::hashing::sha2_256(message.encode().as_slice());
-			// Note: this is a double hash signing
-			let signature =
-				sp_io::crypto::ecdsa_sign_prehashed(THEA, &signer.clone().into(), &msg_hash)
+			let msg_hash = Self::outgoing_message_hash(&message);
+			match scheme {
+				SignatureScheme::Ecdsa => {
+					// Note: this is a double hash signing
+					let signature = sp_io::crypto::ecdsa_sign_prehashed(
+						THEA,
+						&signer.clone().into(),
+						&msg_hash,
+					)
 					.ok_or("Expected signature to be returned")?;
-			signed_messages.push((network, next_outgoing_nonce, signature.into()));
+					signed_messages.push((network, next_outgoing_nonce, signature.into()));
+				},
+				SignatureScheme::Bls => {
+					let secret = match bls_secret.as_ref() {
+						None => {
+							log::warn!(target:"thea","BLS secret key not derived, unable to sign for network {:?}",network);
+							continue;
+						},
+						Some(secret) => secret,
+					};
+					let signature = bls_primitives::sign_with_secret(secret, &msg_hash)
+						.map_err(|_| "Unable to sign with BLS secret key")?;
+					bls_signed_messages.push((network, next_outgoing_nonce, signature));
+				},
+				SignatureScheme::Ed25519 | SignatureScheme::Sr25519 => {
+					let Some(key) =
+						Self::local_signing_key(scheme, *auth_index as u32, id, signer)?
+					else {
+						log::warn!(target:"thea","No {:?} key registered, unable to sign for network {:?}",scheme,network);
+						continue;
+					};
+					let signature = match key {
+						SigningKey::Ed25519(public) => {
+							sp_io::crypto::ed25519_sign(THEA, &public, &msg_hash)
+								.map(SchemeSignature::Ed25519)
+						},
+						SigningKey::Sr25519(public) => {
+							sp_io::crypto::sr25519_sign(THEA, &public, &msg_hash)
+								.map(SchemeSignature::Sr25519)
+						},
+					}
+					.ok_or("Expected signature to be returned")?;
+					scheme_signed_messages.push((network, next_outgoing_nonce, signature));
+				},
+			}
 		}
 
 		if !signed_messages.is_empty() {
@@ -103,7 +192,173 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
+		if !bls_signed_messages.is_empty() {
+			if let Err(()) = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+				Call::<T>::submit_bls_signed_outgoing_messages {
+					auth_index: *auth_index as u32,
+					id,
+					signatures: bls_signed_messages,
+				}
+				.into(),
+			) {
+				log::error!(target:"thea","Error submitting thea BLS unsigned txn");
+			}
+		}
+
+		if !scheme_signed_messages.is_empty() {
+			if let Err(()) = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+				Call::<T>::submit_scheme_signed_outgoing_messages {
+					auth_index: *auth_index as u32,
+					id,
+					signatures: scheme_signed_messages,
+				}
+				.into(),
+			) {
+				log::error!(target:"thea","Error submitting thea scheme signed unsigned txn");
+			}
+		}
+
+		if !signed_commitments.is_empty() {
+			if let Err(()) = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+				Call::<T>::submit_signed_commitments {
+					auth_index: *auth_index as u32,
+					id,
+					signatures: signed_commitments,
+				}
+				.into(),
+			) {
+				log::error!(target:"thea","Error submitting thea commitment unsigned txn");
+			}
+		}
+
 		log::debug!(target:"thea","Thea offchain worker exiting..");
 		Ok(())
 	}
+
+	/// Signs the next message commitment of the network awaiting signatures, if not signed yet
+	fn sign_next_commitment(
+		network: Network,
+		id: thea_primitives::ValidatorSetId,
+		auth_index: u32,
+		signer: &T::TheaId,
+	) -> Result<Option<(Network, u64, T::Signature)>, &'static str> {
+		let next_commitment_nonce = <SignedCommitmentNonce<T>>::get(network).saturating_add(1);
+		if <SignedMessageCommitments<T>>::get(network, next_commitment_nonce).map_or(
+			false,
+			|signed_commitment| {
+				signed_commitment.validator_set_id == id
+					&& signed_commitment.contains_signature(&auth_index)
+			},
+		) {
+			return Ok(None);
+		}
+		let commitment = match <MessageCommitments<T>>::get(network, next_commitment_nonce) {
+			None => return Ok(None),
+			Some(commitment) => commitment,
+		};
+		let commitment_hash = Self::commitment_hash(&commitment);
+		let signature =
+			sp_io::crypto::ecdsa_sign_prehashed(THEA, &signer.clone().into(), &commitment_hash)
+				.ok_or("Expected signature to be returned")?;
+		Ok(Some((network, next_commitment_nonce, signature.into())))
+	}
+
+	/// Returns the key of the scheme of this node registered for the validator set. Registers the
+	/// first key of the scheme found in the keystore if none is registered yet, it can be used
+	/// once the registration is included
+	fn local_signing_key(
+		scheme: SignatureScheme,
+		auth_index: u32,
+		id: thea_primitives::ValidatorSetId,
+		signer: &T::TheaId,
+	) -> Result<Option<SigningKey>, &'static str> {
+		let local_key = match scheme {
+			SignatureScheme::Ed25519 => sp_io::crypto::ed25519_public_keys(THEA)
+				.first()
+				.copied()
+				.map(SigningKey::Ed25519),
+			SignatureScheme::Sr25519 => sp_io::crypto::sr25519_public_keys(THEA)
+				.first()
+				.copied()
+				.map(SigningKey::Sr25519),
+			SignatureScheme::Ecdsa | SignatureScheme::Bls => None,
+		};
+		let Some(local_key) = local_key else {
+			return Ok(None);
+		};
+		match <SigningKeys<T>>::get(id, (scheme, auth_index)) {
+			Some(registered) if registered == local_key => return Ok(Some(registered)),
+			Some(_) => {
+				log::warn!(target:"thea","Another {:?} key is registered for auth index {:?}",scheme,auth_index);
+				return Ok(None);
+			},
+			None => {},
+		}
+		let payload_hash =
+			sp_io::hashing::sha2_256((id, auth_index, &local_key).encode().as_slice());
+		let signature =
+			sp_io::crypto::ecdsa_sign_prehashed(THEA, &signer.clone().into(), &payload_hash)
+				.ok_or("Expected signature to be returned")?;
+		if let Err(()) = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+			Call::<T>::submit_signing_key {
+				auth_index,
+				id,
+				key: local_key,
+				signature: signature.into(),
+			}
+			.into(),
+		) {
+			log::error!(target:"thea","Error submitting thea signing key unsigned txn");
+		}
+		Ok(None)
+	}
+
+	/// Derives the BLS secret key of this authority from its Thea key in the keystore, so the
+	/// secret is never stored outside of it. ECDSA signatures are deterministic, the same key
+	/// always derives the same secret.
+	fn bls_secret(signer: &T::TheaId) -> Option<[u8; 32]> {
+		let tag_hash = sp_io::hashing::sha2_256(BLS_SECRET_DERIVATION_TAG);
+		let signature =
+			sp_io::crypto::ecdsa_sign_prehashed(THEA, &signer.clone().into(), &tag_hash)?;
+		Some(sp_io::hashing::blake2_256(&signature.0))
+	}
+
+	/// Registers the BLS public key of this authority for the given validator set if not yet
+	/// registered
+	fn register_bls_public_key(
+		auth_index: u32,
+		id: thea_primitives::ValidatorSetId,
+		signer: &T::TheaId,
+		secret: &[u8; 32],
+	) -> Result<(), &'static str> {
+		let public =
+			bls_primitives::public_from_secret(secret).map_err(|_| "Invalid BLS secret key")?;
+		match <BlsAuthorities<T>>::get(id, auth_index) {
+			Some(registered) if registered == public => return Ok(()),
+			Some(_) => {
+				log::warn!(target:"thea","Another BLS public key is registered for auth index {:?}",auth_index);
+				return Ok(());
+			},
+			None => {},
+		}
+		let proof_of_possession = bls_primitives::sign_with_secret(secret, public.as_ref())
+			.map_err(|_| "Unable to sign with BLS secret key")?;
+		let payload_hash = sp_io::hashing::sha2_256((id, auth_index, public).encode().as_slice());
+		let signature =
+			sp_io::crypto::ecdsa_sign_prehashed(THEA, &signer.clone().into(), &payload_hash)
+				.ok_or("Expected signature to be returned")?;
+		if let Err(()) = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+			Call::<T>::submit_bls_public_key {
+				auth_index,
+				id,
+				public,
+				proof_of_possession,
+				signature: signature.into(),
+			}
+			.into(),
+		) {
+			log::error!(target:"thea","Error submitting BLS public key");
+		}
+		Ok(())
+	}
 }
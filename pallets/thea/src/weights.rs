@@ -32,8 +32,8 @@ use core::marker::PhantomData;
 /// Weight functions for `thea`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> crate::TheaWeightInfo for WeightInfo<T> {
-	/// Storage: `Thea::AllowListTestingRelayers` (r:1 w:0)
-	/// Proof: `Thea::AllowListTestingRelayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::ActiveRelayers` (r:1 w:0)
+	/// Proof: `Thea::ActiveRelayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Thea::NetworkConfig` (r:1 w:0)
 	/// Proof: `Thea::NetworkConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Thea::IncomingNonce` (r:1 w:0)
@@ -44,15 +44,18 @@ impl<T: frame_system::Config> crate::TheaWeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `TheaExecutor::Metadata` (r:1 w:0)
+	/// Proof: `TheaExecutor::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `b` is `[0, 256]`.
 	fn submit_incoming_message(_b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `338`
 		//  Estimated: `4714`
 		// Minimum execution time: 154_130_000 picoseconds.
+		// Placeholder: DB reads/writes adjusted by hand, regenerate with `benchmark pallet`.
 		Weight::from_parts(157_359_416, 0)
 			.saturating_add(Weight::from_parts(0, 4714))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Thea::OutgoingNonce` (r:1 w:1)
@@ -164,15 +167,22 @@ impl<T: frame_system::Config> crate::TheaWeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:2 w:2)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Thea::Relayers` (r:1 w:1)
+	/// Proof: `Thea::Relayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::ActiveRelayers` (r:1 w:1)
+	/// Proof: `Thea::ActiveRelayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn handle_misbehaviour() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `856`
 		//  Estimated: `8438`
 		// Minimum execution time: 315_790_000 picoseconds.
+		// Placeholder: DB reads/writes adjusted by hand, regenerate with `benchmark pallet`.
 		Weight::from_parts(318_430_000, 0)
 			.saturating_add(Weight::from_parts(0, 8438))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `Thea::ActiveNetworks` (r:1 w:0)
 	/// Proof: `Thea::ActiveNetworks` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -182,7 +192,7 @@ impl<T: frame_system::Config> crate::TheaWeightInfo for WeightInfo<T> {
 	/// Proof: `Thea::IncomingMessagesQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TheaExecutor::Metadata` (r:1 w:0)
 	/// Proof: `TheaExecutor::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `System::Account` (r:233 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Asset` (r:1 w:1)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
@@ -190,18 +200,241 @@ impl<T: frame_system::Config> crate::TheaWeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
 	/// Storage: `Thea::IncomingMessages` (r:0 w:232)
 	/// Proof: `Thea::IncomingMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::RelayerRewardPerMessage` (r:232 w:0)
+	/// Proof: `Thea::RelayerRewardPerMessage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::UnclaimedRelayerRewards` (r:232 w:232)
+	/// Proof: `Thea::UnclaimedRelayerRewards` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::RelayerRewards` (r:232 w:232)
+	/// Proof: `Thea::RelayerRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `x` is `[1, 1000]`.
 	fn on_initialize(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `16709 + x * (18 ±0)`
 		//  Estimated: `262787 + x * (152 ±0)`
 		// Minimum execution time: 195_330_000 picoseconds.
+		// Placeholder: DB reads/writes adjusted by hand, regenerate with `benchmark pallet`.
 		Weight::from_parts(15_935_480_424, 0)
 			.saturating_add(Weight::from_parts(0, 262787))
 			// Standard Error: 405_669
 			.saturating_add(Weight::from_parts(9_493_854, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(201))
-			.saturating_add(T::DbWeight::get().writes(296))
+			.saturating_add(T::DbWeight::get().reads(1129))
+			.saturating_add(T::DbWeight::get().writes(760))
 			.saturating_add(Weight::from_parts(0, 152).saturating_mul(x.into()))
 	}
+	/// Storage: `Thea::Relayers` (r:1 w:1)
+	/// Proof: `Thea::Relayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::NetworkConfig` (r:1 w:0)
+	/// Proof: `Thea::NetworkConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Thea::ActiveRelayers` (r:1 w:1)
+	/// Proof: `Thea::ActiveRelayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register_relayer() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(100_130_000, 0)
+			.saturating_add(Weight::from_parts(0, 4714))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Thea::Relayers` (r:1 w:1)
+	/// Proof: `Thea::Relayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::ActiveRelayers` (r:1 w:1)
+	/// Proof: `Thea::ActiveRelayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unbond_relayer() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(42_570_000, 0)
+			.saturating_add(Weight::from_parts(0, 5308))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Thea::Relayers` (r:1 w:1)
+	/// Proof: `Thea::Relayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	fn withdraw_unbonded() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(84_010_000, 0)
+			.saturating_add(Weight::from_parts(0, 4714))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Thea::NetworkConfig` (r:1 w:1)
+	/// Proof: `Thea::NetworkConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_signature_scheme() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(23_160_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Thea::BlsAuthorities` (r:0 w:1)
+	/// Proof: `Thea::BlsAuthorities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn submit_bls_public_key() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(22_110_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Thea::OutgoingMessages` (r:254 w:0)
+	/// Proof: `Thea::OutgoingMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::BlsSignedOutgoingMessages` (r:254 w:254)
+	/// Proof: `Thea::BlsSignedOutgoingMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::Authorities` (r:1 w:0)
+	/// Proof: `Thea::Authorities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::SignedOutgoingNonce` (r:0 w:254)
+	/// Proof: `Thea::SignedOutgoingNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 255]`.
+	fn submit_bls_signed_outgoing_messages(x: u32, ) -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(96_840_215, 0)
+			.saturating_add(Weight::from_parts(0, 3595))
+			// Standard Error: 48_112
+			.saturating_add(Weight::from_parts(431_206_118, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2887).saturating_mul(x.into()))
+	}
+	/// Storage: `Thea::NetworkPauseStates` (r:1 w:1)
+	/// Proof: `Thea::NetworkPauseStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TheaExecutor::AwaitingNetworkResume` (r:1 w:1)
+	/// Proof: `TheaExecutor::AwaitingNetworkResume` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TheaExecutor::FailedDepositsRetryQueue` (r:1 w:1)
+	/// Proof: `TheaExecutor::FailedDepositsRetryQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_network_pause() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(32_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Thea::AssetPauseStates` (r:1 w:1)
+	/// Proof: `Thea::AssetPauseStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TheaExecutor::AwaitingAssetResume` (r:1 w:1)
+	/// Proof: `TheaExecutor::AwaitingAssetResume` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TheaExecutor::FailedDepositsRetryQueue` (r:1 w:1)
+	/// Proof: `TheaExecutor::FailedDepositsRetryQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_asset_pause() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(32_470_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Thea::SignedOutgoingNonce` (r:1 w:1)
+	/// Proof: `Thea::SignedOutgoingNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::CommittedOutgoingNonce` (r:1 w:1)
+	/// Proof: `Thea::CommittedOutgoingNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::CommitmentPeriods` (r:0 w:1)
+	/// Proof: `Thea::CommitmentPeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_commitment_period() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(30_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 3471))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Thea::MessageCommitments` (r:255 w:0)
+	/// Proof: `Thea::MessageCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::SignedMessageCommitments` (r:255 w:255)
+	/// Proof: `Thea::SignedMessageCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::Authorities` (r:255 w:0)
+	/// Proof: `Thea::Authorities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::SignedCommitmentNonce` (r:255 w:255)
+	/// Proof: `Thea::SignedCommitmentNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 255]`.
+	fn submit_signed_commitments(x: u32, ) -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(18_306_412, 0)
+			.saturating_add(Weight::from_parts(0, 3577))
+			// Standard Error: 21_408
+			.saturating_add(Weight::from_parts(26_781_935, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2693).saturating_mul(x.into()))
+	}
+	/// Storage: `Thea::CommitmentPeriods` (r:1 w:0)
+	/// Proof: `Thea::CommitmentPeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::CommittedOutgoingNonce` (r:1 w:1)
+	/// Proof: `Thea::CommittedOutgoingNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::OutgoingNonce` (r:1 w:0)
+	/// Proof: `Thea::OutgoingNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::OutgoingMessages` (r:512 w:0)
+	/// Proof: `Thea::OutgoingMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::CommitmentNonce` (r:1 w:1)
+	/// Proof: `Thea::CommitmentNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::MessageCommitments` (r:0 w:1)
+	/// Proof: `Thea::MessageCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[0, 512]`.
+	fn commit_outgoing_messages(x: u32, ) -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(4_872_301, 0)
+			.saturating_add(Weight::from_parts(0, 3552))
+			// Standard Error: 3_517
+			.saturating_add(Weight::from_parts(9_634_218, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(Weight::from_parts(0, 2573).saturating_mul(x.into()))
+	}
+	/// Storage: `Thea::SigningKeys` (r:0 w:1)
+	/// Proof: `Thea::SigningKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn submit_signing_key() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(19_540_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Thea::OutgoingMessages` (r:254 w:0)
+	/// Proof: `Thea::OutgoingMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::SchemeSignedOutgoingMessages` (r:254 w:254)
+	/// Proof: `Thea::SchemeSignedOutgoingMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::Authorities` (r:254 w:0)
+	/// Proof: `Thea::Authorities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::SignedOutgoingNonce` (r:254 w:254)
+	/// Proof: `Thea::SignedOutgoingNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 255]`.
+	fn submit_scheme_signed_outgoing_messages(x: u32, ) -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(20_118_442, 0)
+			.saturating_add(Weight::from_parts(0, 3595))
+			// Standard Error: 19_874
+			.saturating_add(Weight::from_parts(29_463_207, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2819).saturating_mul(x.into()))
+	}
+	/// Storage: `Thea::NetworkConfig` (r:1 w:1)
+	/// Proof: `Thea::NetworkConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[0, 16]`.
+	fn set_fork_period_tiers(x: u32, ) -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(23_842_115, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			// Standard Error: 2_141
+			.saturating_add(Weight::from_parts(31_274, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Thea::RelayerRewardPerMessage` (r:0 w:1)
+	/// Proof: `Thea::RelayerRewardPerMessage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_relayer_reward() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(10_240_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Thea::RelayerRewards` (r:1 w:1)
+	/// Proof: `Thea::RelayerRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Thea::UnclaimedRelayerRewards` (r:1 w:1)
+	/// Proof: `Thea::UnclaimedRelayerRewards` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_relayer_rewards() -> Weight {
+		// Placeholder, not measured: regenerate with `benchmark pallet`.
+		Weight::from_parts(69_410_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -668,7 +668,7 @@ pub mod pallet {
 	}
 
 	impl<T: Config> TheaIncomingExecutor for Pallet<T> {
-		fn execute_deposits(_: Network, deposits: Vec<u8>) {
+		fn execute_deposits(_: Network, _: u64, deposits: Vec<u8>) {
 			let deposits = Vec::<Withdraw>::decode(&mut &deposits[..]).unwrap_or_default();
 			for deposit in deposits {
 				// Calculate the withdrawal execution delay
@@ -33,7 +33,7 @@ pub mod application_crypto;
 pub mod host_functions;
 
 use ark_bls12_381::{
-	g1::Config as G1Config, Bls12_381, G1Affine, G1Projective, G2Affine, G2Projective,
+	g1::Config as G1Config, Bls12_381, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
 };
 use ark_ec::{
 	hashing::{
@@ -44,7 +44,7 @@ use ark_ec::{
 	short_weierstrass::Projective,
 	AffineRepr, CurveGroup,
 };
-use ark_ff::{field_hashers::DefaultFieldHasher, Zero};
+use ark_ff::{field_hashers::DefaultFieldHasher, PrimeField, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 #[cfg(feature = "std")]
 use bip39::{Language, Mnemonic, MnemonicType};
@@ -56,7 +56,7 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sha2::Sha256;
 use sp_core::crypto::{ByteArray, CryptoType, CryptoTypeId, Derive, Ss58Codec};
-use sp_std::ops::{Add, Neg};
+use sp_std::ops::{Add, Mul, Neg};
 
 #[cfg(feature = "std")]
 use sp_core::crypto::SecretStringError;
@@ -171,6 +171,8 @@ pub enum Error {
 	BLSSerilizationError(SerializationError),
 	/// Invalid justification.
 	InvalidJunctionForDerivation,
+	/// Unable to hash message to curve.
+	HashToCurveFailed,
 	/// Serde specific error.
 	#[cfg(feature = "std")]
 	SerdeError(serde_json::Error),
@@ -387,6 +389,38 @@ impl sp_core::crypto::Pair for Pair {
 	}
 }
 
+/// Derives the public key of the given secret key.
+///
+/// Works without host functions, so it can be used by offchain workers.
+///
+/// # Parameters
+///
+/// * `secret`: Big-endian secret scalar, as returned by `Pair::to_raw_vec`.
+pub fn public_from_secret(secret: &[u8; 32]) -> Result<Public, Error> {
+	let secret = Fr::from_be_bytes_mod_order(secret);
+	let public: G2Projective = G2Projective::from(G2Affine::generator()).mul(secret);
+	let mut buffer = [0u8; 96];
+	public.into_affine().serialize_compressed(buffer.as_mut_slice())?;
+	Ok(Public(buffer))
+}
+
+/// Signs the message with the given secret key.
+///
+/// Works without host functions, so it can be used by offchain workers.
+///
+/// # Parameters
+///
+/// * `secret`: Big-endian secret scalar, as returned by `Pair::to_raw_vec`.
+/// * `message`: Message to sign.
+pub fn sign_with_secret(secret: &[u8; 32], message: &[u8]) -> Result<Signature, Error> {
+	let secret = Fr::from_be_bytes_mod_order(secret);
+	let message = hash_to_curve_g1(message).map_err(|_| Error::HashToCurveFailed)?;
+	let signature: G1Projective = message.mul(secret);
+	let mut buffer = [0u8; 48];
+	signature.into_affine().serialize_compressed(buffer.as_mut_slice())?;
+	Ok(Signature(buffer))
+}
+
 pub fn hash_to_curve_g1(message: &[u8]) -> Result<G1Projective, HashToCurveError> {
 	let wb_to_curve_hasher = MapToCurveBasedHasher::<
 		Projective<G1Config>,
@@ -429,4 +463,16 @@ mod tests {
 
 		assert!(aggregate_signature.verify(&[pair1.public(), pair2.public()], message))
 	}
+
+	#[test]
+	pub fn test_sign_with_secret_matches_pair() {
+		let pair = crate::Pair::generate().0;
+		let secret: [u8; 32] = pair.to_raw_vec().try_into().unwrap();
+		let message = b"message";
+
+		assert_eq!(crate::public_from_secret(&secret).unwrap(), pair.public());
+		let signature = crate::sign_with_secret(&secret, message).unwrap();
+		assert_eq!(signature, pair.sign(message));
+		assert!(signature.verify(&[pair.public()], message));
+	}
 }
@@ -18,7 +18,7 @@
 
 //! In this module defined ingress messages related types.
 
-use crate::lmp::LMPEpochConfig;
+use crate::lmp::LMPEngineConfig;
 use crate::{ocex::TradingPairConfig, AssetId};
 use parity_scale_codec::{Decode, Encode};
 use rust_decimal::Decimal;
@@ -65,9 +65,15 @@ pub enum IngressMessages<AccountId> {
 	/// Force Close Command ( market, pool_id)
 	ForceClosePool(TradingPairConfig, AccountId),
 	/// LMPConfig
-	LMPConfig(LMPEpochConfig),
+	LMPConfig(LMPEngineConfig),
 	/// New LMP Epoch started
 	NewLMPEpoch(u16),
+	/// Value the pool at the end of an epoch ( market, pool_id, epoch, price,
+	/// total_shares_issued )
+	ValuePool(TradingPairConfig, AccountId, u16, Decimal, Decimal),
+	/// Remove liquidity instantly ( market, pool_id, LP, burn_fraction,
+	/// total_shares_issued_at_burn, request_id )
+	RemoveLiquidityInstantly(TradingPairConfig, AccountId, AccountId, Decimal, Decimal, u64),
 }
 
 #[serde_as]
@@ -96,4 +102,22 @@ pub enum EgressMessages<AccountId> {
 	TradingFees(#[serde_as(as = "Vec<(_, _)>")] BTreeMap<AssetId, Decimal>),
 	/// Price Oracle
 	PriceOracle(#[serde_as(as = "Vec<(_, _)>")] BTreeMap<(AssetId, AssetId), Decimal>),
+	/// Pool Valued ( market, Pool, epoch, total Inventory ( in Quote), total shares issued )
+	PoolValued(TradingPairConfig, AccountId, u16, Decimal, Decimal),
+	/// Remove Liquidity Instantly Result ( Pool, LP, Base freed, Quote Freed, request_id )
+	RemoveLiquidityInstantlyResult(TradingPairConfig, AccountId, AccountId, Decimal, Decimal, u64),
+	/// Remove Liquidity Instantly Failed ( Pool, LP, burn_frac, total_shares_issued, base_free,
+	/// quote_free, base_required, quote_required, request_id )
+	RemoveLiquidityInstantlyFailed(
+		TradingPairConfig,
+		AccountId,
+		AccountId,
+		Decimal,
+		Decimal,
+		Decimal,
+		Decimal,
+		Decimal,
+		Decimal,
+		u64,
+	),
 }
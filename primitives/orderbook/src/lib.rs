@@ -230,6 +230,17 @@ pub trait LiquidityMining<AccountId, Balance> {
 		total: Balance,
 	);
 
+	/// Adds an ingress message to withdraw the given shares immediately, the result is reported
+	/// back with the given request id
+	fn remove_liquidity_instantly(
+		market: TradingPair,
+		pool: AccountId,
+		lp: AccountId,
+		given: Balance,
+		total: Balance,
+		request_id: u64,
+	);
+
 	/// Adds an ingress message to force close all open orders from this main account and initiate
 	/// complete withdrawal
 	fn force_close_pool(market: TradingPair, main: AccountId);
@@ -240,4 +251,21 @@ pub trait LiquidityMining<AccountId, Balance> {
 		epoch: u16,
 		market: TradingPair,
 	) -> Result<Balance, sp_runtime::DispatchError>;
+
+	/// Adds an ingress message requesting the value of the pool at the end of the epoch, which
+	/// is returned as an egress message
+	fn value_pool(
+		market: TradingPair,
+		pool: AccountId,
+		epoch: u16,
+		price: Decimal,
+		total_shares_issued: Decimal,
+	);
+
+	/// Returns the base and quote balances of the pool in the offchain state
+	fn get_pool_balances(pool: AccountId, market: TradingPair) -> (Decimal, Decimal);
+
+	/// Returns the total eligible rewards of the pool for given epoch and market, and if they are
+	/// already claimed
+	fn get_pool_rewards(pool: AccountId, epoch: u16, market: TradingPair) -> (Balance, bool);
 }
@@ -16,8 +16,9 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::TradingPair;
+use crate::types::{OrderSide, TradingPair};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use polkadex_primitives::{AssetId, BlockNumber};
 use rust_decimal::{
 	prelude::{One, Zero},
 	Decimal,
@@ -28,6 +29,12 @@ use serde_with::serde_as;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::vec::Vec;
 
+/// Maximum number of upcoming epochs for which LMP configs can be scheduled
+pub const MAX_SCHEDULED_LMP_EPOCHS: u16 = 12;
+
+/// Divisor applied to the deeper side when scoring one-sided liquidity
+pub const LMP_SINGLE_SIDED_DIVISOR: u8 = 3;
+
 /// LMP Epoch config
 #[derive(
 	Decode, Encode, TypeInfo, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Default,
@@ -49,6 +56,84 @@ pub struct LMPOneMinuteReport<AccountId: Ord> {
 	pub scores: BTreeMap<AccountId, Decimal>,
 }
 
+/// Resting liquidity of a main account at one price level
+#[derive(Decode, Encode, TypeInfo, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LMPDepthLevel {
+	pub side: OrderSide,
+	pub price: Decimal,
+	// Quantity in base asset
+	pub qty: Decimal,
+}
+
+/// Order book depth of a market sampled by the engine once every minute
+#[serde_as]
+#[derive(Decode, Encode, TypeInfo, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LMPDepthSample<AccountId: Ord> {
+	// Price levels of every main account with open orders in the market
+	#[serde_as(as = "Vec<(_, _)>")]
+	pub levels: BTreeMap<AccountId, Vec<LMPDepthLevel>>,
+}
+
+impl<AccountId: Ord + Clone> LMPDepthSample<AccountId> {
+	/// Returns the midpoint of best bid and ask, None if either side of the book is empty
+	pub fn mid_price(&self) -> Option<Decimal> {
+		let levels = self.levels.values().flatten();
+		let best_bid =
+			levels.clone().filter(|l| l.side == OrderSide::Bid).map(|l| l.price).max()?;
+		let best_ask = levels.filter(|l| l.side == OrderSide::Ask).map(|l| l.price).min()?;
+		best_bid.checked_add(best_ask)?.checked_div(Decimal::TWO)
+	}
+
+	/// Computes the Q-score of every main account from the sampled depth
+	///
+	/// A level counts only if its spread ( in % ) is within `max_spread` and its depth
+	/// ( in quote ) is at least `min_depth`, and it contributes
+	/// depth * ((max_spread - spread)/max_spread)^2 to its side. The Q-score is the
+	/// shallower side, or the deeper side divided by `LMP_SINGLE_SIDED_DIVISOR` if that
+	/// is higher. Accounts with zero score are omitted.
+	pub fn compute_q_scores(
+		&self,
+		max_spread: Decimal,
+		min_depth: Decimal,
+	) -> BTreeMap<AccountId, Decimal> {
+		let mut scores = BTreeMap::new();
+		let mid_price = match self.mid_price() {
+			Some(mid_price) if !mid_price.is_zero() && !max_spread.is_zero() => mid_price,
+			_ => return scores,
+		};
+		let divisor = Decimal::from(LMP_SINGLE_SIDED_DIVISOR);
+		for (main, levels) in &self.levels {
+			let (mut q_bid, mut q_ask) = (Decimal::zero(), Decimal::zero());
+			for level in levels {
+				let spread = mid_price
+					.saturating_sub(level.price)
+					.abs()
+					.checked_div(mid_price)
+					.unwrap_or_default()
+					.saturating_mul(Decimal::ONE_HUNDRED);
+				let depth = level.price.saturating_mul(level.qty);
+				if spread > max_spread || depth < min_depth {
+					continue;
+				}
+				let factor =
+					max_spread.saturating_sub(spread).checked_div(max_spread).unwrap_or_default();
+				let weighted_depth = depth.saturating_mul(factor).saturating_mul(factor);
+				match level.side {
+					OrderSide::Bid => q_bid = q_bid.saturating_add(weighted_depth),
+					OrderSide::Ask => q_ask = q_ask.saturating_add(weighted_depth),
+				}
+			}
+			let two_sided = q_bid.min(q_ask);
+			let one_sided = q_bid.max(q_ask).checked_div(divisor).unwrap_or_default();
+			let score = two_sided.max(one_sided);
+			if !score.is_zero() {
+				scores.insert(main.clone(), score);
+			}
+		}
+		scores
+	}
+}
+
 #[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo, Serialize, Deserialize)]
 pub struct LMPMarketConfigWrapper {
 	pub trading_pair: TradingPair,
@@ -57,6 +142,25 @@ pub struct LMPMarketConfigWrapper {
 	pub min_maker_volume: u128,
 	pub max_spread: u128,
 	pub min_depth: u128,
+	/// Ids of the funded reward streams paid to market makers of this market
+	pub reward_streams: Vec<u32>,
+}
+
+/// Extra reward stream funded by a sponsor for a market
+#[derive(
+	Decode, Encode, TypeInfo, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub struct LMPRewardStream<AccountId> {
+	/// Id assigned when the stream is funded
+	pub id: u32,
+	/// Market whose market makers are rewarded
+	pub market: TradingPair,
+	/// Asset in which rewards are paid
+	pub asset: AssetId,
+	/// Rewards given in the epoch to market makers of the market
+	pub amount: Decimal,
+	/// Account that funded this stream, unclaimed rewards are refunded here
+	pub sponsor: AccountId,
 }
 
 /// LMP Configuration for a market
@@ -99,7 +203,7 @@ pub struct LMPMarketConfig {
 #[derive(
 	Decode, Encode, TypeInfo, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize,
 )]
-pub struct LMPEpochConfig {
+pub struct LMPEpochConfig<AccountId> {
 	/// Total rewards given in this epoch for market making
 	pub total_liquidity_mining_rewards: Decimal,
 	/// Total rewards given in this epoch for trading
@@ -111,9 +215,84 @@ pub struct LMPEpochConfig {
 	pub max_accounts_rewarded: u16,
 	/// Claim safety period
 	pub claim_safety_period: u32,
+	/// Vesting of the claimed rewards, paid at once if not set
+	pub vesting: Option<LMPVestingPolicy>,
+	/// Sponsored rewards for market makers of each market, paid on top of the native rewards
+	#[serde_as(as = "Vec<(_, _)>")]
+	pub reward_streams: BTreeMap<TradingPair, Vec<LMPRewardStream<AccountId>>>,
+}
+
+/// LMP Configuration of an epoch as sent to the orderbook engine
+#[serde_as]
+#[derive(
+	Decode, Encode, TypeInfo, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub struct LMPEngineConfig {
+	/// Total rewards given in this epoch for market making
+	pub total_liquidity_mining_rewards: Decimal,
+	/// Total rewards given in this epoch for trading
+	pub total_trading_rewards: Decimal,
+	/// Market Configurations
+	#[serde_as(as = "Vec<(_, _)>")]
+	pub config: BTreeMap<TradingPair, LMPMarketConfig>,
+	/// Max number of accounts rewarded
+	pub max_accounts_rewarded: u16,
+	/// Claim safety period
+	pub claim_safety_period: u32,
+}
+
+impl<AccountId> From<&LMPEpochConfig<AccountId>> for LMPEngineConfig {
+	fn from(config: &LMPEpochConfig<AccountId>) -> Self {
+		Self {
+			total_liquidity_mining_rewards: config.total_liquidity_mining_rewards,
+			total_trading_rewards: config.total_trading_rewards,
+			config: config.config.clone(),
+			max_accounts_rewarded: config.max_accounts_rewarded,
+			claim_safety_period: config.claim_safety_period,
+		}
+	}
+}
+
+/// Vesting policy for the LMP rewards of an epoch
+#[derive(
+	Decode,
+	Encode,
+	TypeInfo,
+	Copy,
+	Clone,
+	Debug,
+	Eq,
+	PartialEq,
+	PartialOrd,
+	Ord,
+	Serialize,
+	Deserialize,
+)]
+pub struct LMPVestingPolicy {
+	/// Blocks after the claim before the vested part starts unlocking
+	pub cliff: BlockNumber,
+	/// Blocks over which the vested part unlocks linearly
+	pub linear_period: BlockNumber,
+	/// Fraction of the rewards paid immediately on claim
+	pub immediate_fraction: Decimal,
 }
 
-impl Default for LMPEpochConfig {
+impl LMPVestingPolicy {
+	/// Checks the integrity of the policy
+	pub fn verify(&self) -> bool {
+		self.linear_period > 0
+			&& self.immediate_fraction >= Decimal::zero()
+			&& self.immediate_fraction <= Decimal::one()
+	}
+
+	/// Splits the rewards into the immediate and the vested portions
+	pub fn split(&self, rewards: Decimal) -> (Decimal, Decimal) {
+		let immediate = rewards.saturating_mul(self.immediate_fraction);
+		(immediate, rewards.saturating_sub(immediate))
+	}
+}
+
+impl<AccountId> Default for LMPEpochConfig<AccountId> {
 	fn default() -> Self {
 		Self {
 			total_liquidity_mining_rewards: Default::default(),
@@ -121,11 +300,13 @@ impl Default for LMPEpochConfig {
 			config: Default::default(),
 			max_accounts_rewarded: 20,
 			claim_safety_period: 50400,
+			vesting: None,
+			reward_streams: Default::default(),
 		}
 	}
 }
 
-impl LMPEpochConfig {
+impl<AccountId> LMPEpochConfig<AccountId> {
 	/// Checks the integrity of current config
 	pub fn verify(&self) -> bool {
 		// Check if market weightage adds upto 1.0
@@ -135,10 +316,140 @@ impl LMPEpochConfig {
 			total_percent = total_percent.saturating_add(config.weightage);
 		}
 
+		// Sponsored streams can only reward markets of this epoch
+		if !self.reward_streams.keys().all(|market| self.config.contains_key(market)) {
+			return false;
+		}
+
 		if total_percent != Decimal::one() {
 			return false;
 		}
 
+		if let Some(vesting) = self.vesting {
+			return vesting.verify();
+		}
+
 		true
 	}
+
+	/// Removes all sponsored reward streams, they are funded for a single epoch only
+	pub fn clear_reward_streams(&mut self) {
+		self.reward_streams.clear();
+	}
+
+	/// Applies the given diff and returns the sponsored reward streams that were replaced or
+	/// removed
+	pub fn apply(
+		&mut self,
+		diff: LMPEpochConfigDiff<AccountId>,
+	) -> Vec<LMPRewardStream<AccountId>> {
+		let mut released = Vec::new();
+		if let Some(total_liquidity_mining_rewards) = diff.total_liquidity_mining_rewards {
+			self.total_liquidity_mining_rewards = total_liquidity_mining_rewards;
+		}
+		if let Some(total_trading_rewards) = diff.total_trading_rewards {
+			self.total_trading_rewards = total_trading_rewards;
+		}
+		for market in diff.remove_markets {
+			self.config.remove(&market);
+			released.extend(self.reward_streams.remove(&market).unwrap_or_default());
+		}
+		for (market, market_config) in diff.add_markets {
+			self.config.insert(market, market_config);
+			released.extend(self.reward_streams.remove(&market).unwrap_or_default());
+		}
+		for (market, streams) in diff.reward_streams {
+			if !streams.is_empty() {
+				self.reward_streams.insert(market, streams);
+			}
+		}
+		if let Some(max_accounts_rewarded) = diff.max_accounts_rewarded {
+			self.max_accounts_rewarded = max_accounts_rewarded;
+		}
+		if let Some(claim_safety_period) = diff.claim_safety_period {
+			self.claim_safety_period = claim_safety_period;
+		}
+		released
+	}
+}
+
+/// Changes to the LMP configuration scheduled for a future epoch
+#[serde_as]
+#[derive(Decode, Encode, TypeInfo, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LMPEpochConfigDiff<AccountId> {
+	/// New total rewards for market making
+	pub total_liquidity_mining_rewards: Option<Decimal>,
+	/// New total rewards for trading
+	pub total_trading_rewards: Option<Decimal>,
+	/// Markets added or updated in that epoch
+	#[serde_as(as = "Vec<(_, _)>")]
+	pub add_markets: BTreeMap<TradingPair, LMPMarketConfig>,
+	/// Sponsored reward streams of the added or updated markets
+	#[serde_as(as = "Vec<(_, _)>")]
+	pub reward_streams: BTreeMap<TradingPair, Vec<LMPRewardStream<AccountId>>>,
+	/// Markets removed in that epoch
+	pub remove_markets: Vec<TradingPair>,
+	/// New max number of accounts rewarded
+	pub max_accounts_rewarded: Option<u16>,
+	/// New claim safety period
+	pub claim_safety_period: Option<u32>,
+}
+
+impl<AccountId> Default for LMPEpochConfigDiff<AccountId> {
+	fn default() -> Self {
+		Self {
+			total_liquidity_mining_rewards: None,
+			total_trading_rewards: None,
+			add_markets: Default::default(),
+			reward_streams: Default::default(),
+			remove_markets: Default::default(),
+			max_accounts_rewarded: None,
+			claim_safety_period: None,
+		}
+	}
+}
+
+/// Length of LMP epochs in blocks
+#[derive(
+	Decode,
+	Encode,
+	TypeInfo,
+	Copy,
+	Clone,
+	Debug,
+	Eq,
+	PartialEq,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct LMPEpochDuration {
+	/// Number of blocks in an epoch
+	pub epoch_length: BlockNumber,
+	/// Number of blocks before the end of the epoch after which LMP withdrawals are not accepted
+	pub withdrawal_stop_offset: BlockNumber,
+}
+
+impl Default for LMPEpochDuration {
+	fn default() -> Self {
+		Self { epoch_length: 201600, withdrawal_stop_offset: 7200 }
+	}
+}
+
+impl LMPEpochDuration {
+	/// Checks the integrity of the duration
+	pub fn verify(&self) -> bool {
+		self.epoch_length > 0 && self.withdrawal_stop_offset < self.epoch_length
+	}
+}
+
+/// Upcoming LMP epoch as currently scheduled
+#[derive(Decode, Encode, TypeInfo, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LMPScheduledEpoch<AccountId> {
+	/// Epoch number
+	pub epoch: u16,
+	/// Block at which the epoch is expected to start
+	pub start_block: BlockNumber,
+	/// Configuration that will be used in that epoch
+	pub config: LMPEpochConfig<AccountId>,
 }
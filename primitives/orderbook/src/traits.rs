@@ -18,7 +18,9 @@
 
 use crate::types::TradingPair;
 use frame_support::dispatch::DispatchResult;
+use polkadex_primitives::{Balance, BlockNumber};
 use rust_decimal::Decimal;
+use sp_runtime::DispatchError;
 
 pub trait LiquidityMiningCrowdSourcePallet<AccountId> {
 	fn new_epoch(n: u16);
@@ -52,6 +54,28 @@ pub trait LiquidityMiningCrowdSourcePallet<AccountId> {
 		quote_required: Decimal,
 	) -> DispatchResult;
 
+	fn remove_liquidity_instantly_success(
+		market: TradingPair,
+		pool: &AccountId,
+		lp: &AccountId,
+		base_free: Decimal,
+		quote_free: Decimal,
+		request_id: u64,
+	) -> DispatchResult;
+
+	#[allow(clippy::too_many_arguments)]
+	fn remove_liquidity_instantly_failed(
+		market: TradingPair,
+		pool: &AccountId,
+		lp: &AccountId,
+		burn_frac: Decimal,
+		base_free: Decimal,
+		quote_free: Decimal,
+		base_required: Decimal,
+		quote_required: Decimal,
+		request_id: u64,
+	) -> DispatchResult;
+
 	fn pool_force_close_success(
 		market: TradingPair,
 		pool: &AccountId,
@@ -59,6 +83,14 @@ pub trait LiquidityMiningCrowdSourcePallet<AccountId> {
 		quote_freed: Decimal,
 	) -> DispatchResult;
 
+	fn pool_valued(
+		market: TradingPair,
+		pool: &AccountId,
+		epoch: u16,
+		total_inventory_in_quote: Decimal,
+		total_shares: Decimal,
+	) -> DispatchResult;
+
 	fn stop_accepting_lmp_withdrawals(epoch: u16);
 }
 
@@ -100,6 +132,31 @@ impl<AccountId> LiquidityMiningCrowdSourcePallet<AccountId> for () {
 		Ok(())
 	}
 
+	fn remove_liquidity_instantly_success(
+		_market: TradingPair,
+		_pool: &AccountId,
+		_lp: &AccountId,
+		_base_free: Decimal,
+		_quote_free: Decimal,
+		_request_id: u64,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn remove_liquidity_instantly_failed(
+		_market: TradingPair,
+		_pool: &AccountId,
+		_lp: &AccountId,
+		_burn_frac: Decimal,
+		_base_free: Decimal,
+		_quote_free: Decimal,
+		_base_required: Decimal,
+		_quote_required: Decimal,
+		_request_id: u64,
+	) -> DispatchResult {
+		Ok(())
+	}
+
 	fn pool_force_close_success(
 		_market: TradingPair,
 		_pool: &AccountId,
@@ -109,5 +166,42 @@ impl<AccountId> LiquidityMiningCrowdSourcePallet<AccountId> for () {
 		Ok(())
 	}
 
+	fn pool_valued(
+		_market: TradingPair,
+		_pool: &AccountId,
+		_epoch: u16,
+		_total_inventory_in_quote: Decimal,
+		_total_shares: Decimal,
+	) -> DispatchResult {
+		Ok(())
+	}
+
 	fn stop_accepting_lmp_withdrawals(_epoch: u16) {}
 }
+
+/// Locks the vested part of claimed LMP rewards
+pub trait LMPRewardsVesting<AccountId> {
+	/// Transfers `per_period * period_count` native tokens from `from` to `to`, unlocking
+	/// `per_period` every `period` blocks after `start`
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		start: BlockNumber,
+		period: BlockNumber,
+		period_count: u32,
+		per_period: Balance,
+	) -> DispatchResult;
+}
+
+impl<AccountId> LMPRewardsVesting<AccountId> for () {
+	fn vested_transfer(
+		_from: &AccountId,
+		_to: &AccountId,
+		_start: BlockNumber,
+		_period: BlockNumber,
+		_period_count: u32,
+		_per_period: Balance,
+	) -> DispatchResult {
+		Err(DispatchError::Other("LMP rewards vesting is not supported"))
+	}
+}
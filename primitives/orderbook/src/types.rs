@@ -250,12 +250,21 @@ pub enum UserActions<AccountId: Ord + Codec + Clone + TypeInfo> {
 	Reset,
 	/// Withdraw operation requested.( request, stid)
 	WithdrawV1(WithdrawalRequest<AccountId>, u64),
-	/// One min LMP Report ( market, epoch, index, total_score, Q_scores)
+	/// One min LMP Report ( market, total_score, Q_scores ), kept to decode old batches. Its
+	/// scores are ignored since they can not be verified without a depth sample
 	OneMinLMPReport(
 		TradingPair,
 		Decimal,
 		#[serde_as(as = "Vec<(_, _)>")] BTreeMap<AccountId, Decimal>,
 	),
+	/// One min LMP Report ( market, total_score, Q_scores, depth sample the scores are computed
+	/// from )
+	OneMinLMPReportV1(
+		TradingPair,
+		Decimal,
+		#[serde_as(as = "Vec<(_, _)>")] BTreeMap<AccountId, Decimal>,
+		LMPDepthSample<AccountId>,
+	),
 }
 
 /// Defines withdraw request DTO.
@@ -304,6 +313,7 @@ impl<AccountId: Codec + Clone + TypeInfo> WithdrawalRequest<AccountId> {
 	}
 }
 use crate::ingress::{EgressMessages, IngressMessages};
+use crate::lmp::LMPDepthSample;
 use crate::ocex::TradingPairConfig;
 #[cfg(not(feature = "std"))]
 use core::{
@@ -1006,4 +1016,16 @@ mod tests {
 
 		serde_json::to_vec(&action).unwrap();
 	}
+
+	#[test]
+	pub fn test_decode_one_min_lmp_report_without_depth_sample() {
+		use crate::types::TradingPair;
+		use parity_scale_codec::{Decode, Encode};
+		let market = TradingPair { base: AssetId::Polkadex, quote: AssetId::Asset(1) };
+		let scores = BTreeMap::from([(AccountId::new([1; 32]), Decimal::ONE)]);
+		// Variant index of `OneMinLMPReport` before depth samples were reported
+		let encoded = (5u8, market, Decimal::ONE, scores.clone()).encode();
+		let action = UserActions::<AccountId>::decode(&mut &encoded[..]).unwrap();
+		assert_eq!(action, UserActions::OneMinLMPReport(market, Decimal::ONE, scores));
+	}
 }
@@ -17,17 +17,86 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::pallet_prelude::TypeInfo;
-use frame_support::{Deserialize, Serialize};
+use frame_support::pallet_prelude::{ConstU32, TypeInfo};
+use frame_support::{BoundedVec, Deserialize, Serialize};
 use sp_std::collections::btree_map::BTreeMap;
 
+/// Maximum number of accounts the trading fees can be split between.
+pub const MAX_FEE_RECIPIENTS: u32 = 8;
+
+/// Weighted list of accounts receiving the distributed trading fees.
+pub type FeeRecipients<AccountId> =
+	BoundedVec<FeeRecipient<AccountId>, ConstU32<MAX_FEE_RECIPIENTS>>;
+
+/// An account receiving a share of the distributed trading fees.
+#[derive(
+	Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Serialize, Deserialize,
+)]
+pub struct FeeRecipient<AccountId> {
+	/// Recipient account.
+	pub account: AccountId,
+	/// Share of the distributed fees in percent.
+	pub share: u8,
+}
+
+/// Defines how the non-native trading fees collected in the pot account are converted to PDEX
+/// at the end of each fee distribution cycle.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	Debug,
+	PartialEq,
+	Default,
+	Serialize,
+	Deserialize,
+)]
+pub enum FeeDistributionMode {
+	/// Fees are auctioned and the highest PDEX bid wins.
+	#[default]
+	Auction,
+	/// Fees are swapped to PDEX through asset conversion. `max_slippage` is the percentage the
+	/// swap output is allowed to deviate from the price reported by the OCEX price oracle.
+	BuyBack { max_slippage: u8 },
+}
+
+impl FeeDistributionMode {
+	/// Checks that the buy-back slippage is a valid percentage.
+	pub fn verify(&self) -> bool {
+		match self {
+			FeeDistributionMode::Auction => true,
+			FeeDistributionMode::BuyBack { max_slippage } => *max_slippage <= 100,
+		}
+	}
+}
+
 #[derive(
 	Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Serialize, Deserialize,
 )]
 pub struct FeeDistribution<AccountId, BlockNo> {
-	pub recipient_address: AccountId,
+	pub recipients: FeeRecipients<AccountId>,
 	pub auction_duration: BlockNo,
 	pub burn_ration: u8,
+	pub mode: FeeDistributionMode,
+}
+
+impl<AccountId, BlockNo> FeeDistribution<AccountId, BlockNo> {
+	/// Checks that the recipient shares add up to 100 percent and the burn ratio and the
+	/// buy-back slippage are valid percentages.
+	pub fn verify(&self) -> bool {
+		Self::verify_recipients(&self.recipients) && self.burn_ration <= 100 && self.mode.verify()
+	}
+
+	/// Checks that the recipient shares add up to 100 percent.
+	pub fn verify_recipients(recipients: &FeeRecipients<AccountId>) -> bool {
+		let total_share = recipients
+			.iter()
+			.fold(0u32, |total, recipient| total.saturating_add(recipient.share as u32));
+		total_share == 100
+	}
 }
 
 #[derive(Clone, Encode, Decode, TypeInfo, Debug, PartialEq)]
@@ -0,0 +1,599 @@
+// This file is part of Polkadex.
+//
+// Copyright (c) 2023 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Ethereum ABI encoding of Thea messages sent to EVM networks.
+//!
+//! Messages are encoded as `abi.encode(uint64 blockNo, uint64 nonce, uint8 network,
+//! uint8 payloadType, bytes data)` and withdrawals as `abi.encode(Withdraw[])` with
+//!
+//! ```solidity
+//! struct Withdraw {
+//!     bytes id;
+//!     uint128 assetId;
+//!     uint128 amount;
+//!     bytes destination;
+//!     bool isBlocked;
+//!     bytes extra;
+//! }
+//! ```
+//!
+//! Validators sign the EIP-191 digest of the keccak256 hash of the encoded message, which is
+//! what `ECDSA.recover(MessageHashUtils.toEthSignedMessageHash(keccak256(encoded)), sig)`
+//! expects on the contract side.
+//!
+//! Message commitments are encoded as `abi.encode(uint64 blockNo, uint64 nonce, uint8 network,
+//! uint64 firstNonce, uint64 lastNonce, bytes32 root)` and signed the same way, their leaves are
+//! the keccak256 hashes of the encoded messages.
+
+use crate::types::{Message, MessageCommitment, PayloadType, Withdraw};
+use sp_std::{boxed::Box, vec, vec::Vec};
+
+/// Size of an ABI word in bytes
+const WORD: usize = 32;
+
+/// Prefix of EIP-191 `personal_sign` digests of 32 byte hashes
+pub const ETH_SIGNED_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+
+/// Errors returned by the ABI decoder
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AbiError {
+	/// Data ends before the value
+	UnexpectedEnd,
+	/// Offset or length does not fit in the data
+	InvalidOffset,
+	/// Value is out of range of its type or has non-zero padding
+	InvalidValue,
+	/// Unknown payload type
+	InvalidPayloadType,
+}
+
+/// ABI value
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Token {
+	/// Unsigned integer up to 128 bits
+	Uint(u128),
+	/// Boolean
+	Bool(bool),
+	/// Fixed size `bytes32`
+	FixedBytes([u8; WORD]),
+	/// Dynamic bytes
+	Bytes(Vec<u8>),
+	/// Dynamic array
+	Array(Vec<Token>),
+	/// Tuple or struct
+	Tuple(Vec<Token>),
+}
+
+/// ABI type used to decode a [`Token`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParamType {
+	/// Unsigned integer with the given number of bits, at most 128
+	Uint(usize),
+	/// Boolean
+	Bool,
+	/// Dynamic bytes
+	Bytes,
+	/// Dynamic array
+	Array(Box<ParamType>),
+	/// Tuple or struct
+	Tuple(Vec<ParamType>),
+}
+
+impl Token {
+	fn is_dynamic(&self) -> bool {
+		match self {
+			Token::Uint(_) | Token::Bool(_) | Token::FixedBytes(_) => false,
+			Token::Bytes(_) | Token::Array(_) => true,
+			Token::Tuple(tokens) => tokens.iter().any(Token::is_dynamic),
+		}
+	}
+
+	fn head_len(&self) -> usize {
+		match self {
+			Token::Tuple(tokens) if !self.is_dynamic() => tokens.iter().map(Token::head_len).sum(),
+			_ => WORD,
+		}
+	}
+}
+
+impl ParamType {
+	fn is_dynamic(&self) -> bool {
+		match self {
+			ParamType::Uint(_) | ParamType::Bool => false,
+			ParamType::Bytes | ParamType::Array(_) => true,
+			ParamType::Tuple(types) => types.iter().any(ParamType::is_dynamic),
+		}
+	}
+
+	fn head_len(&self) -> usize {
+		match self {
+			ParamType::Tuple(types) if !self.is_dynamic() => {
+				types.iter().map(ParamType::head_len).sum()
+			},
+			_ => WORD,
+		}
+	}
+}
+
+fn uint_word(value: u128) -> [u8; WORD] {
+	let mut word = [0u8; WORD];
+	word[16..].copy_from_slice(&value.to_be_bytes());
+	word
+}
+
+fn encode_token(token: &Token, out: &mut Vec<u8>) {
+	match token {
+		Token::Uint(value) => out.extend_from_slice(&uint_word(*value)),
+		Token::Bool(value) => out.extend_from_slice(&uint_word(*value as u128)),
+		Token::FixedBytes(bytes) => out.extend_from_slice(bytes),
+		Token::Bytes(bytes) => {
+			out.extend_from_slice(&uint_word(bytes.len() as u128));
+			out.extend_from_slice(bytes);
+			let padding = (WORD - bytes.len() % WORD) % WORD;
+			out.extend(sp_std::iter::repeat(0u8).take(padding));
+		},
+		Token::Array(tokens) => {
+			out.extend_from_slice(&uint_word(tokens.len() as u128));
+			encode_tuple(tokens, out);
+		},
+		Token::Tuple(tokens) => encode_tuple(tokens, out),
+	}
+}
+
+fn encode_tuple(tokens: &[Token], out: &mut Vec<u8>) {
+	let heads_len: usize = tokens.iter().map(Token::head_len).sum();
+	let mut tails = Vec::new();
+	for token in tokens {
+		if token.is_dynamic() {
+			out.extend_from_slice(&uint_word((heads_len + tails.len()) as u128));
+			encode_token(token, &mut tails);
+		} else {
+			encode_token(token, out);
+		}
+	}
+	out.extend(tails);
+}
+
+/// Encodes the tokens like Solidity's `abi.encode(...)`
+pub fn encode(tokens: &[Token]) -> Vec<u8> {
+	let mut out = Vec::new();
+	encode_tuple(tokens, &mut out);
+	out
+}
+
+fn read_word(data: &[u8], pos: usize) -> Result<&[u8], AbiError> {
+	data.get(pos..pos.checked_add(WORD).ok_or(AbiError::InvalidOffset)?)
+		.ok_or(AbiError::UnexpectedEnd)
+}
+
+fn read_uint(data: &[u8], pos: usize, bits: usize) -> Result<u128, AbiError> {
+	let word = read_word(data, pos)?;
+	let unused = WORD - bits.min(128) / 8;
+	if word[..unused].iter().any(|byte| *byte != 0) {
+		return Err(AbiError::InvalidValue);
+	}
+	let mut value = [0u8; 16];
+	value.copy_from_slice(&word[16..]);
+	Ok(u128::from_be_bytes(value))
+}
+
+fn read_len(data: &[u8], pos: usize) -> Result<usize, AbiError> {
+	let len = read_uint(data, pos, 32).map_err(|_| AbiError::InvalidOffset)?;
+	// Neither offsets nor lengths can be larger than the data itself
+	if len > data.len() as u128 {
+		return Err(AbiError::InvalidOffset);
+	}
+	Ok(len as usize)
+}
+
+fn decode_token(param: &ParamType, data: &[u8], pos: usize) -> Result<Token, AbiError> {
+	match param {
+		ParamType::Uint(bits) => Ok(Token::Uint(read_uint(data, pos, *bits)?)),
+		ParamType::Bool => match read_uint(data, pos, 8)? {
+			0 => Ok(Token::Bool(false)),
+			1 => Ok(Token::Bool(true)),
+			_ => Err(AbiError::InvalidValue),
+		},
+		ParamType::Bytes => {
+			let len = read_len(data, pos)?;
+			let start = pos + WORD;
+			let padded_len = len.div_ceil(WORD) * WORD;
+			let padded = data.get(start..start + padded_len).ok_or(AbiError::UnexpectedEnd)?;
+			if padded[len..].iter().any(|byte| *byte != 0) {
+				return Err(AbiError::InvalidValue);
+			}
+			Ok(Token::Bytes(padded[..len].to_vec()))
+		},
+		ParamType::Array(param) => {
+			let len = read_len(data, pos)?;
+			// Every element takes at least one word
+			if len.saturating_mul(WORD) > data.len() {
+				return Err(AbiError::InvalidOffset);
+			}
+			let types = vec![(**param).clone(); len];
+			decode_tuple(&types, &data[pos + WORD..]).map(Token::Array)
+		},
+		ParamType::Tuple(types) if param.is_dynamic() => {
+			decode_tuple(types, &data[pos..]).map(Token::Tuple)
+		},
+		ParamType::Tuple(types) => {
+			let mut tokens = Vec::with_capacity(types.len());
+			let mut head = pos;
+			for param in types {
+				tokens.push(decode_token(param, data, head)?);
+				head += param.head_len();
+			}
+			Ok(Token::Tuple(tokens))
+		},
+	}
+}
+
+fn decode_tuple(types: &[ParamType], data: &[u8]) -> Result<Vec<Token>, AbiError> {
+	let mut tokens = Vec::with_capacity(types.len());
+	let mut head = 0;
+	for param in types {
+		let token = if param.is_dynamic() {
+			let offset = read_len(data, head)?;
+			decode_token(param, data, offset)?
+		} else {
+			decode_token(param, data, head)?
+		};
+		tokens.push(token);
+		head += param.head_len();
+	}
+	Ok(tokens)
+}
+
+/// Decodes data encoded with Solidity's `abi.encode(...)`
+pub fn decode(types: &[ParamType], data: &[u8]) -> Result<Vec<Token>, AbiError> {
+	decode_tuple(types, data)
+}
+
+fn message_types() -> Vec<ParamType> {
+	vec![
+		ParamType::Uint(64),
+		ParamType::Uint(64),
+		ParamType::Uint(8),
+		ParamType::Uint(8),
+		ParamType::Bytes,
+	]
+}
+
+fn withdraw_type() -> ParamType {
+	ParamType::Tuple(vec![
+		ParamType::Bytes,
+		ParamType::Uint(128),
+		ParamType::Uint(128),
+		ParamType::Bytes,
+		ParamType::Bool,
+		ParamType::Bytes,
+	])
+}
+
+fn payload_type_index(payload_type: &PayloadType) -> u8 {
+	match payload_type {
+		PayloadType::ScheduledRotateValidators => 0,
+		PayloadType::ValidatorsRotated => 1,
+		PayloadType::L1Deposit => 2,
+	}
+}
+
+/// ABI encodes the message
+pub fn encode_message(message: &Message) -> Vec<u8> {
+	encode(&[
+		Token::Uint(message.block_no.into()),
+		Token::Uint(message.nonce.into()),
+		Token::Uint(message.network.into()),
+		Token::Uint(payload_type_index(&message.payload_type).into()),
+		Token::Bytes(message.data.clone()),
+	])
+}
+
+/// Decodes an ABI encoded message
+pub fn decode_message(data: &[u8]) -> Result<Message, AbiError> {
+	match decode(&message_types(), data)?.as_slice() {
+		[Token::Uint(block_no), Token::Uint(nonce), Token::Uint(network), Token::Uint(payload_type), Token::Bytes(data)] =>
+		{
+			let payload_type = match payload_type {
+				0 => PayloadType::ScheduledRotateValidators,
+				1 => PayloadType::ValidatorsRotated,
+				2 => PayloadType::L1Deposit,
+				_ => return Err(AbiError::InvalidPayloadType),
+			};
+			Ok(Message {
+				block_no: *block_no as u64,
+				nonce: *nonce as u64,
+				network: *network as u8,
+				payload_type,
+				data: data.clone(),
+			})
+		},
+		_ => Err(AbiError::InvalidValue),
+	}
+}
+
+/// ABI encodes the withdrawals as `Withdraw[]`
+pub fn encode_withdrawals(withdrawals: &[Withdraw]) -> Vec<u8> {
+	let withdrawals = withdrawals
+		.iter()
+		.map(|withdraw| {
+			Token::Tuple(vec![
+				Token::Bytes(withdraw.id.clone()),
+				Token::Uint(withdraw.asset_id),
+				Token::Uint(withdraw.amount),
+				Token::Bytes(withdraw.destination.clone()),
+				Token::Bool(withdraw.is_blocked),
+				Token::Bytes(withdraw.extra.clone()),
+			])
+		})
+		.collect();
+	encode(&[Token::Array(withdrawals)])
+}
+
+/// Decodes ABI encoded `Withdraw[]`
+pub fn decode_withdrawals(data: &[u8]) -> Result<Vec<Withdraw>, AbiError> {
+	let Some(Token::Array(withdrawals)) =
+		decode(&[ParamType::Array(Box::new(withdraw_type()))], data)?.pop()
+	else {
+		return Err(AbiError::InvalidValue);
+	};
+	withdrawals
+		.into_iter()
+		.map(|withdraw| match withdraw {
+			Token::Tuple(fields) => match fields.as_slice() {
+				[Token::Bytes(id), Token::Uint(asset_id), Token::Uint(amount), Token::Bytes(destination), Token::Bool(is_blocked), Token::Bytes(extra)] => {
+					Ok(Withdraw {
+						id: id.clone(),
+						asset_id: *asset_id,
+						amount: *amount,
+						destination: destination.clone(),
+						is_blocked: *is_blocked,
+						extra: extra.clone(),
+					})
+				},
+				_ => Err(AbiError::InvalidValue),
+			},
+			_ => Err(AbiError::InvalidValue),
+		})
+		.collect()
+}
+
+/// Returns the keccak256 hash of the ABI encoded message
+pub fn message_hash(message: &Message) -> [u8; 32] {
+	sp_io::hashing::keccak_256(&encode_message(message))
+}
+
+/// ABI encodes the message commitment
+pub fn encode_commitment(commitment: &MessageCommitment) -> Vec<u8> {
+	encode(&[
+		Token::Uint(commitment.block_no.into()),
+		Token::Uint(commitment.nonce.into()),
+		Token::Uint(commitment.network.into()),
+		Token::Uint(commitment.first_nonce.into()),
+		Token::Uint(commitment.last_nonce.into()),
+		Token::FixedBytes(commitment.root.0),
+	])
+}
+
+/// Returns the keccak256 hash of the ABI encoded message commitment
+pub fn commitment_hash(commitment: &MessageCommitment) -> [u8; 32] {
+	sp_io::hashing::keccak_256(&encode_commitment(commitment))
+}
+
+/// Returns the EIP-191 `personal_sign` digest of the hash
+pub fn eth_signed_message_hash(hash: &[u8; 32]) -> [u8; 32] {
+	let mut payload = ETH_SIGNED_MESSAGE_PREFIX.to_vec();
+	payload.extend_from_slice(hash);
+	sp_io::hashing::keccak_256(&payload)
+}
+
+/// Returns the digest signed by validators for messages to EVM networks
+pub fn message_digest(message: &Message) -> [u8; 32] {
+	eth_signed_message_hash(&message_hash(message))
+}
+
+/// Returns the digest signed by validators for message commitments of EVM networks
+pub fn commitment_digest(commitment: &MessageCommitment) -> [u8; 32] {
+	eth_signed_message_hash(&commitment_hash(commitment))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// `abi.encode(Withdraw[])` of the test withdrawals
+	const WITHDRAWALS_VECTOR: &str = concat!(
+		"0000000000000000000000000000000000000000000000000000000000000020",
+		"0000000000000000000000000000000000000000000000000000000000000002",
+		"0000000000000000000000000000000000000000000000000000000000000040",
+		"00000000000000000000000000000000000000000000000000000000000001a0",
+		"00000000000000000000000000000000000000000000000000000000000000c0",
+		"0000000000000000000000000000000000000000000000000000000000000001",
+		"0000000000000000000000000000000000000000000000000de0b6b3a7640000",
+		"0000000000000000000000000000000000000000000000000000000000000100",
+		"0000000000000000000000000000000000000000000000000000000000000000",
+		"0000000000000000000000000000000000000000000000000000000000000140",
+		"0000000000000000000000000000000000000000000000000000000000000004",
+		"0102030400000000000000000000000000000000000000000000000000000000",
+		"0000000000000000000000000000000000000000000000000000000000000014",
+		"1111111111111111111111111111111111111111000000000000000000000000",
+		"0000000000000000000000000000000000000000000000000000000000000000",
+		"00000000000000000000000000000000000000000000000000000000000000c0",
+		"0000000000000000000000000000000080000000000000000000000000000000",
+		"00000000000000000000000000000000000000000000000000000000075bcd15",
+		"0000000000000000000000000000000000000000000000000000000000000100",
+		"0000000000000000000000000000000000000000000000000000000000000001",
+		"0000000000000000000000000000000000000000000000000000000000000140",
+		"0000000000000000000000000000000000000000000000000000000000000020",
+		"0505050505050505050505050505050505050505050505050505050505050505",
+		"0000000000000000000000000000000000000000000000000000000000000014",
+		"2222222222222222222222222222222222222222000000000000000000000000",
+		"0000000000000000000000000000000000000000000000000000000000000002",
+		"dead000000000000000000000000000000000000000000000000000000000000",
+	);
+
+	/// ABI encoding of the test message
+	const MESSAGE_VECTOR: &str = concat!(
+		"000000000000000000000000000000000000000000000000000000000000000a",
+		"0000000000000000000000000000000000000000000000000000000000000001",
+		"0000000000000000000000000000000000000000000000000000000000000002",
+		"0000000000000000000000000000000000000000000000000000000000000002",
+		"00000000000000000000000000000000000000000000000000000000000000a0",
+		"0000000000000000000000000000000000000000000000000000000000000360",
+		"0000000000000000000000000000000000000000000000000000000000000020",
+		"0000000000000000000000000000000000000000000000000000000000000002",
+		"0000000000000000000000000000000000000000000000000000000000000040",
+		"00000000000000000000000000000000000000000000000000000000000001a0",
+		"00000000000000000000000000000000000000000000000000000000000000c0",
+		"0000000000000000000000000000000000000000000000000000000000000001",
+		"0000000000000000000000000000000000000000000000000de0b6b3a7640000",
+		"0000000000000000000000000000000000000000000000000000000000000100",
+		"0000000000000000000000000000000000000000000000000000000000000000",
+		"0000000000000000000000000000000000000000000000000000000000000140",
+		"0000000000000000000000000000000000000000000000000000000000000004",
+		"0102030400000000000000000000000000000000000000000000000000000000",
+		"0000000000000000000000000000000000000000000000000000000000000014",
+		"1111111111111111111111111111111111111111000000000000000000000000",
+		"0000000000000000000000000000000000000000000000000000000000000000",
+		"00000000000000000000000000000000000000000000000000000000000000c0",
+		"0000000000000000000000000000000080000000000000000000000000000000",
+		"00000000000000000000000000000000000000000000000000000000075bcd15",
+		"0000000000000000000000000000000000000000000000000000000000000100",
+		"0000000000000000000000000000000000000000000000000000000000000001",
+		"0000000000000000000000000000000000000000000000000000000000000140",
+		"0000000000000000000000000000000000000000000000000000000000000020",
+		"0505050505050505050505050505050505050505050505050505050505050505",
+		"0000000000000000000000000000000000000000000000000000000000000014",
+		"2222222222222222222222222222222222222222000000000000000000000000",
+		"0000000000000000000000000000000000000000000000000000000000000002",
+		"dead000000000000000000000000000000000000000000000000000000000000",
+	);
+
+	const MESSAGE_HASH_VECTOR: &str =
+		"baafa632fad9c0ca0f802800538082fd2f673cd6246991dd50a12c35514df8f7";
+	const MESSAGE_DIGEST_VECTOR: &str =
+		"59d727ab0091dd0d98af07bcc02a267ecfd9f92002a885d0b1b33a4e1ac2ac17";
+
+	/// ABI encoding of the test message commitment
+	const COMMITMENT_VECTOR: &str = concat!(
+		"000000000000000000000000000000000000000000000000000000000000000a",
+		"0000000000000000000000000000000000000000000000000000000000000001",
+		"0000000000000000000000000000000000000000000000000000000000000002",
+		"0000000000000000000000000000000000000000000000000000000000000001",
+		"0000000000000000000000000000000000000000000000000000000000000005",
+		"3333333333333333333333333333333333333333333333333333333333333333",
+	);
+
+	const COMMITMENT_HASH_VECTOR: &str =
+		"c1ad40f173805f07fb7395226b6f1a03cb1e90a5674e8063ef067223a60dfaf0";
+	const COMMITMENT_DIGEST_VECTOR: &str =
+		"58e39688c0ffeda26a9b9ab65e6c1cd6dc2384b0c5cbda0b061b96eff96cbbd0";
+
+	fn withdrawals() -> Vec<Withdraw> {
+		vec![
+			Withdraw {
+				id: vec![1, 2, 3, 4],
+				asset_id: 1,
+				amount: 1_000_000_000_000_000_000,
+				destination: vec![0x11; 20],
+				is_blocked: false,
+				extra: vec![],
+			},
+			Withdraw {
+				id: vec![5; 32],
+				asset_id: 1 << 127,
+				amount: 123_456_789,
+				destination: vec![0x22; 20],
+				is_blocked: true,
+				extra: vec![0xde, 0xad],
+			},
+		]
+	}
+
+	fn message() -> Message {
+		Message {
+			block_no: 10,
+			nonce: 1,
+			network: 2,
+			payload_type: PayloadType::L1Deposit,
+			data: encode_withdrawals(&withdrawals()),
+		}
+	}
+
+	#[test]
+	pub fn test_withdrawals_match_test_vector() {
+		let encoded = encode_withdrawals(&withdrawals());
+		assert_eq!(hex::encode(&encoded), WITHDRAWALS_VECTOR);
+		assert_eq!(decode_withdrawals(&encoded), Ok(withdrawals()));
+		assert_eq!(decode_withdrawals(&encode_withdrawals(&[])), Ok(vec![]));
+	}
+
+	#[test]
+	pub fn test_message_matches_test_vector() {
+		let encoded = encode_message(&message());
+		assert_eq!(hex::encode(&encoded), MESSAGE_VECTOR);
+		assert_eq!(decode_message(&encoded), Ok(message()));
+		assert_eq!(hex::encode(message_hash(&message())), MESSAGE_HASH_VECTOR);
+		assert_eq!(hex::encode(message_digest(&message())), MESSAGE_DIGEST_VECTOR);
+	}
+
+	#[test]
+	pub fn test_commitment_matches_test_vector() {
+		let commitment = MessageCommitment {
+			block_no: 10,
+			nonce: 1,
+			network: 2,
+			first_nonce: 1,
+			last_nonce: 5,
+			root: [0x33; 32].into(),
+		};
+		assert_eq!(hex::encode(encode_commitment(&commitment)), COMMITMENT_VECTOR);
+		assert_eq!(hex::encode(commitment_hash(&commitment)), COMMITMENT_HASH_VECTOR);
+		assert_eq!(hex::encode(commitment_digest(&commitment)), COMMITMENT_DIGEST_VECTOR);
+	}
+
+	#[test]
+	pub fn test_decoder_rejects_malformed_data() {
+		let encoded = hex::decode(MESSAGE_VECTOR).unwrap();
+		assert_eq!(decode_message(&encoded[..encoded.len() - 32]), Err(AbiError::UnexpectedEnd));
+		// Network does not fit in uint8
+		let mut invalid = encoded.clone();
+		invalid[94] = 1;
+		assert_eq!(decode_message(&invalid), Err(AbiError::InvalidValue));
+		// Unknown payload type
+		let mut invalid = encoded.clone();
+		invalid[127] = 3;
+		assert_eq!(decode_message(&invalid), Err(AbiError::InvalidPayloadType));
+		// Offset of data points outside of the message
+		let mut invalid = encoded;
+		invalid[158] = 0xff;
+		assert_eq!(decode_message(&invalid), Err(AbiError::InvalidOffset));
+
+		let encoded = hex::decode(WITHDRAWALS_VECTOR).unwrap();
+		// Non-boolean value of `isBlocked`
+		let mut invalid = encoded.clone();
+		let is_blocked = 64 + 0x1a0 + 4 * 32 + 31;
+		assert_eq!(invalid[is_blocked], 1);
+		invalid[is_blocked] = 2;
+		assert_eq!(decode_withdrawals(&invalid), Err(AbiError::InvalidValue));
+		// Array is longer than the data
+		let mut invalid = encoded;
+		invalid[63] = 0xff;
+		assert_eq!(decode_withdrawals(&invalid), Err(AbiError::InvalidOffset));
+	}
+}
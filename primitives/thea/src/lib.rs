@@ -23,6 +23,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod abi;
 pub mod types;
 
 pub use crate::types::Message;
@@ -93,17 +94,56 @@ pub const MESSAGE_CACHE_DURATION_IN_SECS: u64 = 60;
 
 /// Thea incoming message executor abstraction which should be implemented by the "Thea Executor".
 pub trait TheaIncomingExecutor {
-	fn execute_deposits(network: Network, deposits: Vec<u8>);
+	fn execute_deposits(network: Network, nonce: u64, deposits: Vec<u8>);
+	/// Called when deposits of the asset are allowed again after a pause
+	fn on_asset_ingress_resumed(_asset_id: u128) {}
+	/// Called when deposits from the network are allowed again after a pause
+	fn on_network_ingress_resumed(_network: Network) {}
+	/// Returns the value of the deposits in native decimals, which scales the fork period
+	fn deposits_value(_network: Network, _deposits: &[u8]) -> u128 {
+		0
+	}
 }
 
 /// Thea outgoing message executor abstraction which should be implemented by the "Thea" pallet.
 pub trait TheaOutgoingExecutor {
 	fn execute_withdrawals(network: Network, withdrawals: Vec<u8>) -> DispatchResult;
+	/// Checks that the withdrawal can be sent to the network before its assets are burned
+	fn validate_withdrawal(_network: Network, _withdrawal: &types::Withdraw) -> DispatchResult {
+		Ok(())
+	}
 	fn get_outgoing_nonce(network: Network) -> u64;
 }
 
 impl TheaIncomingExecutor for () {
-	fn execute_deposits(_network: Network, _deposits: Vec<u8>) {}
+	fn execute_deposits(_network: Network, _nonce: u64, _deposits: Vec<u8>) {}
+}
+
+/// Pause switches of bridged networks and assets which should be implemented by the "Thea" pallet.
+pub trait TheaPauseSwitch {
+	fn network_pause(network: Network) -> Option<types::PauseState>;
+	fn asset_pause(asset_id: u128) -> Option<types::PauseState>;
+}
+
+impl TheaPauseSwitch for () {
+	fn network_pause(_network: Network) -> Option<types::PauseState> {
+		None
+	}
+	fn asset_pause(_asset_id: u128) -> Option<types::PauseState> {
+		None
+	}
+}
+
+/// Handler of incoming messages whose inclusion on the foreign chain was proven on-chain, e.g. by
+/// a light client, which should be implemented by the "Thea" pallet.
+pub trait TheaIncomingProofHandler<AccountId> {
+	fn on_proven_message(relayer: AccountId, message: types::Message) -> DispatchResult;
+}
+
+impl<AccountId> TheaIncomingProofHandler<AccountId> for () {
+	fn on_proven_message(_relayer: AccountId, _message: types::Message) -> DispatchResult {
+		Ok(())
+	}
 }
 
 pub trait TheaBenchmarkHelper {
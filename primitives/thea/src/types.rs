@@ -23,24 +23,36 @@ use parity_scale_codec::{Decode, Encode};
 use polkadex_primitives::UNIT_BALANCE;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
-use sp_runtime::Percent;
+use sp_core::{ed25519, sr25519, H256};
+use sp_runtime::{DispatchError, Percent};
 #[cfg(not(feature = "std"))]
 use sp_std::vec::Vec;
 use sp_std::{cmp::Ordering, collections::btree_map::BTreeMap};
 
 use crate::{Network, ValidatorSetId};
 
+/// Returns the number of signatures required to finalize a message
+///
+/// # Arguments
+///
+/// * `max_len` - The maximum length of the validator set
+pub fn signature_threshold(max_len: usize) -> usize {
+	const MAJORITY: u8 = 67;
+	let p = Percent::from_percent(MAJORITY);
+	p * max_len
+}
+
 /// Defines the message structure.
 #[derive(
 	Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize,
 )]
-pub struct SignedMessage<Signature> {
+pub struct SignedMessage<Signature, Payload = Message> {
 	pub validator_set_id: ValidatorSetId,
-	pub message: Message,
+	pub message: Payload,
 	pub signatures: BTreeMap<u32, Signature>,
 }
 
-impl<Signature> SignedMessage<Signature> {
+impl<Signature, Payload: PartialEq> SignedMessage<Signature, Payload> {
 	/// Create a new signed message
 	///
 	/// # Arguments
@@ -54,7 +66,7 @@ impl<Signature> SignedMessage<Signature> {
 	///
 	/// * `Self` - The signed message
 	pub fn new(
-		message: Message,
+		message: Payload,
 		validator_set_id: ValidatorSetId,
 		auth_index: u32,
 		signature: Signature,
@@ -74,7 +86,7 @@ impl<Signature> SignedMessage<Signature> {
 	/// * `signature` - The signature of the authority
 	pub fn add_signature(
 		&mut self,
-		message: Message,
+		message: Payload,
 		validator_set_id: ValidatorSetId,
 		auth_index: u32,
 		signature: Signature,
@@ -101,10 +113,7 @@ impl<Signature> SignedMessage<Signature> {
 	///
 	/// * `bool` - True if the threshold is reached
 	pub fn threshold_reached(&self, max_len: usize) -> bool {
-		const MAJORITY: u8 = 67;
-		let p = Percent::from_percent(MAJORITY);
-		let threshold = p * max_len;
-		self.signatures.len() >= threshold
+		self.signatures.len() >= signature_threshold(max_len)
 	}
 
 	/// Check if the signed message contains the signature of the authority
@@ -121,20 +130,268 @@ impl<Signature> SignedMessage<Signature> {
 	}
 }
 
-pub const THEA_HOLD_REASON: [u8; 8] = *b"theaRela";
+/// Signature scheme used by validators to sign outgoing messages of a network
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	TypeInfo,
+	Debug,
+	Default,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Deserialize,
+	Serialize,
+)]
+pub enum SignatureScheme {
+	/// Individual ECDSA signatures, collected in `SignedMessage`
+	#[default]
+	Ecdsa,
+	/// BLS signatures aggregated into one, collected in `BlsSignedMessage`
+	Bls,
+	/// Individual ed25519 signatures, collected in `SignedMessage<SchemeSignature>`
+	Ed25519,
+	/// Individual sr25519 (Schnorrkel) signatures, collected in `SignedMessage<SchemeSignature>`
+	Sr25519,
+}
 
+/// Public key registered by an authority to sign outgoing messages of ed25519 and sr25519
+/// networks
 #[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum SigningKey {
+	Ed25519(ed25519::Public),
+	Sr25519(sr25519::Public),
+}
+
+impl SigningKey {
+	/// Returns the signature scheme of the key
+	pub fn scheme(&self) -> SignatureScheme {
+		match self {
+			SigningKey::Ed25519(_) => SignatureScheme::Ed25519,
+			SigningKey::Sr25519(_) => SignatureScheme::Sr25519,
+		}
+	}
+}
+
+/// Signature of an outgoing message of ed25519 and sr25519 networks
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub enum SchemeSignature {
+	Ed25519(ed25519::Signature),
+	Sr25519(sr25519::Signature),
+}
+
+impl SchemeSignature {
+	/// Returns the signature scheme of the signature
+	pub fn scheme(&self) -> SignatureScheme {
+		match self {
+			SchemeSignature::Ed25519(_) => SignatureScheme::Ed25519,
+			SchemeSignature::Sr25519(_) => SignatureScheme::Sr25519,
+		}
+	}
+
+	/// Verifies the signature of the message against the key
+	///
+	/// # Arguments
+	///
+	/// * `key` - Public key of the signer
+	/// * `msg` - Signed message
+	///
+	/// # Returns
+	///
+	/// * `bool` - True if the signature is valid, false if it is invalid or of another scheme
+	pub fn verify(&self, key: &SigningKey, msg: &[u8]) -> bool {
+		match (self, key) {
+			(SchemeSignature::Ed25519(signature), SigningKey::Ed25519(public)) => {
+				sp_io::crypto::ed25519_verify(signature, msg, public)
+			},
+			(SchemeSignature::Sr25519(signature), SigningKey::Sr25519(public)) => {
+				sp_io::crypto::sr25519_verify(signature, msg, public)
+			},
+			_ => false,
+		}
+	}
+}
+
+/// Directions of a bridged network or asset that are halted
+#[derive(Encode, Decode, Clone, Copy, TypeInfo, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum PauseState {
+	/// Incoming messages and deposits are held back
+	Ingress,
+	/// Withdrawals are rejected and outgoing batches are held back
+	Egress,
+	/// Both directions are halted
+	Both,
+}
+
+impl PauseState {
+	/// Returns true if incoming messages and deposits are halted
+	pub fn ingress(&self) -> bool {
+		matches!(self, PauseState::Ingress | PauseState::Both)
+	}
+
+	/// Returns true if withdrawals are halted
+	pub fn egress(&self) -> bool {
+		matches!(self, PauseState::Egress | PauseState::Both)
+	}
+}
+
+/// Outgoing message signed with an aggregated BLS signature
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct BlsSignedMessage {
+	pub validator_set_id: ValidatorSetId,
+	pub message: Message,
+	/// Bitmap of the signers, bit `i % 8` of byte `i / 8` is set if authority `i` signed
+	pub signers: Vec<u8>,
+	/// Aggregate of all signatures
+	pub signature: bls_primitives::Signature,
+}
+
+impl BlsSignedMessage {
+	/// Create a new BLS signed message
+	///
+	/// # Arguments
+	///
+	/// * `message` - The message to be signed
+	/// * `validator_set_id` - The validator set id
+	/// * `auth_index` - The index of the authority signing the message
+	/// * `signature` - The BLS signature of the authority
+	pub fn new(
+		message: Message,
+		validator_set_id: ValidatorSetId,
+		auth_index: u32,
+		signature: bls_primitives::Signature,
+	) -> Self {
+		let mut signed_message = Self { validator_set_id, message, signers: Vec::new(), signature };
+		signed_message.set_signer(auth_index);
+		signed_message
+	}
+
+	/// Aggregate a signature into the signed message
+	///
+	/// # Arguments
+	///
+	/// * `message` - The message to be signed
+	/// * `validator_set_id` - The validator set id
+	/// * `auth_index` - The index of the authority signing the message
+	/// * `signature` - The BLS signature of the authority
+	pub fn add_signature(
+		&mut self,
+		message: Message,
+		validator_set_id: ValidatorSetId,
+		auth_index: u32,
+		signature: bls_primitives::Signature,
+	) -> Result<(), bls_primitives::Error> {
+		if self.message != message {
+			// silently drop if message is different
+			log::error!(target:"thea", "Thea Message is not same");
+			return Ok(());
+		}
+		if self.validator_set_id < validator_set_id {
+			*self = Self::new(message, validator_set_id, auth_index, signature);
+			return Ok(());
+		}
+		// Signatures of an older validator set can't be aggregated with the current ones
+		if self.validator_set_id > validator_set_id || self.contains_signature(&auth_index) {
+			return Ok(());
+		}
+		self.signature = self.signature.add_signature(&signature)?;
+		self.set_signer(auth_index);
+		Ok(())
+	}
+
+	/// Returns the authority indexes of all signers
+	pub fn signer_indexes(&self) -> Vec<u32> {
+		self.signers
+			.iter()
+			.enumerate()
+			.flat_map(|(byte_index, byte)| {
+				(0..8u32)
+					.filter(move |bit| byte & (1 << bit) != 0)
+					.map(move |bit| (byte_index as u32) * 8 + bit)
+			})
+			.collect()
+	}
+
+	/// Check if the signed message has reached the threshold
+	///
+	/// # Arguments
+	///
+	/// * `max_len` - The maximum length of the validator set
+	pub fn threshold_reached(&self, max_len: usize) -> bool {
+		self.signer_indexes().len() >= signature_threshold(max_len)
+	}
+
+	/// Check if the signed message contains the signature of the authority
+	///
+	/// # Arguments
+	///
+	/// * `auth_index` - The index of the authority
+	pub fn contains_signature(&self, auth_index: &u32) -> bool {
+		self.signers
+			.get((*auth_index / 8) as usize)
+			.map(|byte| byte & (1 << (auth_index % 8)) != 0)
+			.unwrap_or(false)
+	}
+
+	fn set_signer(&mut self, auth_index: u32) {
+		let byte_index = (auth_index / 8) as usize;
+		if self.signers.len() <= byte_index {
+			self.signers.resize(byte_index.saturating_add(1), 0);
+		}
+		self.signers[byte_index] |= 1 << (auth_index % 8);
+	}
+}
+
+pub const THEA_HOLD_REASON: [u8; 8] = *b"theaRela";
+
+/// Hold reason for the bond of registered relayers
+pub const THEA_RELAYER_BOND_REASON: [u8; 8] = *b"theaBond";
+
+#[derive(
+	Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize,
+)]
 pub enum NetworkType {
 	Parachain,
 	Evm,
 }
 
-#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(
+	Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize,
+)]
 pub struct NetworkConfig {
 	pub fork_period: u32,
 	pub min_stake: u128,
 	pub fisherman_stake: u128,
 	pub network_type: NetworkType,
+	/// Signature scheme used by validators to sign outgoing messages
+	pub signature_scheme: SignatureScheme,
+	/// Longer fork periods for incoming messages moving more value, sorted by `min_value`
+	pub fork_period_tiers: Vec<ForkPeriodTier>,
+}
+
+/// Fork period of incoming messages whose deposits are worth at least `min_value`
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	TypeInfo,
+	Debug,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Deserialize,
+	Serialize,
+)]
+pub struct ForkPeriodTier {
+	/// Value of the deposits in native decimals
+	pub min_value: u128,
+	/// Fork period in blocks
+	pub fork_period: u32,
 }
 
 impl Default for NetworkConfig {
@@ -144,6 +401,8 @@ impl Default for NetworkConfig {
 			min_stake: 1000 * UNIT_BALANCE,
 			fisherman_stake: 100 * UNIT_BALANCE,
 			network_type: NetworkType::Parachain,
+			signature_scheme: SignatureScheme::Ecdsa,
+			fork_period_tiers: Vec::new(),
 		}
 	}
 }
@@ -151,8 +410,74 @@ impl Default for NetworkConfig {
 impl NetworkConfig {
 	pub fn new(fork_period: u32, min_stake: u128, fisherman_stake: u128, is_evm: bool) -> Self {
 		let key_type = if is_evm { NetworkType::Evm } else { NetworkType::Parachain };
-		Self { fork_period, min_stake, fisherman_stake, network_type: key_type }
+		Self {
+			fork_period,
+			min_stake,
+			fisherman_stake,
+			network_type: key_type,
+			signature_scheme: SignatureScheme::Ecdsa,
+			fork_period_tiers: Vec::new(),
+		}
 	}
+
+	/// Returns the fork period of an incoming message moving `value`, which is the fork period
+	/// of the highest tier reached or `fork_period` if none is
+	pub fn fork_period_for_value(&self, value: u128) -> u32 {
+		self.fork_period_tiers
+			.iter()
+			.rev()
+			.find(|tier| tier.min_value <= value)
+			.map_or(self.fork_period, |tier| tier.fork_period)
+	}
+
+	/// Returns true if tier values are strictly increasing and no tier waits less than the tier
+	/// below it or than `fork_period`
+	pub fn valid_fork_period_tiers(&self, tiers: &[ForkPeriodTier]) -> bool {
+		tiers.first().map_or(true, |tier| tier.fork_period >= self.fork_period)
+			&& tiers.windows(2).all(|pair| {
+				pair[0].min_value < pair[1].min_value && pair[0].fork_period <= pair[1].fork_period
+			})
+	}
+}
+
+/// Nonces of a network as tracked by Thea
+#[derive(
+	Clone, Copy, Encode, Decode, TypeInfo, Debug, Default, Eq, PartialEq, Deserialize, Serialize,
+)]
+pub struct NetworkNonces {
+	/// Nonce of the last executed incoming message
+	pub incoming: u64,
+	/// Nonce of the last created outgoing message
+	pub outgoing: u64,
+	/// Nonce of the last outgoing message that reached the signature threshold
+	pub signed_outgoing: u64,
+}
+
+/// Signing progress of an outgoing message
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct OutgoingMessageStatus<Signature> {
+	pub message: Message,
+	/// Validator set signing the message
+	pub validator_set_id: ValidatorSetId,
+	pub scheme: SignatureScheme,
+	/// Individual signatures by authority index, empty for BLS signed networks
+	pub signatures: BTreeMap<u32, Signature>,
+	/// Authority indexes of all signers
+	pub signers: Vec<u32>,
+	/// Number of signatures required to finalize the message
+	pub required_signatures: u32,
+	pub threshold_reached: bool,
+}
+
+/// Bond of a relayer registered for a network
+#[derive(
+	Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize,
+)]
+pub struct RelayerInfo<Balance> {
+	/// Amount held as bond
+	pub bond: Balance,
+	/// Block number after which the bond can be withdrawn, if unbonding
+	pub unbonding_at: Option<u32>,
 }
 
 #[derive(
@@ -201,6 +526,36 @@ pub struct Message {
 	pub data: Vec<u8>,
 }
 
+/// Merkle root of a contiguous range of outgoing messages of a network, signed by validators
+/// in place of the individual messages
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct MessageCommitment {
+	/// Block number.
+	pub block_no: u64,
+	/// Commitment nonce
+	pub nonce: u64,
+	/// Network
+	pub network: Network,
+	/// Nonce of the first committed message
+	pub first_nonce: u64,
+	/// Nonce of the last committed message
+	pub last_nonce: u64,
+	/// Keccak-256 Merkle root of the messages ordered by nonce, ABI encoded for EVM networks and
+	/// SCALE encoded otherwise
+	pub root: H256,
+}
+
+/// Inclusion proof of an outgoing message in a message commitment
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
+pub struct MessageProof {
+	pub commitment: MessageCommitment,
+	pub message: Message,
+	/// Position of the message among the committed messages
+	pub leaf_index: u32,
+	/// Hashes of the sibling nodes from the leaf up to the root
+	pub proof: Vec<H256>,
+}
+
 /// Defines the destination of a thea message
 #[derive(
 	Copy,
@@ -249,6 +604,17 @@ impl<AccountId> Deposit<AccountId> {
 	}
 }
 
+/// Deposits of an incoming message that couldn't be executed.
+#[derive(Encode, Decode, Clone, TypeInfo, PartialEq, Debug)]
+pub struct FailedDeposits<AccountId> {
+	/// Payload as received, only kept if it couldn't be decoded.
+	pub payload: Vec<u8>,
+	/// Deposits that are not executed yet.
+	pub deposits: Vec<Deposit<AccountId>>,
+	/// Error of the last execution attempt.
+	pub error: DispatchError,
+}
+
 /// Defines the structure of the withdraw.
 ///
 /// Withdraw is relative to solochain
@@ -311,6 +677,88 @@ impl AssetMetadata {
 	}
 }
 
+/// Value caps of an asset on a network over a rolling window of blocks.
+///
+/// Caps are in native decimals.
+#[derive(Encode, Decode, Clone, Copy, TypeInfo, PartialEq, Debug)]
+pub struct RateLimit {
+	/// Length of the rolling window in blocks
+	pub window: u32,
+	/// Maximum amount deposited within the window
+	pub deposit_cap: u128,
+	/// Maximum amount withdrawn within the window
+	pub withdrawal_cap: u128,
+}
+
+/// Amounts moved through the bridge in the current and the previous window.
+///
+/// The rolling usage is estimated by weighting the previous window with the part of it that
+/// still overlaps the rolling window.
+#[derive(Encode, Decode, Clone, Copy, TypeInfo, PartialEq, Debug, Default)]
+pub struct RateLimitUsage {
+	/// Block the current window started at
+	pub window_start: u32,
+	/// Amount deposited in the current window
+	pub deposited: u128,
+	/// Amount withdrawn in the current window
+	pub withdrawn: u128,
+	/// Amount deposited in the previous window
+	pub previous_deposited: u128,
+	/// Amount withdrawn in the previous window
+	pub previous_withdrawn: u128,
+}
+
+impl RateLimitUsage {
+	/// Moves the current window forward so that it contains `now`
+	pub fn roll(&mut self, now: u32, window: u32) {
+		let window = window.max(1);
+		let elapsed = now.saturating_sub(self.window_start);
+		if elapsed < window {
+			return;
+		}
+		if elapsed < window.saturating_mul(2) {
+			self.previous_deposited = self.deposited;
+			self.previous_withdrawn = self.withdrawn;
+		} else {
+			self.previous_deposited = 0;
+			self.previous_withdrawn = 0;
+		}
+		self.deposited = 0;
+		self.withdrawn = 0;
+		self.window_start = now.saturating_sub(elapsed % window);
+	}
+
+	/// Returns the amounts (deposited, withdrawn) within the rolling window ending at `now`
+	pub fn rolling_usage(&self, now: u32, window: u32) -> (u128, u128) {
+		let mut usage = *self;
+		usage.roll(now, window);
+		let window = window.max(1);
+		let overlap = window.saturating_sub(now.saturating_sub(usage.window_start)) as u128;
+		let weighted = |previous: u128| previous.saturating_mul(overlap) / window as u128;
+		(
+			weighted(usage.previous_deposited).saturating_add(usage.deposited),
+			weighted(usage.previous_withdrawn).saturating_add(usage.withdrawn),
+		)
+	}
+}
+
+/// Bridge operation held back for governance review because it exceeds a rate limit
+#[derive(Encode, Decode, Clone, TypeInfo, PartialEq, Debug)]
+pub enum PendingReview<AccountId> {
+	/// Deposit of the incoming message with the given nonce
+	Deposit { network: Network, nonce: u64, deposit: Deposit<AccountId> },
+	/// Withdrawal of `user`, `amount` is the burned amount and `fees` the fees paid, both in
+	/// native decimals
+	Withdrawal {
+		network: Network,
+		user: AccountId,
+		amount: u128,
+		withdraw: Withdraw,
+		pay_for_remaining: bool,
+		fees: u128,
+	},
+}
+
 /// Overarching type used by aggregator to collect signatures from
 /// authorities for a given Thea message
 #[derive(Deserialize, Serialize, Clone)]
@@ -327,9 +775,53 @@ pub struct ApprovedMessage {
 
 #[cfg(test)]
 mod tests {
-	use crate::types::AssetMetadata;
+	use crate::types::{AssetMetadata, ForkPeriodTier, NetworkConfig, RateLimitUsage};
 	use polkadex_primitives::UNIT_BALANCE;
 
+	#[test]
+	pub fn test_fork_period_scales_with_value() {
+		let mut config = NetworkConfig::new(10, 0, 0, false);
+		assert_eq!(config.fork_period_for_value(u128::MAX), 10);
+		config.fork_period_tiers = vec![
+			ForkPeriodTier { min_value: 1_000, fork_period: 50 },
+			ForkPeriodTier { min_value: 100_000, fork_period: 200 },
+		];
+		assert!(config.valid_fork_period_tiers(&config.fork_period_tiers));
+		assert_eq!(config.fork_period_for_value(0), 10);
+		assert_eq!(config.fork_period_for_value(999), 10);
+		assert_eq!(config.fork_period_for_value(1_000), 50);
+		assert_eq!(config.fork_period_for_value(99_999), 50);
+		assert_eq!(config.fork_period_for_value(u128::MAX), 200);
+		config.fork_period_tiers.reverse();
+		assert!(!config.valid_fork_period_tiers(&config.fork_period_tiers));
+		// Fork periods can not decrease with the value
+		assert!(!config.valid_fork_period_tiers(&[
+			ForkPeriodTier { min_value: 1_000, fork_period: 200 },
+			ForkPeriodTier { min_value: 100_000, fork_period: 50 },
+		]));
+		// Nor be shorter than the fork period of the network
+		assert!(
+			!config.valid_fork_period_tiers(&[ForkPeriodTier { min_value: 1_000, fork_period: 5 }])
+		);
+	}
+
+	#[test]
+	pub fn test_rate_limit_usage_rolls_over_windows() {
+		let mut usage = RateLimitUsage::default();
+		usage.roll(100, 10);
+		assert_eq!(usage.window_start, 100);
+		usage.deposited = 1000;
+		usage.withdrawn = 500;
+		assert_eq!(usage.rolling_usage(105, 10), (1000, 500));
+		// Previous window only counts for the part that overlaps the rolling window
+		assert_eq!(usage.rolling_usage(112, 10), (800, 400));
+		usage.roll(112, 10);
+		assert_eq!(usage.window_start, 110);
+		assert_eq!((usage.deposited, usage.previous_deposited), (0, 1000));
+		// Everything expires after two windows
+		assert_eq!(usage.rolling_usage(125, 10), (0, 0));
+	}
+
 	#[test]
 	pub fn test_decimal_conversion() {
 		// Decimal is greater
@@ -17,13 +17,20 @@
 
 //! Some configurable implementations as associated type for the substrate runtime.
 
-use frame_support::traits::{
-	fungibles::{Balanced, Credit},
-	Currency, OnUnbalanced,
+use frame_support::{
+	dispatch::DispatchResult,
+	traits::{
+		fungibles::{Balanced, Credit},
+		Currency, ExistenceRequirement, Get, OnUnbalanced,
+	},
 };
 use pallet_asset_tx_payment::HandleCredit;
+use sp_runtime::traits::StaticLookup;
 
-use crate::{AccountId, Assets, Authorship, Balances, NegativeImbalance, Runtime};
+use crate::{
+	AccountId, Assets, Authorship, Balance, Balances, BlockNumber, MinVestedTransfer,
+	NegativeImbalance, OrmlVesting, Runtime, RuntimeOrigin,
+};
 
 pub struct Author;
 impl OnUnbalanced<NegativeImbalance> for Author {
@@ -46,6 +53,32 @@ impl HandleCredit<AccountId, Assets> for CreditToBlockAuthor {
 	}
 }
 
+/// Vests the claimed LMP rewards through `orml_vesting`.
+/// Amounts below the minimum vested transfer are paid at once.
+pub struct LMPRewardsVesting;
+impl orderbook_primitives::traits::LMPRewardsVesting<AccountId> for LMPRewardsVesting {
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		start: BlockNumber,
+		period: BlockNumber,
+		period_count: u32,
+		per_period: Balance,
+	) -> DispatchResult {
+		let schedule = orml_vesting::VestingSchedule { start, period, period_count, per_period };
+		match schedule.total_amount() {
+			Some(amount) if amount < MinVestedTransfer::get() => {
+				Balances::transfer(from, to, amount, ExistenceRequirement::AllowDeath)
+			},
+			_ => OrmlVesting::vested_transfer(
+				RuntimeOrigin::signed(from.clone()),
+				<Runtime as frame_system::Config>::Lookup::unlookup(to.clone()),
+				schedule,
+			),
+		}
+	}
+}
+
 #[cfg(test)]
 mod multiplier_tests {
 	use frame_support::{
@@ -32,9 +32,9 @@ use frame_support::{
 	pallet_prelude::{ConstU32, RuntimeDebug},
 	parameter_types,
 	traits::{
-		fungible::Inspect, AsEnsureOriginWithArg, Currency, EitherOfDiverse, EnsureOrigin,
-		EqualPrivilegeOnly, Everything, Get, Imbalance, InstanceFilter, KeyOwnerProofSystem,
-		LockIdentifier, OnUnbalanced,
+		fungible::Inspect, AsEnsureOriginWithArg, Currency, EitherOf, EitherOfDiverse,
+		EnsureOrigin, EqualPrivilegeOnly, Everything, Get, Imbalance, InstanceFilter,
+		KeyOwnerProofSystem, LockIdentifier, OnUnbalanced,
 	},
 	weights::{
 		constants::{
@@ -122,10 +122,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// and set impl_version to 0. If only runtime
 	// implementation changes and behavior does not, then leave spec_version as
 	// is and increment impl_version.
-	spec_version: 342,
+	spec_version: 343,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 2,
+	transaction_version: 3,
 	state_version: 0,
 };
 
@@ -838,6 +838,9 @@ impl pallet_collective::Config<OrderbookCollective> for Runtime {
 	type MaxProposalWeight = MaxCollectivesProposalWeight;
 }
 
+type EnsureHalfTechnicalCommittee =
+	pallet_collective::EnsureProportionMoreThan<AccountId, TechnicalCollective, 1, 2>;
+
 type EnsureRootOrHalfCouncil = EitherOfDiverse<
 	EnsureRoot<AccountId>,
 	pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
@@ -1252,11 +1255,35 @@ impl EnsureOrigin<RuntimeOrigin> for EnsureRootOrTreasury {
 	}
 }
 
+/// Origin of the LMP rewards account, which vests the claimed LMP rewards
+pub struct EnsureLMPRewards;
+impl EnsureOrigin<RuntimeOrigin> for EnsureLMPRewards {
+	type Success = AccountId;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		Into::<Result<RawOrigin<AccountId>, RuntimeOrigin>>::into(o).and_then(|o| match o {
+			RawOrigin::Signed(caller)
+				if caller == LMPRewardsPalletId::get().into_account_truncating() =>
+			{
+				Ok(caller)
+			},
+			r => Err(RuntimeOrigin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::from(RawOrigin::Signed(
+			LMPRewardsPalletId::get().into_account_truncating(),
+		)))
+	}
+}
+
 impl orml_vesting::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type MinVestedTransfer = MinVestedTransfer;
-	type VestedTransferOrigin = EnsureRootOrTreasury;
+	type VestedTransferOrigin = EitherOf<EnsureRootOrTreasury, EnsureLMPRewards>;
 	type WeightInfo = ();
 	type MaxVestingSchedules = MaxVestingSchedules;
 	type BlockNumberProvider = SusbtrateBlockNumberProvider;
@@ -1285,6 +1312,7 @@ parameter_types! {
 	pub const LMPRewardsPalletId: PalletId = PalletId(*b"LMPREWAR");
 	pub const MsPerDay: u64 = 86_400_000;
 	pub const OBWithdrawalLimit: u32 = 50;
+	pub const LMPRewardStreamsClaimPeriod: u32 = 201600; // 28 days in blocks
 }
 
 impl pallet_ocex_lmp::Config for Runtime {
@@ -1292,12 +1320,15 @@ impl pallet_ocex_lmp::Config for Runtime {
 	type PalletId = OcexPalletId;
 	type TreasuryPalletId = TreasuryPalletId;
 	type LMPRewardsPalletId = LMPRewardsPalletId;
+	type LMPRewardStreamsClaimPeriod = LMPRewardStreamsClaimPeriod;
 	type NativeCurrency = Balances;
 	type OtherAssets = Assets;
 	type EnclaveOrigin = EnsureSigned<AccountId>;
 	type AuthorityId = pallet_ocex_lmp::sr25519::AuthorityId;
 	type GovernanceOrigin = EnsureRootOrHalfCouncil;
-	type CrowdSourceLiqudityMining = ();
+	type LMPRewardsVesting = LMPRewardsVesting;
+	type CrowdSourceLiqudityMining = CrowdSourceLMP;
+	type Swap = AssetConversion;
 	type OBWithdrawalLimit = OBWithdrawalLimit;
 	type WeightInfo = pallet_ocex_lmp::weights::WeightInfo<Runtime>;
 }
@@ -1319,6 +1350,8 @@ impl pallet_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const CrowdSourcingRewardsPalletId: PalletId = PalletId(*b"CROWSOUR");
+	pub const MaxPoolsPerSnapshot: u32 = 20;
+	pub const EarlyExitPenalty: Permill = Permill::from_percent(2);
 }
 
 impl pallet_lmp::pallet::Config for Runtime {
@@ -1327,6 +1360,9 @@ impl pallet_lmp::pallet::Config for Runtime {
 	type PalletId = CrowdSourcingRewardsPalletId;
 	type NativeCurrency = Balances;
 	type OtherAssets = Assets;
+	type MaxPoolsPerSnapshot = MaxPoolsPerSnapshot;
+	type EarlyExitPenalty = EarlyExitPenalty;
+	type WeightInfo = pallet_lmp::weights::WeightInfo<Runtime>;
 }
 
 use polkadex_primitives::POLKADEX_NATIVE_ASSET_ID;
@@ -1335,6 +1371,18 @@ parameter_types! {
 	pub const PolkadexAssetId: u128 = POLKADEX_NATIVE_ASSET_ID;
 }
 
+parameter_types! {
+	pub const MaxTheaRelayers: u32 = 50;
+	pub const TheaRelayerUnbondingPeriod: u32 = 7 * DAYS;
+	pub const TheaRelayerRotationPeriod: u32 = 10;
+	pub const TheaRelayerRewardsPalletId: PalletId = PalletId(*b"th/relrw");
+	// Receives `TheaRelayerRewardsShare` of every withdrawal fee collected by Thea Executor,
+	// governance can top it up with `fund_relayer_rewards`
+	pub TheaRelayerRewardsAccount: AccountId =
+		TheaRelayerRewardsPalletId::get().into_account_truncating();
+	pub const TheaRelayerRewardsShare: Perbill = Perbill::from_percent(50);
+}
+
 impl thea::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type TheaId = thea::ecdsa::AuthorityId;
@@ -1343,11 +1391,32 @@ impl thea::Config for Runtime {
 	type Executor = TheaExecutor;
 	type NativeCurrency = Balances;
 	type TheaGovernanceOrigin = EnsureRootOrHalfCouncil;
+	type PauseOrigin = EnsureHalfTechnicalCommittee;
+	type MaxRelayers = MaxTheaRelayers;
+	type RelayerUnbondingPeriod = TheaRelayerUnbondingPeriod;
+	type RelayerRotationPeriod = TheaRelayerRotationPeriod;
+	type RelayerRewardsAccount = TheaRelayerRewardsAccount;
+	type ProvenNetworks = EthLightClient;
+	type FishermanId = thea::fisherman::crypto::FishermanAuthId;
+	type ForeignChainSource = thea::fisherman::JsonRpcSource;
 	#[cfg(feature = "runtime-benchmarks")]
 	type TheaBenchmarkHelper = TheaExecutor;
 	type WeightInfo = thea::weights::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	// About a day of finalized epochs
+	pub const MaxFinalizedEthHeaders: u32 = 256;
+}
+
+impl eth_light_client::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type GovernanceOrigin = EnsureRootOrHalfCouncil;
+	type MessageHandler = Thea;
+	type MaxFinalizedHeaders = MaxFinalizedEthHeaders;
+	type WeightInfo = eth_light_client::weights::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const TheaPalletAccount: PalletId = PalletId(*b"th/accnt");
 	pub const WithdrawalSize: u32 = 10;
@@ -1372,6 +1441,9 @@ impl thea_executor::Config for Runtime {
 	type AssetBalanceAdapter = u128;
 	type GovernanceOrigin = EnsureRootOrHalfCouncil;
 	type ExistentialDeposit = AutoSwapInitialNativeDeposit;
+	type PauseSwitch = Thea;
+	type RelayerRewardsAccount = TheaRelayerRewardsAccount;
+	type RelayerRewardsShare = TheaRelayerRewardsShare;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -1507,6 +1579,7 @@ construct_runtime!(
 		Statement: pallet_statement = 48,
 		AssetTxPayment: pallet_asset_tx_payment = 49,
 		CrowdSourceLMP: pallet_lmp::pallet = 50,
+		EthLightClient: eth_light_client::pallet = 51,
 	}
 );
 
@@ -1558,6 +1631,7 @@ construct_runtime!(
 		Statement: pallet_statement = 48,
 		AssetTxPayment: pallet_asset_tx_payment = 49,
 		CrowdSourceLMP: pallet_lmp::pallet = 50,
+		EthLightClient: eth_light_client::pallet = 51,
 	}
 );
 /// Digest item type.
@@ -1603,9 +1677,18 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
-use crate::impls::CreditToBlockAuthor;
+/// Pending storage migrations
+pub type Migrations = (
+	thea::migration::v1::MigrateToV1<Runtime>,
+	pallet_ocex_lmp::migration::v1::MigrateToV1<Runtime>,
+	pallet_lmp::migration::v1::MigrateToV1<Runtime>,
+);
+
+use crate::impls::{CreditToBlockAuthor, LMPRewardsVesting};
+use orderbook_primitives::lmp::LMPScheduledEpoch;
 use orderbook_primitives::ObCheckpointRaw;
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
@@ -1688,6 +1771,48 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl thea_runtime_api::TheaRuntimeApi<Block, AccountId, thea::ecdsa::AuthorityId, thea::ecdsa::AuthoritySignature> for Runtime {
+		fn get_bls_signed_message(network: thea_primitives::Network, nonce: u64) -> Option<thea_primitives::types::BlsSignedMessage> {
+			Thea::get_bls_signed_message(network, nonce)
+		}
+		fn get_bls_authorities(id: thea_primitives::ValidatorSetId) -> Vec<(u32, bls_primitives::Public)> {
+			Thea::get_bls_authorities(id)
+		}
+		fn get_outgoing_message_status(network: thea_primitives::Network, nonce: Option<u64>) -> Option<thea_primitives::types::OutgoingMessageStatus<thea::ecdsa::AuthoritySignature>> {
+			Thea::get_outgoing_message_status(network, nonce)
+		}
+		fn get_incoming_queue(network: thea_primitives::Network) -> Vec<(u64, thea_primitives::types::IncomingMessage<AccountId, Balance>)> {
+			Thea::get_incoming_queue(network)
+		}
+		fn get_network_nonces(network: thea_primitives::Network) -> thea_primitives::types::NetworkNonces {
+			Thea::get_network_nonces(network)
+		}
+		fn get_validator_set_id() -> thea_primitives::ValidatorSetId {
+			Thea::validator_set_id()
+		}
+		fn get_authorities(id: thea_primitives::ValidatorSetId) -> Vec<thea::ecdsa::AuthorityId> {
+			Thea::get_authorities(id)
+		}
+		fn get_network_configs() -> Vec<(thea_primitives::Network, thea_primitives::types::NetworkConfig)> {
+			Thea::get_network_configs()
+		}
+		fn get_message_proof(network: thea_primitives::Network, nonce: u64) -> Option<thea_primitives::types::MessageProof> {
+			Thea::get_message_proof(network, nonce)
+		}
+		fn get_signed_commitment(network: thea_primitives::Network, nonce: u64) -> Option<thea_primitives::types::SignedMessage<thea::ecdsa::AuthoritySignature, thea_primitives::types::MessageCommitment>> {
+			Thea::get_signed_commitment(network, nonce)
+		}
+	}
+
+	impl thea_executor_runtime_api::TheaExecutorRuntimeApi<Block, AccountId> for Runtime {
+		fn get_failed_deposits(network: thea_primitives::Network) -> Vec<(u64, thea_primitives::types::FailedDeposits<AccountId>)> {
+			TheaExecutor::get_failed_deposits(network)
+		}
+		fn get_rate_limit_usage(network: thea_primitives::Network, asset_id: u128) -> Option<(thea_primitives::types::RateLimit, u128, u128)> {
+			TheaExecutor::get_rate_limit_usage(network, asset_id)
+		}
+	}
+
 	impl pallet_rewards_runtime_api::PolkadexRewardsRuntimeApi<Block, AccountId, Hash> for Runtime {
 		fn account_info(account_id : AccountId, reward_id: u32) ->  Result<polkadex_primitives::rewards::RewardsInfoByAccount<u128>, DispatchError> {
 			Rewards::account_info(account_id, reward_id)
@@ -1713,6 +1838,10 @@ impl_runtime_apis! {
 			OCEX::get_lmp_rewards(&main, epoch.saturated_into(), market)
 		}
 
+		fn calculate_lmp_claimable_rewards(main: AccountId, epoch: u16, market: TradingPair) -> (Decimal, Decimal, bool) {
+			OCEX::get_lmp_claimable_rewards(&main, epoch, market)
+		}
+
 		fn get_fees_paid_by_user_per_epoch(epoch: u32,market: TradingPair, main: AccountId) -> Decimal {
 			OCEX::get_fees_paid_by_user_per_epoch(epoch.saturated_into(),market,main)
 		}
@@ -1727,6 +1856,39 @@ impl_runtime_apis! {
 		fn get_trader_metrics(epoch: u16, market: TradingPair, main: AccountId) -> (Decimal, Decimal, bool){
 			OCEX::get_trader_metrics(epoch,market,main)
 		}
+		fn get_lmp_schedule() -> Vec<LMPScheduledEpoch<AccountId>> {
+			OCEX::get_lmp_schedule()
+		}
+		fn get_lmp_score_timeline(epoch: u16, market: TradingPair, main: AccountId) -> sp_std::collections::btree_map::BTreeMap<u16, Decimal> {
+			OCEX::get_lmp_score_timeline(epoch, market, main)
+		}
+		fn get_projected_lmp_rewards(main: AccountId, market: TradingPair) -> (Decimal, Decimal) {
+			OCEX::get_projected_lmp_rewards(main, market)
+		}
+		fn lmp_rewarded_leaderboard(epoch: u16, market: TradingPair, sorted_by_mm_score: bool, offset: u32, limit: u16) -> Vec<(AccountId, Decimal, Decimal)> {
+			OCEX::lmp_rewarded_leaderboard(epoch, market, sorted_by_mm_score, offset, limit)
+		}
+	}
+
+	impl pallet_lmp_runtime_api::PolkadexLmpRuntimeApi<Block, AccountId, Balance> for Runtime {
+		fn list_pools() -> Vec<(TradingPair, AccountId, pallet_lmp::types::MarketMakerConfig<AccountId>, pallet_lmp::types::PoolFlags, Option<Decimal>)> {
+			CrowdSourceLMP::list_pools()
+		}
+		fn get_lp_shares(market: TradingPair, market_maker: AccountId, lp: AccountId) -> Balance {
+			CrowdSourceLMP::get_lp_shares(market, market_maker, lp)
+		}
+		fn get_pool_nav(market: TradingPair, market_maker: AccountId) -> Result<(Decimal, Decimal), DispatchError> {
+			CrowdSourceLMP::get_pool_nav(market, market_maker)
+		}
+		fn get_performance_fee(epoch: u16, market: TradingPair, market_maker: AccountId) -> Option<(Decimal, Balance)> {
+			CrowdSourceLMP::get_performance_fee(epoch, market, market_maker)
+		}
+		fn get_pending_withdrawals(epoch: u16, market: TradingPair, market_maker: AccountId) -> Vec<(AccountId, Balance, Balance)> {
+			CrowdSourceLMP::get_pending_withdrawals(epoch, market, market_maker)
+		}
+		fn get_claimable_rewards(market: TradingPair, market_maker: AccountId, epoch: u16, lp: AccountId) -> (Balance, bool) {
+			CrowdSourceLMP::get_claimable_rewards(market, market_maker, epoch, lp)
+		}
 	}
 
 	impl sp_statement_store::runtime_api::ValidateStatement<Block> for Runtime {
@@ -1917,6 +2079,8 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, thea_executor, TheaExecutor);
 			list_benchmark!(list, extra, thea, Thea);
 			list_benchmark!(list, extra, thea_message_handler, TheaMH);
+			list_benchmark!(list, extra, eth_light_client, EthLightClient);
+			list_benchmark!(list, extra, pallet_lmp, CrowdSourceLMP);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -1953,6 +2117,8 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, thea_executor, TheaExecutor); //TheaExecutor: thea_executor
 			add_benchmark!(params, batches, thea, Thea);
 			add_benchmark!(params, batches, thea_message_handler, TheaMH);
+			add_benchmark!(params, batches, eth_light_client, EthLightClient);
+			add_benchmark!(params, batches, pallet_lmp, CrowdSourceLMP);
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
 		}